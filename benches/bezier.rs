@@ -3,8 +3,12 @@ use rand::Rng;
 
 use askew::canvas::math;
 use askew::canvas::math::point::Point;
+use askew::canvas::samples::Samples;
 use askew::canvas::shape::rational_bezier::RationalBezierPoint;
 
+const CURVE_POINTS: usize = 30;
+const CURVE_SAMPLES: usize = 1000;
+
 fn bench_bezier(criterion: &mut Criterion) {
     let mut random = rand::thread_rng();
     let points = (0..10_000)
@@ -14,6 +18,10 @@ fn bench_bezier(criterion: &mut Criterion) {
 
     let mut group = criterion.benchmark_group("bezier");
     group.bench_function("de_casteljau", |bencher| bencher.iter(|| math::de_casteljau(&points, t)));
+    let mut buffer = Vec::with_capacity(points.len());
+    group.bench_function("de_casteljau_in", |bencher| {
+        bencher.iter(|| math::de_casteljau_in(&points, t, &mut buffer))
+    });
     group.bench_function("chudy_wozny", |bencher| bencher.iter(|| math::chudy_wozny(&points, t)));
 
     let rational_points =
@@ -25,5 +33,84 @@ fn bench_bezier(criterion: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_bezier);
+fn bench_bezier_path(criterion: &mut Criterion) {
+    let mut random = rand::thread_rng();
+    let points = (0..CURVE_POINTS)
+        .map(|_| Point::new(random.gen_range(0.0..=1000.0), random.gen_range(0.0..=1000.0)))
+        .collect::<Vec<_>>();
+    let rational_points = points
+        .iter()
+        .map(|point| RationalBezierPoint::new(*point, random.gen_range(0.5..=2.0)))
+        .collect::<Vec<_>>();
+    let ts = (0..=CURVE_SAMPLES).map(|index| index as f32 / CURVE_SAMPLES as f32);
+
+    let mut group = criterion.benchmark_group("bezier_path");
+    group.bench_function("de_casteljau", |bencher| {
+        bencher.iter(|| ts.clone().map(|t| math::de_casteljau(&points, t)).collect::<Vec<_>>())
+    });
+    group.bench_function("de_casteljau_in", |bencher| {
+        let mut buffer = Vec::with_capacity(points.len());
+        bencher.iter(|| {
+            ts.clone().map(|t| math::de_casteljau_in(&points, t, &mut buffer)).collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("chudy_wozny", |bencher| {
+        bencher.iter(|| ts.clone().map(|t| math::chudy_wozny(&points, t)).collect::<Vec<_>>())
+    });
+    group.bench_function("rational_de_casteljau", |bencher| {
+        bencher.iter(|| {
+            ts.clone().map(|t| math::rational_de_casteljau(&rational_points, t)).collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("rational_de_casteljau_in", |bencher| {
+        let mut buffer = Vec::with_capacity(rational_points.len());
+        bencher.iter(|| {
+            ts.clone()
+                .map(|t| math::rational_de_casteljau_in(&rational_points, t, &mut buffer))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("rational_chudy_wozny", |bencher| {
+        bencher.iter(|| {
+            ts.clone().map(|t| math::rational_chudy_wozny(&rational_points, t)).collect::<Vec<_>>()
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_sampling(criterion: &mut Criterion) {
+    let mut random = rand::thread_rng();
+    let points = (0..CURVE_POINTS)
+        .map(|_| Point::new(random.gen_range(0.0..=1000.0), random.gen_range(0.0..=1000.0)))
+        .collect::<Vec<_>>();
+
+    let mut group = criterion.benchmark_group("sampling");
+    for samples in [250, 500, CURVE_SAMPLES, 4 * CURVE_SAMPLES] {
+        let samples = Samples::new(samples);
+        group.bench_function(format!("serial_{}", samples.samples()), |bencher| {
+            let mut buffer = Vec::with_capacity(points.len());
+            bencher.iter(|| {
+                samples
+                    .sample(0.0..=1.0, |t| math::de_casteljau_in(&points, t, &mut buffer))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function(format!("parallel_{}", samples.samples()), |bencher| {
+            bencher.iter(|| {
+                samples
+                    .sample_parallel(
+                        0.0..=1.0,
+                        || Vec::with_capacity(points.len()),
+                        |buffer, t| math::de_casteljau_in(&points, t, buffer),
+                    )
+                    .collect::<Vec<_>>()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_bezier, bench_bezier_path, bench_sampling);
 criterion_main!(benches);
@@ -0,0 +1,167 @@
+use tiny_skia::{PixmapMut, Point};
+
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::shape::DrawOn;
+use crate::canvas::visual_path::line::{VisualLine, VisualLineProperties};
+use crate::canvas::visual_path::point::{VisualPoint, VisualPointProperties};
+use crate::config::rgb::{Alpha, Rgb};
+use crate::config::CanvasConfig;
+
+pub mod request;
+
+pub type OpenBaseLine = VisualBaseLine<false>;
+pub type ClosedBaseLine = VisualBaseLine<true>;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct VisualBaseLine<const CLOSED: bool> {
+    line: VisualLine<CLOSED>,
+    points: VisualPoint,
+    #[serde(skip)]
+    point_buffer: Vec<Point>,
+    /// Empty if the path is not sampled from a curve.
+    #[serde(skip)]
+    parameters: Vec<f32>,
+}
+
+impl<const CLOSED: bool> VisualBaseLine<CLOSED> {
+    #[must_use]
+    pub fn new(line: VisualLine<CLOSED>, points: VisualPoint) -> Self {
+        Self { line, points, point_buffer: Vec::new(), parameters: Vec::new() }
+    }
+
+    pub fn rebuild_paths<P>(&mut self, points: impl Iterator<Item = P>)
+    where
+        P: Into<Point>,
+    {
+        self.point_buffer.clear();
+        self.parameters.clear();
+        let points = points.map(P::into);
+        self.point_buffer.extend(points);
+        self.rebuild_visual_paths();
+    }
+
+    pub fn rebuild_sampled_paths(&mut self, samples: impl Iterator<Item = (f32, CurvePoint)>) {
+        self.point_buffer.clear();
+        self.parameters.clear();
+        for (t, point) in samples {
+            self.parameters.push(t);
+            self.point_buffer.push(point.into());
+        }
+        self.rebuild_visual_paths();
+    }
+
+    fn rebuild_visual_paths(&mut self) {
+        self.line.rebuild_path(self.point_buffer.iter().copied());
+        self.points.rebuild_path(self.point_buffer.iter().copied());
+    }
+
+    /// Maps position in `[0, 1]` along the points of the path to the parameter of the curve.
+    #[must_use]
+    pub fn parameter_at(&self, position: f32) -> f32 {
+        let Some(last_segment) = self.sampled_parameters().and_then(|t| t.len().checked_sub(2))
+        else {
+            return position;
+        };
+        let position = position.clamp(0.0, 1.0) * (last_segment + 1) as f32;
+        let index = (position.floor() as usize).min(last_segment);
+        let ratio = position - index as f32;
+        self.parameters[index] * (1.0 - ratio) + self.parameters[index + 1] * ratio
+    }
+
+    /// Inverse of [`VisualBaseLine::parameter_at`] for a fraction of the parameter range.
+    #[must_use]
+    pub fn position_at(&self, fraction: f32) -> f32 {
+        let Some(parameters) = self.sampled_parameters().filter(|t| t.len() > 1) else {
+            return fraction;
+        };
+        let (first, last) = (parameters[0], parameters[parameters.len() - 1]);
+        let t = first + fraction.clamp(0.0, 1.0) * (last - first);
+        let ascending = first <= last;
+        let index = parameters
+            .partition_point(|&parameter| (parameter <= t) == ascending)
+            .clamp(1, parameters.len() - 1);
+        let (start, end) = (parameters[index - 1], parameters[index]);
+        let span = end - start;
+        let ratio =
+            if span.abs() > f32::EPSILON { ((t - start) / span).clamp(0.0, 1.0) } else { 0.0 };
+        ((index - 1) as f32 + ratio) / (parameters.len() - 1) as f32
+    }
+
+    fn sampled_parameters(&self) -> Option<&[f32]> {
+        (!self.parameters.is_empty() && !CLOSED).then_some(self.parameters.as_slice())
+    }
+
+    /// Closed line ends with its first point.
+    #[must_use]
+    pub fn path_points(&self) -> Vec<CurvePoint> {
+        let points = self.point_buffer.iter().copied().map(CurvePoint::from);
+        if CLOSED {
+            points.chain(self.point_buffer.first().copied().map(CurvePoint::from)).collect()
+        } else {
+            points.collect()
+        }
+    }
+}
+
+impl<const CLOSED: bool> DrawOn for VisualBaseLine<CLOSED> {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.line.draw_on(pixmap);
+        self.points.draw_on(pixmap);
+    }
+}
+
+impl<const CLOSED: bool> Default for VisualBaseLine<CLOSED> {
+    fn default() -> Self {
+        Self {
+            line: VisualLine::new(true, VisualLineProperties::new(2.0, Rgb::WHITE, Alpha::OPAQUE)),
+            points: VisualPoint::new(
+                false,
+                VisualPointProperties::new(4.0, Rgb::WHITE, Alpha::OPAQUE),
+            ),
+            point_buffer: Vec::new(),
+            parameters: Vec::new(),
+        }
+    }
+}
+
+impl<const CLOSED: bool> From<&CanvasConfig> for VisualBaseLine<CLOSED> {
+    fn from(value: &CanvasConfig) -> Self {
+        Self {
+            line: VisualLine::new(
+                true,
+                VisualLineProperties::new(
+                    value.default_line_width,
+                    value.line_color,
+                    Alpha::OPAQUE,
+                ),
+            ),
+            points: VisualPoint::new(
+                false,
+                VisualPointProperties::new(3.0, Rgb::WHITE, Alpha::OPAQUE),
+            ),
+            point_buffer: Vec::new(),
+            parameters: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_follow_parameters_of_samples() {
+        let mut base_line = OpenBaseLine::default();
+        let samples = [0.0, 0.1, 0.2, 1.0].map(|t| (t, CurvePoint::new(t, 0.0)));
+        base_line.rebuild_sampled_paths(samples.into_iter());
+        assert!((base_line.parameter_at(2.0 / 3.0) - 0.2).abs() < 1e-6);
+        assert!((base_line.position_at(0.6) - 2.5 / 3.0).abs() < 1e-6);
+        for position in [0.0, 0.2, 0.5, 0.9, 1.0] {
+            let parameter = base_line.parameter_at(position);
+            assert!((base_line.position_at(parameter) - position).abs() < 1e-6);
+        }
+
+        base_line.rebuild_paths(samples.map(|(_, point)| point).into_iter());
+        assert_eq!(base_line.parameter_at(0.25), 0.25);
+    }
+}
@@ -0,0 +1,41 @@
+use anyhow::anyhow;
+
+use crate::canvas::base_line::VisualBaseLine;
+use crate::canvas::math;
+use crate::canvas::math::projection::Projection;
+use crate::canvas::shape::request::declare::{
+    GetArcLength, GetCurveFeatures, GetEquidistantPoints, GetSampledPath, ProjectPoint,
+};
+use crate::request::{Error, RequestHandler, Response};
+
+impl<const CLOSED: bool> RequestHandler<GetArcLength> for VisualBaseLine<CLOSED> {
+    fn handle(&self, _request: GetArcLength) -> Response<GetArcLength> {
+        Ok(math::arc_length::polyline_length(&self.path_points()))
+    }
+}
+
+impl<const CLOSED: bool> RequestHandler<GetEquidistantPoints> for VisualBaseLine<CLOSED> {
+    fn handle(&self, request: GetEquidistantPoints) -> Response<GetEquidistantPoints> {
+        Ok(math::arc_length::resample_polyline(&self.path_points(), request.count))
+    }
+}
+
+impl<const CLOSED: bool> RequestHandler<GetSampledPath> for VisualBaseLine<CLOSED> {
+    fn handle(&self, _request: GetSampledPath) -> Response<GetSampledPath> {
+        Ok(self.path_points())
+    }
+}
+
+impl<const CLOSED: bool> RequestHandler<GetCurveFeatures> for VisualBaseLine<CLOSED> {
+    fn handle(&self, _request: GetCurveFeatures) -> Response<GetCurveFeatures> {
+        Ok(math::curve_features::polyline_features(&self.path_points()))
+    }
+}
+
+impl<const CLOSED: bool> RequestHandler<ProjectPoint> for VisualBaseLine<CLOSED> {
+    fn handle(&self, request: ProjectPoint) -> Response<ProjectPoint> {
+        let projection = math::projection::polyline_projection(&self.path_points(), request.point)
+            .ok_or_else(|| Error::Other(anyhow!("curve has no points to project on")))?;
+        Ok(Projection { t: self.parameter_at(projection.t), ..projection })
+    }
+}
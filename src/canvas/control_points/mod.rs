@@ -8,6 +8,8 @@ use crate::canvas::math::vector::Vector;
 
 pub mod point;
 pub mod request;
+pub mod tangent;
+pub mod tcb;
 pub mod weighted;
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -71,6 +73,7 @@ impl<T> ControlPoints<T> {
             let rotated_vertical = sin_angle * vector.horizontal() + cos_angle * vector.vertical();
             let rotated = Vector::new(rotated_horizontal, rotated_vertical);
             *point.point_mut() = center + rotated;
+            point.rotate_attached(cos_angle, sin_angle);
         }
     }
 
@@ -132,6 +135,10 @@ impl<T> ControlPoints<T> {
         self.points.iter()
     }
 
+    pub fn iterator_mut(&mut self) -> impl ExactSizeIterator<Item = &mut T> {
+        self.points.iter_mut()
+    }
+
     #[must_use]
     pub fn points_iterator<V>(&self) -> impl ExactSizeIterator<Item = Point<V>> + '_
     where
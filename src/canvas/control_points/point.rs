@@ -8,6 +8,9 @@ pub trait PointContainer<T> {
     fn into_point(self) -> Point<T>;
 
     fn point_mut(&mut self) -> &mut Point<T>;
+
+    /// Rotates directions attached to the point, e.g. its tangent, by the given angle.
+    fn rotate_attached(&mut self, _cos_angle: T, _sin_angle: T) {}
 }
 
 impl<T> PointContainer<T> for Point<T>
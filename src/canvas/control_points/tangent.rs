@@ -0,0 +1,46 @@
+use num_traits::Float;
+
+use crate::canvas::control_points::point::PointContainer;
+use crate::canvas::math::point::Point;
+use crate::canvas::math::vector::Vector;
+
+#[derive(Debug, Copy, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct TangentPoint<T> {
+    point: Point<T>,
+    tangent: Vector<T>,
+}
+
+impl<T> TangentPoint<T> {
+    pub fn new(point: Point<T>, tangent: Vector<T>) -> Self {
+        Self { point, tangent }
+    }
+
+    pub fn tangent(self) -> Vector<T> {
+        self.tangent
+    }
+
+    pub fn tangent_mut(&mut self) -> &mut Vector<T> {
+        &mut self.tangent
+    }
+}
+
+impl<T> PointContainer<T> for TangentPoint<T>
+where
+    T: Float,
+{
+    fn into_point(self) -> Point<T> {
+        self.point
+    }
+
+    fn point_mut(&mut self) -> &mut Point<T> {
+        &mut self.point
+    }
+
+    fn rotate_attached(&mut self, cos_angle: T, sin_angle: T) {
+        let (horizontal, vertical) = (self.tangent.horizontal(), self.tangent.vertical());
+        self.tangent = Vector::new(
+            cos_angle * horizontal - sin_angle * vertical,
+            sin_angle * horizontal + cos_angle * vertical,
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use crate::canvas::control_points::point::PointContainer;
+use crate::canvas::math::point::Point;
+
+#[derive(Debug, Copy, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct TcbPoint<T> {
+    point: Point<T>,
+    parameters: TcbParameters,
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    parse_display::Display,
+)]
+#[display("{tension} {continuity} {bias}")]
+pub struct TcbParameters {
+    pub tension: f32,
+    pub continuity: f32,
+    pub bias: f32,
+}
+
+impl<T> TcbPoint<T> {
+    pub fn new(point: Point<T>, parameters: TcbParameters) -> Self {
+        Self { point, parameters }
+    }
+
+    pub fn parameters(self) -> TcbParameters {
+        self.parameters
+    }
+
+    pub fn parameters_mut(&mut self) -> &mut TcbParameters {
+        &mut self.parameters
+    }
+}
+
+impl<T> PointContainer<T> for TcbPoint<T>
+where
+    T: Copy,
+{
+    fn into_point(self) -> Point<T> {
+        self.point
+    }
+
+    fn point_mut(&mut self) -> &mut Point<T> {
+        &mut self.point
+    }
+}
+
+impl TcbParameters {
+    #[must_use]
+    pub fn new(tension: f32, continuity: f32, bias: f32) -> Self {
+        Self { tension, continuity, bias }
+    }
+}
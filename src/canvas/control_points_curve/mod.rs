@@ -0,0 +1,300 @@
+use std::mem;
+
+use tiny_skia::{PixmapMut, Point as SkiaPoint};
+
+use crate::canvas::base_line::VisualBaseLine;
+use crate::canvas::control_points::point::{CurvePoint, PointContainer};
+use crate::canvas::control_points::ControlPoints;
+use crate::canvas::math::convex_hull::GrahamScan;
+use crate::canvas::math::curvature;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::DrawOn;
+use crate::canvas::visual_path::arrow::VisualArrows;
+use crate::canvas::visual_path::circle::VisualCircles;
+use crate::canvas::visual_path::comb::VisualComb;
+use crate::canvas::visual_path::line::{VisualLine, VisualLineProperties};
+use crate::canvas::visual_path::point::{VisualPoint, VisualPointProperties};
+use crate::config::rgb::{Alpha, Rgb};
+use crate::config::CanvasConfig;
+
+pub type ControlLine = VisualLine<false>;
+pub type ConvexHullLine = VisualLine<true>;
+
+pub mod request;
+
+const COMB_TEETH: usize = 128;
+const VECTOR_LENGTH: f32 = 50.0;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Overlay {
+    CurvatureComb,
+    TangentNormal,
+    OsculatingCircle,
+    /// Drawn from the first control point of Bezier curve.
+    Hodograph,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct VisualControlPoints {
+    control_points: VisualPoint,
+    // TODO: add current control point
+    control_line: ControlLine,
+
+    convex_hull: ConvexHullLine,
+    #[serde(skip)]
+    convex_hull_buffer: Vec<CurvePoint>,
+
+    center_of_mass: VisualPoint,
+
+    curvature_comb: VisualComb,
+    tangent_normal: VisualArrows,
+    osculating_circle: VisualCircles,
+    hodograph: VisualLine<false>,
+    /// Fraction of the parameter range of the curve.
+    overlay_parameter: f32,
+    comb_scale: f32,
+}
+
+impl VisualControlPoints {
+    #[must_use]
+    pub fn new(
+        control_points: VisualPoint,
+        control_line: ControlLine,
+        convex_hull: ConvexHullLine,
+        center_of_mass: VisualPoint,
+    ) -> Self {
+        Self { control_points, control_line, convex_hull, center_of_mass, ..Self::default() }
+    }
+
+    pub fn rebuild_paths<P>(&mut self, points: &ControlPoints<P>, layers: Layers)
+    where
+        P: PointContainer<f32> + Copy,
+    {
+        if layers.contains(Layers::CONTROL_POINTS) {
+            self.control_points.rebuild_path(points.points_iterator());
+            self.control_line.rebuild_path(points.points_iterator());
+        }
+
+        if layers.contains(Layers::CONVEX_HULL) {
+            self.rebuild_convex_hull_path(points);
+        }
+
+        if layers.contains(Layers::CENTER_OF_MASS) {
+            let center_of_mass = points.center_of_mass().map(SkiaPoint::from).into_iter();
+            self.center_of_mass.rebuild_path(center_of_mass);
+        }
+    }
+
+    #[must_use]
+    pub fn convex_hull_points(&self) -> &[CurvePoint] {
+        &self.convex_hull_buffer
+    }
+
+    #[must_use]
+    pub fn is_overlay_visible(&self, overlay: Overlay) -> bool {
+        match overlay {
+            Overlay::CurvatureComb => self.curvature_comb.is_visible(),
+            Overlay::TangentNormal => self.tangent_normal.is_visible(),
+            Overlay::OsculatingCircle => self.osculating_circle.is_visible(),
+            Overlay::Hodograph => self.hodograph.is_visible(),
+        }
+    }
+
+    pub fn set_overlay_visible(&mut self, overlay: Overlay, visible: bool) {
+        match overlay {
+            Overlay::CurvatureComb => self.curvature_comb.set_visible(visible),
+            Overlay::TangentNormal => self.tangent_normal.set_visible(visible),
+            Overlay::OsculatingCircle => self.osculating_circle.set_visible(visible),
+            Overlay::Hodograph => self.hodograph.set_visible(visible),
+        }
+    }
+
+    pub fn rebuild_overlays<const CLOSED: bool>(
+        &mut self,
+        base_line: &VisualBaseLine<CLOSED>,
+        layers: Layers,
+    ) {
+        let visible = self.curvature_comb.is_visible()
+            || self.tangent_normal.is_visible()
+            || self.osculating_circle.is_visible();
+        if !visible || !layers.intersects(Layers::CURVE | Layers::OVERLAYS) {
+            return;
+        }
+
+        let frames = curvature::polyline_frames(&base_line.path_points());
+        let step = frames.len().div_ceil(COMB_TEETH).max(1);
+        let teeth = frames
+            .iter()
+            .step_by(step)
+            .flat_map(|frame| {
+                let tip = frame.point + frame.normal * (-frame.curvature * self.comb_scale);
+                [frame.point, tip]
+            })
+            .map(SkiaPoint::from)
+            .collect::<Vec<_>>();
+        self.curvature_comb.rebuild_path(teeth.into_iter());
+
+        let position = base_line.position_at(self.overlay_parameter);
+        let frame = curvature::frame_at(&frames, position);
+        let vectors = frame.iter().flat_map(|frame| {
+            let tangent = frame.point + frame.tangent * VECTOR_LENGTH;
+            let normal = frame.point + frame.normal * VECTOR_LENGTH;
+            [frame.point, tangent, frame.point, normal]
+        });
+        self.tangent_normal.rebuild_path(vectors.collect::<Vec<_>>().into_iter());
+
+        let circle = frame
+            .and_then(|frame| Some([frame.curvature_center()?, frame.point]))
+            .into_iter()
+            .flatten();
+        self.osculating_circle.rebuild_path(circle.collect::<Vec<_>>().into_iter());
+    }
+
+    pub fn rebuild_hodograph(&mut self, points: impl Iterator<Item = CurvePoint>) {
+        let points = points.map(SkiaPoint::from).collect::<Vec<_>>();
+        self.hodograph.rebuild_path(points.into_iter());
+    }
+
+    fn rebuild_convex_hull_path<P>(&mut self, points: &ControlPoints<P>)
+    where
+        P: PointContainer<f32> + Copy,
+    {
+        self.convex_hull_buffer.clear();
+        let points = points.points_iterator();
+        self.convex_hull_buffer.extend(points);
+        let graham_scan = GrahamScan::new(mem::take(&mut self.convex_hull_buffer));
+        self.convex_hull_buffer = graham_scan.convex_hull();
+        let convex_hull = self.convex_hull_buffer.iter().copied().map(SkiaPoint::from);
+        self.convex_hull.rebuild_path(convex_hull);
+    }
+}
+
+impl DrawOn for VisualControlPoints {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.control_line.draw_on(pixmap);
+        self.convex_hull.draw_on(pixmap);
+        self.control_points.draw_on(pixmap);
+        self.center_of_mass.draw_on(pixmap);
+        self.curvature_comb.draw_on(pixmap);
+        self.tangent_normal.draw_on(pixmap);
+        self.osculating_circle.draw_on(pixmap);
+        self.hodograph.draw_on(pixmap);
+    }
+}
+
+impl Default for VisualControlPoints {
+    fn default() -> Self {
+        Self {
+            control_points: VisualPoint::new(
+                true,
+                VisualPointProperties::new(4.0, Rgb::WHITE, Alpha::OPAQUE),
+            ),
+            control_line: VisualLine::new(
+                false,
+                VisualLineProperties::new(4.0, Rgb::WHITE, Alpha::OPAQUE),
+            ),
+            convex_hull: VisualLine::new(
+                false,
+                VisualLineProperties::new(4.0, Rgb::WHITE, Alpha::OPAQUE),
+            ),
+            convex_hull_buffer: Vec::new(),
+            center_of_mass: VisualPoint::new(
+                true,
+                VisualPointProperties::new(4.0, Rgb::WHITE, Alpha::OPAQUE),
+            ),
+            curvature_comb: VisualComb::new(
+                false,
+                VisualLineProperties::new(1.0, Rgb::WHITE, Alpha::OPAQUE),
+            ),
+            tangent_normal: VisualArrows::new(
+                false,
+                VisualLineProperties::new(2.0, Rgb::WHITE, Alpha::OPAQUE),
+            ),
+            osculating_circle: VisualCircles::new(
+                false,
+                VisualLineProperties::new(1.0, Rgb::WHITE, Alpha::OPAQUE),
+            ),
+            hodograph: VisualLine::new(
+                false,
+                VisualLineProperties::new(2.0, Rgb::WHITE, Alpha::OPAQUE),
+            ),
+            overlay_parameter: 0.5,
+            comb_scale: 1000.0,
+        }
+    }
+}
+
+impl From<&CanvasConfig> for VisualControlPoints {
+    fn from(value: &CanvasConfig) -> Self {
+        Self {
+            control_points: VisualPoint::new(
+                true,
+                VisualPointProperties::new(
+                    value.default_point_radius,
+                    value.control_points_color,
+                    Alpha::OPAQUE,
+                ),
+            ),
+            control_line: VisualLine::new(
+                value.show_control_line,
+                VisualLineProperties::new(
+                    value.default_line_width,
+                    value.convex_hull_color,
+                    Alpha::OPAQUE,
+                ),
+            ),
+            convex_hull: VisualLine::new(
+                value.show_convex_hull,
+                VisualLineProperties::new(
+                    value.default_line_width,
+                    value.convex_hull_color,
+                    Alpha::OPAQUE,
+                ),
+            ),
+            convex_hull_buffer: Vec::new(),
+            center_of_mass: VisualPoint::new(
+                value.show_center_of_mass,
+                VisualPointProperties::new(
+                    value.default_point_radius * 2.0,
+                    Rgb::new(0, 255, 0),
+                    Alpha::OPAQUE,
+                ),
+            ),
+            curvature_comb: VisualComb::new(
+                value.show_curvature_comb,
+                VisualLineProperties::new(
+                    value.default_line_width / 2.0,
+                    value.overlay_color,
+                    Alpha::OPAQUE,
+                ),
+            ),
+            tangent_normal: VisualArrows::new(
+                value.show_tangent_normal,
+                VisualLineProperties::new(
+                    value.default_line_width,
+                    value.overlay_color,
+                    Alpha::OPAQUE,
+                ),
+            ),
+            osculating_circle: VisualCircles::new(
+                value.show_osculating_circle,
+                VisualLineProperties::new(
+                    value.default_line_width / 2.0,
+                    value.overlay_color,
+                    Alpha::OPAQUE,
+                ),
+            ),
+            hodograph: VisualLine::new(
+                value.show_hodograph,
+                VisualLineProperties::new(
+                    value.default_line_width,
+                    value.overlay_color,
+                    Alpha::OPAQUE,
+                ),
+            ),
+            overlay_parameter: 0.5,
+            comb_scale: value.default_comb_scale,
+        }
+    }
+}
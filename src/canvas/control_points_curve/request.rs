@@ -0,0 +1,50 @@
+use anyhow::anyhow;
+
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::shape::request::declare::{
+    GetCombScale, GetOverlayParameter, GetOverlayVisibility, SetCombScale, SetOverlayParameter,
+    SetOverlayVisibility,
+};
+use crate::request::{Error, RequestHandler, RequestHandlerMut, Response, ResponseMut};
+
+impl RequestHandler<GetOverlayVisibility> for VisualControlPoints {
+    fn handle(&self, event: GetOverlayVisibility) -> Response<GetOverlayVisibility> {
+        Ok(self.is_overlay_visible(event.0))
+    }
+}
+
+impl RequestHandlerMut<SetOverlayVisibility> for VisualControlPoints {
+    fn handle_mut(&mut self, event: SetOverlayVisibility) -> ResponseMut<SetOverlayVisibility> {
+        self.set_overlay_visible(event.0, event.1);
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetOverlayParameter> for VisualControlPoints {
+    fn handle(&self, _event: GetOverlayParameter) -> Response<GetOverlayParameter> {
+        Ok(self.overlay_parameter)
+    }
+}
+
+impl RequestHandlerMut<SetOverlayParameter> for VisualControlPoints {
+    fn handle_mut(&mut self, event: SetOverlayParameter) -> ResponseMut<SetOverlayParameter> {
+        if !(0.0..=1.0).contains(&event.0) {
+            return Err(Error::Other(anyhow!("overlay parameter should be in range [0, 1]")));
+        }
+        self.overlay_parameter = event.0;
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetCombScale> for VisualControlPoints {
+    fn handle(&self, _event: GetCombScale) -> Response<GetCombScale> {
+        Ok(self.comb_scale)
+    }
+}
+
+impl RequestHandlerMut<SetCombScale> for VisualControlPoints {
+    fn handle_mut(&mut self, event: SetCombScale) -> ResponseMut<SetCombScale> {
+        self.comb_scale = event.0;
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::math::vector::Vector;
+
+pub const TOLERANCE: f32 = 1e-3;
+
+const GAUSS_LEGENDRE: [(f32, f32); 5] = [
+    (0.0, 0.568_888_9),
+    (-0.538_469_3, 0.478_628_67),
+    (0.538_469_3, 0.478_628_67),
+    (-0.906_179_85, 0.236_926_89),
+    (0.906_179_85, 0.236_926_89),
+];
+const MAX_DEPTH: u32 = 16;
+const MAX_ITERATIONS: usize = 16;
+
+#[must_use]
+pub fn length(
+    derivative: impl Fn(f32) -> Vector<f32>,
+    start: f32,
+    end: f32,
+    tolerance: f32,
+) -> f32 {
+    let speed = |t| {
+        let derivative = derivative(t);
+        derivative.dot_product(derivative).sqrt()
+    };
+    let whole = quadrature(&speed, start, end);
+    adaptive_length(&speed, start, end, whole, tolerance, MAX_DEPTH)
+}
+
+#[must_use]
+pub fn equal_length_parameters(
+    derivative: impl Fn(f32) -> Vector<f32>,
+    count: usize,
+    tolerance: f32,
+) -> Vec<f32> {
+    let total = length(&derivative, 0.0, 1.0, tolerance);
+    if count < 2 || total <= f32::EPSILON {
+        return vec![0.0; count];
+    }
+    let step = total / (count - 1) as f32;
+    let mut parameters = vec![0.0];
+    let mut previous = 0.0;
+    for _ in 1..count - 1 {
+        let (mut low, mut high) = (previous, 1.0);
+        let mut t = (previous + step / total).min(1.0);
+        for _ in 0..MAX_ITERATIONS {
+            let difference = length(&derivative, previous, t, tolerance) - step;
+            if difference.abs() <= tolerance {
+                break;
+            }
+            if difference > 0.0 {
+                high = t;
+            } else {
+                low = t;
+            }
+            let derivative = derivative(t);
+            let speed = derivative.dot_product(derivative).sqrt();
+            let next = t - difference / speed;
+            t = if speed > f32::EPSILON && next > low && next < high {
+                next
+            } else {
+                (low + high) / 2.0
+            };
+        }
+        parameters.push(t);
+        previous = t;
+    }
+    parameters.push(1.0);
+    parameters
+}
+
+#[must_use]
+pub fn polyline_length(points: &[CurvePoint]) -> f32 {
+    points.windows(2).map(|pair| pair[0].distance_squared(pair[1]).sqrt()).sum()
+}
+
+#[must_use]
+pub fn resample_polyline(points: &[CurvePoint], count: usize) -> Vec<CurvePoint> {
+    let total = polyline_length(points);
+    if points.len() < 2 || count < 2 || total <= f32::EPSILON {
+        return points.iter().copied().take(1).cycle().take(count).collect();
+    }
+    let step = total / (count - 1) as f32;
+    let mut resampled = Vec::with_capacity(count);
+    let mut segments = points.windows(2);
+    let mut segment = segments.next().expect("polyline should have at least one segment");
+    let mut segment_start = 0.0;
+    for index in 0..count - 1 {
+        let target = index as f32 * step;
+        let mut segment_length = segment[0].distance_squared(segment[1]).sqrt();
+        while segment_start + segment_length < target {
+            let Some(next) = segments.next() else {
+                break;
+            };
+            segment_start += segment_length;
+            segment = next;
+            segment_length = segment[0].distance_squared(segment[1]).sqrt();
+        }
+        let ratio = if segment_length > f32::EPSILON {
+            ((target - segment_start) / segment_length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        resampled.push(segment[0] + (segment[1] - segment[0]) * ratio);
+    }
+    resampled.push(points[points.len() - 1]);
+    resampled
+}
+
+fn quadrature(speed: &impl Fn(f32) -> f32, start: f32, end: f32) -> f32 {
+    let half = (end - start) / 2.0;
+    let center = (start + end) / 2.0;
+    GAUSS_LEGENDRE.iter().map(|(node, weight)| weight * speed(center + half * node)).sum::<f32>()
+        * half
+}
+
+fn adaptive_length(
+    speed: &impl Fn(f32) -> f32,
+    start: f32,
+    end: f32,
+    whole: f32,
+    tolerance: f32,
+    depth: u32,
+) -> f32 {
+    let center = (start + end) / 2.0;
+    let left = quadrature(speed, start, center);
+    let right = quadrature(speed, center, end);
+    if depth == 0 || (left + right - whole).abs() <= tolerance {
+        return left + right;
+    }
+    let tolerance = tolerance / 2.0;
+    adaptive_length(speed, start, center, left, tolerance, depth - 1)
+        + adaptive_length(speed, center, end, right, tolerance, depth - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::math::point::Point;
+
+    #[test]
+    fn equal_length_parameters_divide_circle_evenly() {
+        let circle = |t: f32| {
+            let angle = t * std::f32::consts::PI;
+            Point::new(angle.cos(), angle.sin())
+        };
+        let derivative = |t: f32| {
+            let angle = t * std::f32::consts::PI;
+            Vector::new(-angle.sin(), angle.cos()) * std::f32::consts::PI
+        };
+        let length = length(derivative, 0.0, 1.0, 1e-5);
+        assert!((length - std::f32::consts::PI).abs() < 1e-4);
+
+        // Speed of the curve grows along it, so that equal steps in `t` are not equally long.
+        let squared = |t: f32| derivative(t * t) * (2.0 * t);
+        let parameters = equal_length_parameters(squared, 5, 1e-5);
+        let points = parameters.iter().map(|t| circle(t * t)).collect::<Vec<_>>();
+        let chord = points[0].distance_squared(points[1]).sqrt();
+        for pair in points.windows(2) {
+            assert!((pair[0].distance_squared(pair[1]).sqrt() - chord).abs() < 1e-4);
+        }
+    }
+}
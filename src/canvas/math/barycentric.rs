@@ -0,0 +1,118 @@
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::math;
+use crate::canvas::math::point::Point;
+
+/// Weights are kept in `f64`, because their ratios for many nodes do not fit in `f32`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BarycentricNodes {
+    nodes: Vec<f32>,
+    weights: Vec<f64>,
+}
+
+impl BarycentricNodes {
+    #[must_use]
+    pub fn chebyshev(length: usize) -> Self {
+        let nodes = (1..=length).map(|k| math::chebyshev(length, k)).collect();
+        let weights = (1..=length)
+            .map(|k| {
+                let angle = (2 * k - 1) as f64 * std::f64::consts::PI / (2 * length) as f64;
+                alternating_sign(k) * angle.sin()
+            })
+            .collect();
+        Self { nodes, weights }
+    }
+
+    #[must_use]
+    pub fn equally_spaced(length: usize) -> Self {
+        let last = length.saturating_sub(1);
+        let nodes =
+            (0..length).map(|k| if last == 0 { 0.0 } else { k as f32 / last as f32 }).collect();
+        let logarithms = (0..length)
+            .scan(0.0, |logarithm, k| {
+                if k > 0 {
+                    *logarithm += ((last - k + 1) as f64 / k as f64).ln();
+                }
+                Some(*logarithm)
+            })
+            .collect::<Vec<_>>();
+        let largest = logarithms.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let weights = logarithms
+            .into_iter()
+            .enumerate()
+            .map(|(k, logarithm)| alternating_sign(k) * (logarithm - largest).exp())
+            .collect();
+        Self { nodes, weights }
+    }
+
+    #[must_use]
+    pub fn nodes(&self) -> &[f32] {
+        &self.nodes
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    #[must_use]
+    pub fn interpolate(&self, t: f32, points: &[CurvePoint]) -> CurvePoint {
+        debug_assert_eq!(self.nodes.len(), points.len());
+        let mut numerator = (0.0, 0.0);
+        let mut denominator = 0.0;
+        for ((node, weight), point) in self.nodes.iter().zip(&self.weights).zip(points) {
+            let difference = f64::from(t) - f64::from(*node);
+            if difference == 0.0 {
+                return *point;
+            }
+            let coefficient = weight / difference;
+            numerator.0 += coefficient * f64::from(point.horizontal());
+            numerator.1 += coefficient * f64::from(point.vertical());
+            denominator += coefficient;
+        }
+        Point::new((numerator.0 / denominator) as f32, (numerator.1 / denominator) as f32)
+    }
+}
+
+fn alternating_sign(k: usize) -> f64 {
+    if k % 2 == 0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn barycentric_formula_agrees_with_lagrange_polynomial() {
+        let points = [(0.0, 0.0), (30.0, 90.0), (70.0, -90.0), (100.0, 0.0), (120.0, 40.0)];
+        let points = points.map(Point::from);
+        let (xs, ys): (Vec<_>, Vec<_>) = points.iter().copied().map(<(f32, f32)>::from).unzip();
+        for nodes in [BarycentricNodes::chebyshev(5), BarycentricNodes::equally_spaced(5)] {
+            for t in [0.05, 0.3, 0.45, 0.8] {
+                let expected = Point::new(
+                    math::lagrange(t, nodes.nodes(), &xs),
+                    math::lagrange(t, nodes.nodes(), &ys),
+                );
+                assert!(nodes.interpolate(t, &points).distance_squared(expected) < 1e-4);
+            }
+            for (node, point) in nodes.nodes().iter().zip(points) {
+                assert_eq!(nodes.interpolate(*node, &points), point);
+            }
+        }
+
+        let nodes = BarycentricNodes::equally_spaced(400);
+        assert!(nodes.weights.iter().all(|weight| weight.is_finite()));
+        let points =
+            nodes.nodes().iter().map(|node| Point::new(*node, 2.0 * node)).collect::<Vec<_>>();
+        let point = nodes.interpolate(0.5012, &points);
+        assert!(point.distance_squared(Point::new(0.5012, 1.0024)) < 1e-4);
+    }
+}
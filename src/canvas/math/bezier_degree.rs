@@ -0,0 +1,186 @@
+use crate::canvas::control_points::point::{CurvePoint, PointContainer};
+use crate::canvas::math;
+use crate::canvas::math::least_squares;
+use crate::canvas::math::point::Point;
+use crate::canvas::shape::rational_bezier::RationalBezierPoint;
+
+const SAMPLES_PER_POINT: usize = 8;
+pub const MAX_DEGREE: usize = 64;
+
+#[must_use]
+pub fn elevate(points: &[CurvePoint]) -> Vec<CurvePoint> {
+    let degree = points.len() as f32;
+    let mut elevated = Vec::with_capacity(points.len() + 1);
+    elevated.push(points[0]);
+    for (index, pair) in points.windows(2).enumerate() {
+        let ratio = (index + 1) as f32 / degree;
+        elevated.push(pair[0] + (pair[1] - pair[0]) * (1.0 - ratio));
+    }
+    elevated.push(points[points.len() - 1]);
+    elevated
+}
+
+#[must_use]
+pub fn rational_elevate(points: &[RationalBezierPoint]) -> Vec<RationalBezierPoint> {
+    let (points, weights) = into_homogeneous(points);
+    let weights = weights.into_iter().map(|weight| Point::new(weight, 0.0)).collect::<Vec<_>>();
+    from_homogeneous(&elevate(&points), &elevate(&weights))
+}
+
+/// Keeps the end points and returns the largest distance from the given curve.
+#[must_use]
+pub fn reduce(points: &[CurvePoint]) -> Option<(Vec<CurvePoint>, f32)> {
+    if points.len() < 3 {
+        return None;
+    }
+    let ts = parameters(points.len());
+    let samples = ts.iter().map(|t| math::de_casteljau(points, *t)).collect::<Vec<_>>();
+    let last = points[points.len() - 1];
+    let reduced = fit_with_ends(&ts, &samples, points[0], last, points.len() - 2)?;
+    let error = max_distance(&ts, &samples, |t| math::de_casteljau(&reduced, t));
+    Some((reduced, error))
+}
+
+/// Keeps the end points and returns the largest distance from the given curve.
+#[must_use]
+pub fn rational_reduce(points: &[RationalBezierPoint]) -> Option<(Vec<RationalBezierPoint>, f32)> {
+    if points.len() < 3 {
+        return None;
+    }
+    let (homogeneous, weights) = into_homogeneous(points);
+    let weights = weights.into_iter().map(|weight| Point::new(weight, 0.0)).collect::<Vec<_>>();
+    let ts = parameters(points.len());
+    let fit = |points: &[CurvePoint]| {
+        let samples = ts.iter().map(|t| math::de_casteljau(points, *t)).collect::<Vec<_>>();
+        fit_with_ends(&ts, &samples, points[0], points[points.len() - 1], points.len() - 2)
+    };
+    let reduced_weights = fit(&weights)?;
+    if reduced_weights.iter().any(|weight| weight.horizontal() <= 0.0) {
+        return None;
+    }
+    let reduced = from_homogeneous(&fit(&homogeneous)?, &reduced_weights);
+
+    let samples = ts.iter().map(|t| math::rational_de_casteljau(points, *t)).collect::<Vec<_>>();
+    let error = max_distance(&ts, &samples, |t| math::rational_de_casteljau(&reduced, t));
+    Some((reduced, error))
+}
+
+fn fit_with_ends(
+    ts: &[f32],
+    samples: &[CurvePoint],
+    first: CurvePoint,
+    last: CurvePoint,
+    degree: usize,
+) -> Option<Vec<CurvePoint>> {
+    let degree = degree as u32;
+    let origin = Point::new(0.0, 0.0);
+    let residuals = ts
+        .iter()
+        .zip(samples)
+        .map(|(t, sample)| {
+            let first = (origin - first) * math::bernstein(degree, 0, *t);
+            let last = (origin - last) * math::bernstein(degree, degree, *t);
+            *sample + (first + last)
+        })
+        .collect::<Vec<_>>();
+    let mut reduced = vec![first];
+    if degree > 1 {
+        let basis = |t| (1..degree).map(|k| math::bernstein(degree, k, t)).collect();
+        reduced.extend(least_squares::fit(ts, &residuals, basis)?);
+    }
+    reduced.push(last);
+    Some(reduced)
+}
+
+fn parameters(points: usize) -> Vec<f32> {
+    let samples = SAMPLES_PER_POINT * points;
+    (0..=samples).map(|index| index as f32 / samples as f32).collect()
+}
+
+fn max_distance(ts: &[f32], samples: &[CurvePoint], curve: impl Fn(f32) -> CurvePoint) -> f32 {
+    ts.iter()
+        .zip(samples)
+        .map(|(t, sample)| curve(*t).distance_squared(*sample))
+        .fold(0.0, f32::max)
+        .sqrt()
+}
+
+fn into_homogeneous(points: &[RationalBezierPoint]) -> (Vec<CurvePoint>, Vec<f32>) {
+    points
+        .iter()
+        .map(|point| {
+            let weight = point.weight();
+            let point = point.into_point();
+            (Point::new(point.horizontal() * weight, point.vertical() * weight), weight)
+        })
+        .unzip()
+}
+
+fn from_homogeneous(points: &[CurvePoint], weights: &[CurvePoint]) -> Vec<RationalBezierPoint> {
+    points
+        .iter()
+        .zip(weights)
+        .map(|(point, weight)| {
+            let weight = weight.horizontal();
+            let point = Point::new(point.horizontal() / weight, point.vertical() / weight);
+            RationalBezierPoint::new(point, weight)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduction_inverts_elevation() {
+        let points = [(0.0, 0.0), (1.0, 3.0), (3.0, 2.0), (4.0, 0.0)].map(Point::from);
+        let elevated = elevate(&points);
+        assert_eq!(elevated.len(), 5);
+        for t in [0.0, 0.2, 0.5, 0.7, 1.0] {
+            let expected = math::de_casteljau(&points, t);
+            assert!(expected.distance_squared(math::de_casteljau(&elevated, t)) < 1e-10);
+        }
+
+        let (reduced, error) = reduce(&elevated).unwrap();
+        assert!(error < 1e-4);
+        for (actual, expected) in reduced.iter().zip(points) {
+            assert!(actual.distance_squared(expected) < 1e-6);
+        }
+    }
+
+    #[test]
+    fn reduction_keeps_end_points() {
+        let points = [(0.0, 0.0), (1.0, 5.0), (2.0, -3.0), (3.0, 4.0), (5.0, 1.0)].map(Point::from);
+        let (reduced, error) = reduce(&points).unwrap();
+        assert_eq!(reduced.len(), 4);
+        assert_eq!(reduced[0], points[0]);
+        assert_eq!(reduced[3], points[4]);
+        assert!(error > 0.0);
+
+        let (line, _) = reduce(&points[..3]).unwrap();
+        assert_eq!(line, [points[0], points[2]]);
+    }
+
+    #[test]
+    fn rational_elevation_keeps_curve() {
+        let points = [((0.0, 0.0), 1.0), ((1.0, 3.0), 2.5), ((3.0, 2.0), 0.5), ((4.0, 0.0), 1.0)]
+            .map(|(point, weight)| RationalBezierPoint::new(Point::from(point), weight));
+        let elevated = rational_elevate(&points);
+        assert_eq!(elevated.len(), 5);
+        for t in [0.0, 0.2, 0.5, 0.7, 1.0] {
+            let expected = math::rational_de_casteljau(&points, t);
+            let actual = math::rational_de_casteljau(&elevated, t);
+            assert!(expected.distance_squared(actual) < 1e-8);
+        }
+
+        let (reduced, error) = rational_reduce(&elevated).unwrap();
+        assert!(error < 1e-3);
+        assert_eq!(reduced[0].into_point(), points[0].into_point());
+        assert_eq!(reduced[3].into_point(), points[3].into_point());
+        for (actual, expected) in reduced.iter().zip(points) {
+            assert!(actual.into_point().distance_squared(expected.into_point()) < 1e-4);
+            assert!((actual.weight() - expected.weight()).abs() < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use itertools::Itertools;
+
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::math;
+use crate::canvas::math::vector::Vector;
+use crate::canvas::shape::cubic_spline::SplineParametrisation;
+
+const MAX_REPARAMETRISATIONS: usize = 4;
+
+/// Returns control points laid out as `anchor, handle, handle, anchor, ...`.
+#[must_use]
+pub fn fit_cubic_spline(points: &[CurvePoint], tolerance: f32) -> Vec<CurvePoint> {
+    let points = points
+        .iter()
+        .copied()
+        .dedup_by(|previous, next| previous.distance_squared(*next) <= f32::EPSILON)
+        .collect::<Vec<_>>();
+    if points.len() < 2 {
+        return points;
+    }
+    let last = points.len() - 1;
+    let start_tangent = normalised(points[1] - points[0]);
+    let end_tangent = normalised(points[last - 1] - points[last]);
+    let mut spline = vec![points[0]];
+    fit_segments(&points, start_tangent, end_tangent, tolerance * tolerance, &mut spline);
+    spline
+}
+
+fn fit_segments(
+    points: &[CurvePoint],
+    start_tangent: Vector<f32>,
+    end_tangent: Vector<f32>,
+    squared_tolerance: f32,
+    spline: &mut Vec<CurvePoint>,
+) {
+    if points.len() == 2 {
+        let distance = length(points[1] - points[0]) / 3.0;
+        spline.extend([
+            points[0] + start_tangent * distance,
+            points[1] + end_tangent * distance,
+            points[1],
+        ]);
+        return;
+    }
+
+    let mut parameters = SplineParametrisation::Chordal.knots(points);
+    let mut segment = fit_segment(points, &parameters, start_tangent, end_tangent);
+    let (mut error, mut split) = max_error(points, &segment, &parameters);
+    if error > squared_tolerance && error < 4.0 * squared_tolerance {
+        for _ in 0..MAX_REPARAMETRISATIONS {
+            reparametrise(points, &segment, &mut parameters);
+            segment = fit_segment(points, &parameters, start_tangent, end_tangent);
+            (error, split) = max_error(points, &segment, &parameters);
+            if error <= squared_tolerance {
+                break;
+            }
+        }
+    }
+    if error <= squared_tolerance {
+        spline.extend_from_slice(&segment[1..]);
+        return;
+    }
+
+    let center_tangent = normalised(points[split - 1] - points[split + 1]);
+    fit_segments(&points[..=split], start_tangent, center_tangent, squared_tolerance, spline);
+    let center_tangent = center_tangent * -1.0;
+    fit_segments(&points[split..], center_tangent, end_tangent, squared_tolerance, spline);
+}
+
+fn fit_segment(
+    points: &[CurvePoint],
+    parameters: &[f32],
+    start_tangent: Vector<f32>,
+    end_tangent: Vector<f32>,
+) -> [CurvePoint; 4] {
+    let first = points[0];
+    let last = points[points.len() - 1];
+    let mut matrix = [[0.0; 2]; 2];
+    let mut right = [0.0; 2];
+    for (point, t) in points.iter().zip(parameters) {
+        let start = start_tangent * math::bernstein(3, 1, *t);
+        let end = end_tangent * math::bernstein(3, 2, *t);
+        let end_weight = math::bernstein(3, 2, *t) + math::bernstein(3, 3, *t);
+        let remainder = (*point - first) - (last - first) * end_weight;
+        matrix[0][0] += start.dot_product(start);
+        matrix[0][1] += start.dot_product(end);
+        matrix[1][1] += end.dot_product(end);
+        right[0] += start.dot_product(remainder);
+        right[1] += end.dot_product(remainder);
+    }
+
+    let determinant = matrix[0][0] * matrix[1][1] - matrix[0][1] * matrix[0][1];
+    let (mut start_length, mut end_length) = if determinant.abs() > f32::EPSILON {
+        (
+            (right[0] * matrix[1][1] - right[1] * matrix[0][1]) / determinant,
+            (matrix[0][0] * right[1] - matrix[0][1] * right[0]) / determinant,
+        )
+    } else {
+        (0.0, 0.0)
+    };
+    let chord_length = length(last - first);
+    let epsilon = 1e-6 * chord_length;
+    if start_length < epsilon || end_length < epsilon {
+        start_length = chord_length / 3.0;
+        end_length = chord_length / 3.0;
+    }
+    [first, first + start_tangent * start_length, last + end_tangent * end_length, last]
+}
+
+fn max_error(points: &[CurvePoint], segment: &[CurvePoint; 4], parameters: &[f32]) -> (f32, usize) {
+    (1..points.len() - 1)
+        .map(|index| {
+            let error =
+                math::de_casteljau(segment, parameters[index]).distance_squared(points[index]);
+            (error, index)
+        })
+        .fold((0.0, points.len() / 2), |max, current| if current.0 > max.0 { current } else { max })
+}
+
+fn reparametrise(points: &[CurvePoint], segment: &[CurvePoint; 4], parameters: &mut [f32]) {
+    let derivative = [0, 1, 2].map(|index| (segment[index + 1] - segment[index]) * 3.0);
+    let second_derivative = [0, 1].map(|index| (derivative[index + 1] - derivative[index]) * 2.0);
+    for (point, t) in points.iter().zip(parameters.iter_mut()) {
+        let u = 1.0 - *t;
+        let difference = math::de_casteljau(segment, *t) - *point;
+        let first =
+            derivative[0] * (u * u) + derivative[1] * (2.0 * u * *t) + derivative[2] * (*t * *t);
+        let second = second_derivative[0] * u + second_derivative[1] * *t;
+        let denominator = first.dot_product(first) + difference.dot_product(second);
+        if denominator.abs() > f32::EPSILON {
+            *t = (*t - difference.dot_product(first) / denominator).clamp(0.0, 1.0);
+        }
+    }
+}
+
+fn length(vector: Vector<f32>) -> f32 {
+    vector.dot_product(vector).sqrt()
+}
+
+fn normalised(vector: Vector<f32>) -> Vector<f32> {
+    let length = length(vector);
+    if length > f32::EPSILON {
+        vector / length
+    } else {
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canvas::math::point::Point;
+
+    use super::*;
+
+    #[test]
+    fn fits_every_point_within_tolerance() {
+        let points = (0..=40)
+            .map(|index| {
+                let t = index as f32 / 40.0;
+                if t < 0.5 {
+                    Point::new(100.0 * t, 50.0 * f32::sin(t * 10.0))
+                } else {
+                    Point::new(50.0, 100.0 * t)
+                }
+            })
+            .collect::<Vec<_>>();
+        let tolerance = 1.0;
+        let spline = fit_cubic_spline(&points, tolerance);
+        assert_eq!(spline.len() % 3, 1);
+        assert!(spline.len() > 4);
+
+        let path = spline
+            .windows(4)
+            .step_by(3)
+            .flat_map(|segment| (0..=200).map(|t| math::de_casteljau(segment, t as f32 / 200.0)))
+            .collect::<Vec<_>>();
+        for point in points {
+            let distance = path
+                .iter()
+                .map(|sample| sample.distance_squared(point))
+                .fold(f32::INFINITY, f32::min);
+            assert!(distance.sqrt() < tolerance * 1.1);
+        }
+    }
+
+    #[test]
+    fn fits_cubic_with_single_segment() {
+        let segment = [(0.0, 0.0), (30.0, 60.0), (70.0, 60.0), (100.0, 0.0)].map(Point::from);
+        let points =
+            (0..=30).map(|index| math::de_casteljau(&segment, index as f32 / 30.0)).collect_vec();
+        let spline = fit_cubic_spline(&points, 4.0);
+        assert_eq!(spline.len(), 4);
+    }
+}
@@ -0,0 +1,207 @@
+#[derive(Debug, Clone)]
+pub struct CubicSpline {
+    knots: Vec<f32>,
+    values: Vec<f32>,
+    moments: Vec<f32>,
+}
+
+impl CubicSpline {
+    #[must_use]
+    pub fn natural(knots: Vec<f32>, values: Vec<f32>) -> Self {
+        Self::with_boundary(knots, values, None)
+    }
+
+    #[must_use]
+    pub fn clamped(knots: Vec<f32>, values: Vec<f32>, start: f32, end: f32) -> Self {
+        Self::with_boundary(knots, values, Some((start, end)))
+    }
+
+    /// The last value should be equal to the first one.
+    #[must_use]
+    pub fn periodic(knots: Vec<f32>, values: Vec<f32>) -> Self {
+        debug_assert_eq!(knots.len(), values.len());
+        let length = knots.len() - 1;
+        let steps = steps(&knots);
+        let slopes = slopes(&steps, &values);
+
+        let mut lower = Vec::with_capacity(length);
+        let mut diagonal = Vec::with_capacity(length);
+        let mut upper = Vec::with_capacity(length);
+        let mut right = Vec::with_capacity(length);
+        for index in 0..length {
+            let previous = (index + length - 1) % length;
+            lower.push(steps[previous]);
+            diagonal.push(2.0 * (steps[previous] + steps[index]));
+            upper.push(steps[index]);
+            right.push(6.0 * (slopes[index] - slopes[previous]));
+        }
+
+        let mut moments = solve_cyclic_tridiagonal(&lower, &diagonal, &upper, &right);
+        moments.push(moments[0]);
+        Self { knots, values, moments }
+    }
+
+    fn with_boundary(knots: Vec<f32>, values: Vec<f32>, derivatives: Option<(f32, f32)>) -> Self {
+        debug_assert_eq!(knots.len(), values.len());
+        let length = knots.len();
+        let steps = steps(&knots);
+        let slopes = slopes(&steps, &values);
+
+        let mut lower = vec![0.0; length];
+        let mut diagonal = vec![1.0; length];
+        let mut upper = vec![0.0; length];
+        let mut right = vec![0.0; length];
+        for index in 1..length - 1 {
+            lower[index] = steps[index - 1];
+            diagonal[index] = 2.0 * (steps[index - 1] + steps[index]);
+            upper[index] = steps[index];
+            right[index] = 6.0 * (slopes[index] - slopes[index - 1]);
+        }
+        if let Some((start, end)) = derivatives {
+            let last = length - 1;
+            diagonal[0] = 2.0 * steps[0];
+            upper[0] = steps[0];
+            right[0] = 6.0 * (slopes[0] - start);
+            lower[last] = steps[last - 1];
+            diagonal[last] = 2.0 * steps[last - 1];
+            right[last] = 6.0 * (end - slopes[last - 1]);
+        }
+
+        let moments = solve_tridiagonal(&lower, &diagonal, &upper, &right);
+        Self { knots, values, moments }
+    }
+
+    #[must_use]
+    pub fn evaluate(&self, t: f32) -> f32 {
+        let segments = self.knots.len() - 1;
+        let index =
+            self.knots.partition_point(|knot| *knot <= t).saturating_sub(1).min(segments - 1);
+        let (start, end) = (self.knots[index], self.knots[index + 1]);
+        let step = end - start;
+        let (left, right) = (end - t, t - start);
+        let (moment_start, moment_end) = (self.moments[index], self.moments[index + 1]);
+        (moment_start * left.powi(3) + moment_end * right.powi(3)) / (6.0 * step)
+            + (self.values[index] / step - moment_start * step / 6.0) * left
+            + (self.values[index + 1] / step - moment_end * step / 6.0) * right
+    }
+}
+
+fn steps(knots: &[f32]) -> Vec<f32> {
+    knots.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
+
+fn slopes(steps: &[f32], values: &[f32]) -> Vec<f32> {
+    values.windows(2).zip(steps).map(|(pair, step)| (pair[1] - pair[0]) / step).collect()
+}
+
+/// Ignores `lower[0]` and `upper[n - 1]`.
+#[must_use]
+pub fn solve_tridiagonal(
+    lower: &[f32],
+    diagonal: &[f32],
+    upper: &[f32],
+    right: &[f32],
+) -> Vec<f32> {
+    let length = diagonal.len();
+    let mut upper_prime = Vec::with_capacity(length);
+    let mut right_prime = Vec::with_capacity(length);
+    upper_prime.push(upper[0] / diagonal[0]);
+    right_prime.push(right[0] / diagonal[0]);
+    for index in 1..length {
+        let denominator = diagonal[index] - lower[index] * upper_prime[index - 1];
+        upper_prime.push(upper[index] / denominator);
+        right_prime.push((right[index] - lower[index] * right_prime[index - 1]) / denominator);
+    }
+
+    let mut solution = right_prime;
+    for index in (0..length - 1).rev() {
+        solution[index] -= upper_prime[index] * solution[index + 1];
+    }
+    solution
+}
+
+/// Treats `lower[0]` and `upper[n - 1]` as corner elements.
+#[must_use]
+pub fn solve_cyclic_tridiagonal(
+    lower: &[f32],
+    diagonal: &[f32],
+    upper: &[f32],
+    right: &[f32],
+) -> Vec<f32> {
+    let length = diagonal.len();
+    match length {
+        1 => return vec![right[0] / (diagonal[0] + lower[0] + upper[0])],
+        2 => {
+            let upper = [upper[0] + lower[0], 0.0];
+            let lower = [0.0, lower[1] + upper[1]];
+            return solve_tridiagonal(&lower, diagonal, &upper, right);
+        }
+        _ => {}
+    }
+
+    let last = length - 1;
+    let top = lower[0];
+    let bottom = upper[last];
+    let gamma = -diagonal[0];
+    let mut modified = diagonal.to_vec();
+    modified[0] -= gamma;
+    modified[last] -= bottom * top / gamma;
+
+    let solution = solve_tridiagonal(lower, &modified, upper, right);
+    let mut correction = vec![0.0; length];
+    correction[0] = gamma;
+    correction[last] = bottom;
+    let correction = solve_tridiagonal(lower, &modified, upper, &correction);
+
+    let factor = (solution[0] + top * solution[last] / gamma)
+        / (1.0 + correction[0] + top * correction[last] / gamma);
+    solution.iter().zip(correction).map(|(value, correction)| value - factor * correction).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cyclic_tridiagonal() {
+        let lower = [1.0, 1.0, 1.0, 1.0];
+        let diagonal = [4.0, 4.0, 4.0, 4.0];
+        let upper = [1.0, 1.0, 1.0, 1.0];
+        let expected = [1.0, 2.0, 3.0, 4.0];
+        let right = [4.0 + 2.0 + 4.0, 1.0 + 8.0 + 3.0, 2.0 + 12.0 + 4.0, 3.0 + 16.0 + 1.0];
+        let solution = solve_cyclic_tridiagonal(&lower, &diagonal, &upper, &right);
+        for (value, expected) in solution.into_iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-5, "{value} != {expected}");
+        }
+    }
+
+    #[test]
+    fn clamped_reproduces_cubic() {
+        let cubic = |t: f32| t * t * t - 2.0 * t + 1.0;
+        let derivative = |t: f32| 3.0 * t * t - 2.0;
+        let knots = vec![0.0, 0.5, 1.5, 2.0, 3.0];
+        let values = knots.iter().copied().map(cubic).collect();
+        let spline = CubicSpline::clamped(knots, values, derivative(0.0), derivative(3.0));
+        for t in [0.1, 0.7, 1.2, 2.5, 2.9] {
+            assert!((spline.evaluate(t) - cubic(t)).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn natural_reproduces_line() {
+        let spline = CubicSpline::natural(vec![0.0, 1.0, 3.0], vec![1.0, 3.0, 7.0]);
+        assert!((spline.evaluate(2.0) - 5.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn periodic_approximates_sine() {
+        let knots =
+            (0..=16).map(|index| index as f32 * std::f32::consts::TAU / 16.0).collect::<Vec<_>>();
+        let mut values = knots.iter().copied().map(f32::sin).collect::<Vec<_>>();
+        values[16] = values[0];
+        let spline = CubicSpline::periodic(knots, values);
+        for t in [0.1, 1.0, 3.0, 6.2] {
+            assert!((spline.evaluate(t) - t.sin()).abs() < 1e-3);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::math::vector::Vector;
+
+/// Normal is turned left from the tangent and curvature is positive where the curve turns left.
+#[derive(Debug, Copy, Clone)]
+pub struct FrenetFrame {
+    pub point: CurvePoint,
+    pub tangent: Vector<f32>,
+    pub normal: Vector<f32>,
+    pub curvature: f32,
+}
+
+impl FrenetFrame {
+    #[must_use]
+    pub fn curvature_center(&self) -> Option<CurvePoint> {
+        (self.curvature.abs() > f32::EPSILON).then(|| self.point + self.normal / self.curvature)
+    }
+}
+
+#[must_use]
+pub fn menger_curvature(previous: CurvePoint, vertex: CurvePoint, next: CurvePoint) -> f32 {
+    let (incoming, outgoing) = (vertex - previous, next - vertex);
+    let chord = previous.distance_squared(next).sqrt();
+    let lengths = length(incoming) * length(outgoing) * chord;
+    if lengths > f32::EPSILON {
+        2.0 * incoming.cross_product_magnitude(outgoing) / lengths
+    } else {
+        0.0
+    }
+}
+
+/// Returns no frames for polylines with fewer than three points.
+#[must_use]
+pub fn polyline_frames(points: &[CurvePoint]) -> Vec<FrenetFrame> {
+    if points.len() < 3 {
+        return Vec::new();
+    }
+    let last = points.len() - 1;
+    (0..=last)
+        .map(|index| {
+            let center = index.clamp(1, last - 1);
+            let (previous, next) = (points[index.saturating_sub(1)], points[(index + 1).min(last)]);
+            let tangent = normalised(next - previous);
+            let normal = Vector::new(-tangent.vertical(), tangent.horizontal());
+            let curvature =
+                menger_curvature(points[center - 1], points[center], points[center + 1]);
+            FrenetFrame { point: points[index], tangent, normal, curvature }
+        })
+        .collect()
+}
+
+/// Returns frame at `t` in `[0, 1]` along the frames, which are treated as equally spaced.
+#[must_use]
+pub fn frame_at(frames: &[FrenetFrame], t: f32) -> Option<FrenetFrame> {
+    let last_segment = frames.len().checked_sub(2)?;
+    let position = t.clamp(0.0, 1.0) * (last_segment + 1) as f32;
+    let index = (position.floor() as usize).min(last_segment);
+    let ratio = position - index as f32;
+    let (start, end) = (frames[index], frames[index + 1]);
+    let tangent = normalised(start.tangent * (1.0 - ratio) + end.tangent * ratio);
+    Some(FrenetFrame {
+        point: start.point + (end.point - start.point) * ratio,
+        tangent,
+        normal: Vector::new(-tangent.vertical(), tangent.horizontal()),
+        curvature: start.curvature * (1.0 - ratio) + end.curvature * ratio,
+    })
+}
+
+fn length(vector: Vector<f32>) -> f32 {
+    vector.dot_product(vector).sqrt()
+}
+
+fn normalised(vector: Vector<f32>) -> Vector<f32> {
+    let length = length(vector);
+    if length > f32::EPSILON {
+        vector / length
+    } else {
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::math::point::Point;
+
+    #[test]
+    fn frames_of_circle_point_to_its_center() {
+        let circle = (0..=100)
+            .map(|index| {
+                let angle = index as f32 / 100.0 * std::f32::consts::PI;
+                Point::new(50.0 * angle.cos(), 50.0 * angle.sin())
+            })
+            .collect::<Vec<_>>();
+        let frame = frame_at(&polyline_frames(&circle), 0.5).unwrap();
+        assert!((frame.curvature - 1.0 / 50.0).abs() < 1e-4);
+        assert!(frame.point.distance_squared(Point::new(0.0, 50.0)) < 1e-6);
+        let center = frame.curvature_center().unwrap();
+        assert!(center.distance_squared(Point::new(0.0, 0.0)) < 1e-2);
+    }
+}
@@ -0,0 +1,245 @@
+use itertools::Itertools;
+
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::math;
+use crate::canvas::math::curvature;
+use crate::canvas::math::intersection;
+use crate::canvas::math::point::Point;
+use crate::canvas::math::vector::Vector;
+
+const CUSP_ANGLE: f32 = 0.75 * std::f32::consts::PI;
+const STRAIGHT_ANGLE: f32 = 1e-4;
+/// Relative to the highest speed of the curve.
+const CUSP_SPEED: f32 = 1e-3;
+/// Relative to the largest absolute curvature.
+const EXTREMUM_PROMINENCE: f32 = 0.05;
+const BEZIER_SAMPLES: usize = 256;
+const ITERATIONS: usize = 32;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CurveFeatures {
+    pub self_intersections: Vec<CurvePoint>,
+    pub inflections: Vec<CurvePoint>,
+    pub cusps: Vec<CurvePoint>,
+    pub curvature_extrema: Vec<CurvePoint>,
+}
+
+/// Treats the polyline as closed if its first and last points are equal.
+#[must_use]
+pub fn polyline_features(points: &[CurvePoint]) -> CurveFeatures {
+    let points = deduplicated(points);
+    let mut features = CurveFeatures {
+        self_intersections: self_intersections(&points),
+        ..CurveFeatures::default()
+    };
+    let closed = points.len() > 3 && points[0].distance_squared(points[points.len() - 1]) == 0.0;
+    let vertices = if closed {
+        points.iter().chain([&points[1]]).copied().tuple_windows().collect::<Vec<_>>()
+    } else {
+        points.iter().copied().tuple_windows().collect::<Vec<_>>()
+    };
+
+    let mut curvatures = Vec::with_capacity(vertices.len());
+    let mut previous_sign = None;
+    for (previous, vertex, next) in vertices {
+        let (incoming, outgoing) = (vertex - previous, next - vertex);
+        let angle =
+            incoming.cross_product_magnitude(outgoing).atan2(incoming.dot_product(outgoing));
+        if angle.abs() >= CUSP_ANGLE {
+            features.cusps.push(vertex);
+            previous_sign = None;
+            continue;
+        }
+        if angle.abs() > STRAIGHT_ANGLE {
+            let sign = angle.is_sign_positive();
+            if previous_sign.is_some_and(|(previous_sign, _)| previous_sign != sign) {
+                let (_, previous_vertex) = previous_sign.expect("sign should be checked");
+                features.inflections.push(previous_vertex + (vertex - previous_vertex) * 0.5);
+            }
+            previous_sign = Some((sign, vertex));
+        }
+        curvatures.push((vertex, curvature::menger_curvature(previous, vertex, next)));
+    }
+
+    let values = curvatures.iter().map(|(_, curvature)| *curvature).collect::<Vec<_>>();
+    features.curvature_extrema =
+        extrema(&values).into_iter().map(|(index, _)| curvatures[index].0).collect();
+    features
+}
+
+/// Finds self-intersections on the sampled path and the other features from the derivatives.
+#[must_use]
+pub fn bezier_features(points: &[CurvePoint], path: &[CurvePoint]) -> CurveFeatures {
+    let mut features = CurveFeatures {
+        self_intersections: self_intersections(&deduplicated(path)),
+        ..CurveFeatures::default()
+    };
+    if points.len() < 3 {
+        return features;
+    }
+    let first = math::hodograph(points);
+    let second = math::hodograph(&first);
+    let derivative = |t| math::de_casteljau(&first, t) - Point::zero();
+    let second_derivative = |t| math::de_casteljau(&second, t) - Point::zero();
+    let speed = |t| length(derivative(t));
+    let cross = |t| derivative(t).cross_product_magnitude(second_derivative(t));
+    let curvature = |t| {
+        let speed = speed(t);
+        if speed > f32::EPSILON {
+            cross(t) / (speed * speed * speed)
+        } else {
+            0.0
+        }
+    };
+
+    let step = 1.0 / BEZIER_SAMPLES as f32;
+    let ts = (0..=BEZIER_SAMPLES).map(|index| index as f32 * step).collect::<Vec<_>>();
+    let speeds = ts.iter().map(|t| speed(*t)).collect::<Vec<_>>();
+    let max_speed = speeds.iter().copied().fold(0.0, f32::max);
+    let cusps = (1..BEZIER_SAMPLES)
+        .filter(|index| speeds[index - 1] >= speeds[*index] && speeds[*index] < speeds[index + 1])
+        .map(|index| golden_section(speed, ts[index - 1], ts[index + 1]))
+        .filter(|t| speed(*t) <= CUSP_SPEED * max_speed)
+        .collect::<Vec<_>>();
+    let near_cusp = |t: f32| cusps.iter().any(|cusp| (cusp - t).abs() <= 2.0 * step);
+
+    let mut previous: Option<f32> = None;
+    for t in ts.iter().copied() {
+        let value = cross(t);
+        if value == 0.0 {
+            continue;
+        }
+        if let Some(start) = previous.filter(|start| cross(*start) * value < 0.0) {
+            let root = bisection(cross, start, t);
+            if !near_cusp(root) {
+                features.inflections.push(math::de_casteljau(points, root));
+            }
+        }
+        previous = Some(t);
+    }
+
+    let ts = ts.into_iter().filter(|t| !near_cusp(*t)).collect::<Vec<_>>();
+    let curvatures = ts.iter().map(|t| curvature(*t)).collect::<Vec<_>>();
+    features.curvature_extrema = extrema(&curvatures)
+        .into_iter()
+        .map(|(index, maximum)| {
+            let start = ts[index.saturating_sub(1)];
+            let end = ts[(index + 1).min(ts.len() - 1)];
+            let t = if maximum {
+                golden_section(|t| -curvature(t), start, end)
+            } else {
+                golden_section(curvature, start, end)
+            };
+            math::de_casteljau(points, t)
+        })
+        .collect();
+
+    features.cusps = cusps.into_iter().map(|t| math::de_casteljau(points, t)).collect();
+    features
+}
+
+fn self_intersections(path: &[CurvePoint]) -> Vec<CurvePoint> {
+    intersection::polyline_self_intersections(path)
+        .into_iter()
+        .map(|intersection| intersection.point)
+        .collect()
+}
+
+fn deduplicated(points: &[CurvePoint]) -> Vec<CurvePoint> {
+    points
+        .iter()
+        .copied()
+        .dedup_by(|previous, next| previous.distance_squared(*next) == 0.0)
+        .collect()
+}
+
+/// Returns indices of interior extrema of the values, each with whether it is a maximum.
+fn extrema(values: &[f32]) -> Vec<(usize, bool)> {
+    let delta = EXTREMUM_PROMINENCE * values.iter().map(|value| value.abs()).fold(0.0, f32::max);
+    let mut extrema = Vec::new();
+    let (mut maximum, mut minimum) = ((0, f32::NEG_INFINITY), (0, f32::INFINITY));
+    let mut looking_for_maximum = None;
+    for (index, value) in values.iter().copied().enumerate() {
+        if value > maximum.1 {
+            maximum = (index, value);
+        }
+        if value < minimum.1 {
+            minimum = (index, value);
+        }
+        if looking_for_maximum != Some(false) && value < maximum.1 - delta {
+            extrema.push((maximum.0, true));
+            minimum = (index, value);
+            looking_for_maximum = Some(false);
+        } else if looking_for_maximum != Some(true) && value > minimum.1 + delta {
+            extrema.push((minimum.0, false));
+            maximum = (index, value);
+            looking_for_maximum = Some(true);
+        }
+    }
+    extrema.retain(|(index, _)| *index != 0 && *index != values.len() - 1);
+    extrema
+}
+
+fn golden_section(function: impl Fn(f32) -> f32, mut start: f32, mut end: f32) -> f32 {
+    let ratio = (5.0_f32.sqrt() - 1.0) / 2.0;
+    for _ in 0..ITERATIONS {
+        let left = end - ratio * (end - start);
+        let right = start + ratio * (end - start);
+        if function(left) < function(right) {
+            end = right;
+        } else {
+            start = left;
+        }
+    }
+    (start + end) / 2.0
+}
+
+fn bisection(function: impl Fn(f32) -> f32, mut start: f32, mut end: f32) -> f32 {
+    let start_sign = function(start).is_sign_positive();
+    for _ in 0..ITERATIONS {
+        let center = (start + end) / 2.0;
+        if function(center).is_sign_positive() == start_sign {
+            start = center;
+        } else {
+            end = center;
+        }
+    }
+    (start + end) / 2.0
+}
+
+fn length(vector: Vector<f32>) -> f32 {
+    vector.dot_product(vector).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_features_of_cubic_bezier_curves() {
+        let points = [(0.0, 0.0), (150.0, 100.0), (-50.0, 100.0), (100.0, 0.0)].map(Point::from);
+        let path = (0..=200).map(|t| math::de_casteljau(&points, t as f32 / 200.0)).collect_vec();
+        let features = bezier_features(&points, &path);
+        assert_eq!(features.self_intersections.len(), 1);
+        assert!(features.cusps.is_empty());
+        assert!(features.inflections.is_empty());
+        assert_eq!(polyline_features(&path).self_intersections.len(), 1);
+
+        let points = [(0.0, 0.0), (100.0, 100.0), (0.0, 100.0), (100.0, 0.0)].map(Point::from);
+        let features = bezier_features(&points, &[]);
+        assert_eq!(features.cusps.len(), 1);
+        assert!(features.cusps[0].distance_squared(Point::new(50.0, 75.0)) < 1e-2);
+
+        let points = [(0.0, 0.0), (100.0, 100.0), (0.0, 100.0), (100.0, 200.0)].map(Point::from);
+        let features = bezier_features(&points, &[]);
+        assert_eq!(features.inflections.len(), 1);
+        assert!(features.inflections[0].distance_squared(Point::new(50.0, 100.0)) < 1e-2);
+        assert_eq!(features.curvature_extrema.len(), 2);
+
+        let path = (0..=500).map(|t| math::de_casteljau(&points, t as f32 / 500.0)).collect_vec();
+        let features = polyline_features(&path);
+        assert_eq!(features.inflections.len(), 1);
+        assert_eq!(features.curvature_extrema.len(), 2);
+        assert!(features.cusps.is_empty());
+    }
+}
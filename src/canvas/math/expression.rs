@@ -0,0 +1,425 @@
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+use std::{f32, fmt};
+
+use anyhow::{anyhow, Result};
+
+/// Supports `+`, `-`, `*`, `/`, `^`, constants `pi` and `e` and [`Function`]s of variables.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Expression {
+    source: String,
+    root: Node,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Number(f32),
+    Variable(String),
+    Negate(Box<Node>),
+    Binary(Operator, Box<Node>, Box<Node>),
+    Call(Function, Vec<Node>),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Power,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, parse_display::Display, parse_display::FromStr)]
+#[display(style = "lowercase")]
+pub enum Function {
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Atan2,
+    Sinh,
+    Cosh,
+    Tanh,
+    Exp,
+    Ln,
+    Sqrt,
+    Abs,
+    Floor,
+    Ceil,
+    Pow,
+    Min,
+    Max,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f32),
+    Identifier(String),
+    Operator(Operator),
+    LeftParenthesis,
+    RightParenthesis,
+    Comma,
+}
+
+impl Expression {
+    /// Unknown variables evaluate to NaN.
+    #[must_use]
+    pub fn evaluate(&self, variable: &impl Fn(&str) -> Option<f32>) -> f32 {
+        self.root.evaluate(variable)
+    }
+
+    #[must_use]
+    pub fn variables(&self) -> Vec<&str> {
+        let mut variables = Vec::new();
+        self.root.collect_variables(&mut variables);
+        variables
+    }
+
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+impl Node {
+    fn evaluate(&self, variable: &impl Fn(&str) -> Option<f32>) -> f32 {
+        match self {
+            Node::Number(value) => *value,
+            Node::Variable(name) => variable(name).unwrap_or(f32::NAN),
+            Node::Negate(node) => -node.evaluate(variable),
+            Node::Binary(operator, left, right) => {
+                let left = left.evaluate(variable);
+                let right = right.evaluate(variable);
+                match operator {
+                    Operator::Add => left + right,
+                    Operator::Subtract => left - right,
+                    Operator::Multiply => left * right,
+                    Operator::Divide => left / right,
+                    Operator::Power => left.powf(right),
+                }
+            }
+            Node::Call(function, arguments) => {
+                let first = arguments[0].evaluate(variable);
+                let second = || arguments[1].evaluate(variable);
+                match function {
+                    Function::Sin => first.sin(),
+                    Function::Cos => first.cos(),
+                    Function::Tan => first.tan(),
+                    Function::Asin => first.asin(),
+                    Function::Acos => first.acos(),
+                    Function::Atan => first.atan(),
+                    Function::Atan2 => first.atan2(second()),
+                    Function::Sinh => first.sinh(),
+                    Function::Cosh => first.cosh(),
+                    Function::Tanh => first.tanh(),
+                    Function::Exp => first.exp(),
+                    Function::Ln => first.ln(),
+                    Function::Sqrt => first.sqrt(),
+                    Function::Abs => first.abs(),
+                    Function::Floor => first.floor(),
+                    Function::Ceil => first.ceil(),
+                    Function::Pow => first.powf(second()),
+                    Function::Min => first.min(second()),
+                    Function::Max => first.max(second()),
+                }
+            }
+        }
+    }
+
+    fn collect_variables<'a>(&'a self, variables: &mut Vec<&'a str>) {
+        match self {
+            Node::Number(_) => {}
+            Node::Variable(name) => {
+                if !variables.contains(&name.as_str()) {
+                    variables.push(name);
+                }
+            }
+            Node::Negate(node) => node.collect_variables(variables),
+            Node::Binary(_, left, right) => {
+                left.collect_variables(variables);
+                right.collect_variables(variables);
+            }
+            Node::Call(_, arguments) => {
+                for argument in arguments {
+                    argument.collect_variables(variables);
+                }
+            }
+        }
+    }
+}
+
+impl Function {
+    #[must_use]
+    pub fn arity(self) -> usize {
+        match self {
+            Function::Atan2 | Function::Pow | Function::Min | Function::Max => 2,
+            _ => 1,
+        }
+    }
+}
+
+#[must_use]
+pub fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let starts_with_letter = chars.next().is_some_and(|char| char.is_alphabetic() || char == '_');
+    starts_with_letter
+        && chars.all(|char| char.is_alphanumeric() || char == '_')
+        && builtin_constant(name).is_none()
+        && name.parse::<Function>().is_err()
+}
+
+fn builtin_constant(name: &str) -> Option<f32> {
+    match name {
+        "pi" => Some(f32::consts::PI),
+        "e" => Some(f32::consts::E),
+        _ => None,
+    }
+}
+
+/// Recursive descent parser, where `^` is right associative and binds tighter than unary minus.
+struct Parser {
+    tokens: Peekable<std::vec::IntoIter<Token>>,
+}
+
+impl Parser {
+    fn parse(input: &str) -> Result<Node> {
+        let tokens = tokenize(input)?;
+        let mut parser = Self { tokens: tokens.into_iter().peekable() };
+        let node = parser.sum()?;
+        if let Some(token) = parser.tokens.next() {
+            return Err(anyhow!("unexpected {token} after the end of expression"));
+        }
+        Ok(node)
+    }
+
+    fn sum(&mut self) -> Result<Node> {
+        let mut node = self.product()?;
+        while let Some(Token::Operator(operator @ (Operator::Add | Operator::Subtract))) =
+            self.tokens.peek().cloned()
+        {
+            self.tokens.next();
+            node = Node::Binary(operator, Box::new(node), Box::new(self.product()?));
+        }
+        Ok(node)
+    }
+
+    fn product(&mut self) -> Result<Node> {
+        let mut node = self.unary()?;
+        while let Some(Token::Operator(operator @ (Operator::Multiply | Operator::Divide))) =
+            self.tokens.peek().cloned()
+        {
+            self.tokens.next();
+            node = Node::Binary(operator, Box::new(node), Box::new(self.unary()?));
+        }
+        Ok(node)
+    }
+
+    fn unary(&mut self) -> Result<Node> {
+        if self.tokens.next_if_eq(&Token::Operator(Operator::Subtract)).is_some() {
+            return Ok(Node::Negate(Box::new(self.unary()?)));
+        }
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Node> {
+        let node = self.atom()?;
+        if self.tokens.next_if_eq(&Token::Operator(Operator::Power)).is_some() {
+            return Ok(Node::Binary(Operator::Power, Box::new(node), Box::new(self.unary()?)));
+        }
+        Ok(node)
+    }
+
+    fn atom(&mut self) -> Result<Node> {
+        match self.tokens.next() {
+            Some(Token::Number(value)) => Ok(Node::Number(value)),
+            Some(Token::LeftParenthesis) => {
+                let node = self.sum()?;
+                self.expect(&Token::RightParenthesis)?;
+                Ok(node)
+            }
+            Some(Token::Identifier(name)) => {
+                if self.tokens.next_if_eq(&Token::LeftParenthesis).is_some() {
+                    return self.call(&name);
+                }
+                if let Some(value) = builtin_constant(&name) {
+                    return Ok(Node::Number(value));
+                }
+                Ok(Node::Variable(name))
+            }
+            Some(token) => Err(anyhow!("unexpected {token}")),
+            None => Err(anyhow!("unexpected end of expression")),
+        }
+    }
+
+    fn call(&mut self, name: &str) -> Result<Node> {
+        let function =
+            name.parse::<Function>().map_err(|_error| anyhow!("unknown function {name}"))?;
+        let mut arguments = vec![self.sum()?];
+        while self.tokens.next_if_eq(&Token::Comma).is_some() {
+            arguments.push(self.sum()?);
+        }
+        self.expect(&Token::RightParenthesis)?;
+        let arity = function.arity();
+        if arguments.len() != arity {
+            return Err(anyhow!("{function} expects {arity} arguments, got {}", arguments.len()));
+        }
+        Ok(Node::Call(function, arguments))
+    }
+
+    fn expect(&mut self, expected: &Token) -> Result<()> {
+        match self.tokens.next() {
+            Some(token) if token == *expected => Ok(()),
+            Some(token) => Err(anyhow!("expected {expected}, got {token}")),
+            None => Err(anyhow!("expected {expected}, got end of expression")),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&char) = chars.peek() {
+        let token = match char {
+            char if char.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            char if char.is_ascii_digit() || char == '.' => {
+                let number = take_while(&mut chars, |char| char.is_ascii_digit() || char == '.');
+                let value = number.parse().map_err(|_error| anyhow!("invalid number {number}"))?;
+                Token::Number(value)
+            }
+            char if char.is_alphabetic() || char == '_' => {
+                let name = take_while(&mut chars, |char| char.is_alphanumeric() || char == '_');
+                Token::Identifier(name)
+            }
+            _ => {
+                chars.next();
+                match char {
+                    '+' => Token::Operator(Operator::Add),
+                    '-' => Token::Operator(Operator::Subtract),
+                    '*' => Token::Operator(Operator::Multiply),
+                    '/' => Token::Operator(Operator::Divide),
+                    '^' => Token::Operator(Operator::Power),
+                    '(' => Token::LeftParenthesis,
+                    ')' => Token::RightParenthesis,
+                    ',' => Token::Comma,
+                    _ => return Err(anyhow!("unexpected character '{char}'")),
+                }
+            }
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn take_while(chars: &mut Peekable<Chars<'_>>, predicate: impl Fn(char) -> bool) -> String {
+    let mut result = String::new();
+    while let Some(char) = chars.next_if(|&char| predicate(char)) {
+        result.push(char);
+    }
+    result
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(value) => write!(f, "number {value}"),
+            Token::Identifier(name) => write!(f, "identifier {name}"),
+            Token::Operator(operator) => {
+                let symbol = match operator {
+                    Operator::Add => '+',
+                    Operator::Subtract => '-',
+                    Operator::Multiply => '*',
+                    Operator::Divide => '/',
+                    Operator::Power => '^',
+                };
+                write!(f, "'{symbol}'")
+            }
+            Token::LeftParenthesis => write!(f, "'('"),
+            Token::RightParenthesis => write!(f, "')'"),
+            Token::Comma => write!(f, "','"),
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let root = Parser::parse(input)?;
+        Ok(Self { source: input.to_owned(), root })
+    }
+}
+
+impl TryFrom<String> for Expression {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Expression> for String {
+    fn from(value: Expression) -> Self {
+        value.source
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(source: &str, t: f32) -> f32 {
+        let expression = source.parse::<Expression>().unwrap();
+        expression.evaluate(&|name| (name == "t").then_some(t))
+    }
+
+    #[test]
+    fn evaluate_respects_precedence() {
+        assert_eq!(evaluate("1 + 2 * 3", 0.0), 7.0);
+        assert_eq!(evaluate("(1 + 2) * 3", 0.0), 9.0);
+        assert_eq!(evaluate("2 ^ 3 ^ 2", 0.0), 512.0);
+        assert_eq!(evaluate("-2 ^ 2", 0.0), -4.0);
+        assert_eq!(evaluate("8 / 4 / 2", 0.0), 1.0);
+        assert_eq!(evaluate("1 - 2 - 3", 0.0), -4.0);
+    }
+
+    #[test]
+    fn evaluate_functions_and_variables() {
+        assert!((evaluate("cos(3*t)", 1.0) - 3.0f32.cos()).abs() < 1e-6);
+        assert!((evaluate("pow(t, 2) + exp(0)", 3.0) - 10.0).abs() < 1e-6);
+        assert!((evaluate("sin(pi / 2) * e", 0.0) - f32::consts::E).abs() < 1e-6);
+        assert!(evaluate("a * t", 1.0).is_nan());
+    }
+
+    #[test]
+    fn parse_rejects_invalid_input() {
+        assert!("1 +".parse::<Expression>().is_err());
+        assert!("(1 + 2".parse::<Expression>().is_err());
+        assert!("foo(1)".parse::<Expression>().is_err());
+        assert!("pow(1)".parse::<Expression>().is_err());
+        assert!("1 # 2".parse::<Expression>().is_err());
+        assert!("1 2".parse::<Expression>().is_err());
+    }
+
+    #[test]
+    fn variables_are_collected_once() {
+        let expression = "a * t + sin(b * t) - a".parse::<Expression>().unwrap();
+        assert_eq!(expression.variables(), vec!["a", "t", "b"]);
+        assert!(is_variable_name("a_1"));
+        assert!(!is_variable_name("pi"));
+        assert!(!is_variable_name("sin"));
+        assert!(!is_variable_name("1a"));
+    }
+}
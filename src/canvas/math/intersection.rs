@@ -0,0 +1,209 @@
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::math;
+
+const FLATNESS: f32 = 1e-3;
+const MAX_DEPTH: u32 = 24;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Intersection {
+    pub point: CurvePoint,
+    pub first: f32,
+    pub second: f32,
+}
+
+impl Intersection {
+    #[must_use]
+    pub fn new(point: CurvePoint, first: f32, second: f32) -> Self {
+        Self { point, first, second }
+    }
+}
+
+#[must_use]
+pub fn bezier_intersections(first: &[CurvePoint], second: &[CurvePoint]) -> Vec<Intersection> {
+    let mut intersections = Vec::new();
+    if first.len() > 1 && second.len() > 1 {
+        let first = BezierPart { points: first.to_vec(), start: 0.0, end: 1.0 };
+        let second = BezierPart { points: second.to_vec(), start: 0.0, end: 1.0 };
+        subdivide(&first, &second, MAX_DEPTH, &mut intersections);
+    }
+    intersections
+}
+
+/// Parameters of the polylines are scaled so that their points are equally spaced in `[0, 1]`.
+#[must_use]
+pub fn polyline_intersections(first: &[CurvePoint], second: &[CurvePoint]) -> Vec<Intersection> {
+    let mut intersections = Vec::new();
+    let first_step = 1.0 / first.len().saturating_sub(1).max(1) as f32;
+    let second_step = 1.0 / second.len().saturating_sub(1).max(1) as f32;
+    for (i, first_segment) in first.windows(2).enumerate() {
+        for (j, second_segment) in second.windows(2).enumerate() {
+            let Some((s, u)) = segment_intersection(first_segment, second_segment) else {
+                continue;
+            };
+            let point = lerp(first_segment[0], first_segment[1], s);
+            let first = (i as f32 + s) * first_step;
+            let second = (j as f32 + u) * second_step;
+            push_unique(&mut intersections, Intersection::new(point, first, second));
+        }
+    }
+    intersections
+}
+
+/// Neighbouring segments are not tested against each other.
+#[must_use]
+pub fn polyline_self_intersections(points: &[CurvePoint]) -> Vec<Intersection> {
+    let mut intersections = Vec::new();
+    let segments = points.len().saturating_sub(1);
+    let step = 1.0 / segments.max(1) as f32;
+    let closed = segments > 2 && points[0].distance_squared(points[segments]) <= f32::EPSILON;
+    for (i, first_segment) in points.windows(2).enumerate() {
+        for (j, second_segment) in points.windows(2).enumerate().skip(i + 2) {
+            if closed && i == 0 && j == segments - 1 {
+                continue;
+            }
+            let Some((s, u)) = segment_intersection(first_segment, second_segment) else {
+                continue;
+            };
+            let point = lerp(first_segment[0], first_segment[1], s);
+            let intersection =
+                Intersection::new(point, (i as f32 + s) * step, (j as f32 + u) * step);
+            push_unique(&mut intersections, intersection);
+        }
+    }
+    intersections
+}
+
+struct BezierPart {
+    points: Vec<CurvePoint>,
+    start: f32,
+    end: f32,
+}
+
+impl BezierPart {
+    fn split(&self) -> (Self, Self) {
+        let (first, second) = math::de_casteljau_split(&self.points, 0.5);
+        let center = (self.start + self.end) / 2.0;
+        (
+            Self { points: first, start: self.start, end: center },
+            Self { points: second, start: center, end: self.end },
+        )
+    }
+
+    fn chord(&self) -> [CurvePoint; 2] {
+        [self.points[0], self.points[self.points.len() - 1]]
+    }
+
+    fn is_flat(&self) -> bool {
+        let [start, end] = self.chord();
+        let chord = end - start;
+        let length = chord.dot_product(chord).sqrt();
+        self.points.iter().all(|point| {
+            let offset = *point - start;
+            let distance = if length > f32::EPSILON {
+                chord.cross_product_magnitude(offset).abs() / length
+            } else {
+                offset.dot_product(offset).sqrt()
+            };
+            distance <= FLATNESS
+        })
+    }
+
+    fn parameter(&self, t: f32) -> f32 {
+        self.start + (self.end - self.start) * t
+    }
+}
+
+fn subdivide(
+    first: &BezierPart,
+    second: &BezierPart,
+    depth: u32,
+    intersections: &mut Vec<Intersection>,
+) {
+    if !boxes_overlap(&first.points, &second.points) {
+        return;
+    }
+    if depth == 0 || (first.is_flat() && second.is_flat()) {
+        if let Some((s, u)) = segment_intersection(&first.chord(), &second.chord()) {
+            let [start, end] = first.chord();
+            let intersection =
+                Intersection::new(lerp(start, end, s), first.parameter(s), second.parameter(u));
+            push_unique(intersections, intersection);
+        }
+        return;
+    }
+    let (first_left, first_right) = first.split();
+    let (second_left, second_right) = second.split();
+    for first in [&first_left, &first_right] {
+        for second in [&second_left, &second_right] {
+            subdivide(first, second, depth - 1, intersections);
+        }
+    }
+}
+
+fn boxes_overlap(first: &[CurvePoint], second: &[CurvePoint]) -> bool {
+    let [first_min, first_max] = bounding_box(first);
+    let [second_min, second_max] = bounding_box(second);
+    first_min.0 <= second_max.0 + FLATNESS
+        && second_min.0 <= first_max.0 + FLATNESS
+        && first_min.1 <= second_max.1 + FLATNESS
+        && second_min.1 <= first_max.1 + FLATNESS
+}
+
+fn bounding_box(points: &[CurvePoint]) -> [(f32, f32); 2] {
+    let empty = [(f32::INFINITY, f32::INFINITY), (f32::NEG_INFINITY, f32::NEG_INFINITY)];
+    points.iter().fold(empty, |[min, max], point| {
+        let (x, y) = (point.horizontal(), point.vertical());
+        [(min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y))]
+    })
+}
+
+fn segment_intersection(first: &[CurvePoint], second: &[CurvePoint]) -> Option<(f32, f32)> {
+    let first_direction = first[1] - first[0];
+    let second_direction = second[1] - second[0];
+    let denominator = first_direction.cross_product_magnitude(second_direction);
+    if denominator.abs() <= f32::EPSILON {
+        return None;
+    }
+    let offset = second[0] - first[0];
+    let s = offset.cross_product_magnitude(second_direction) / denominator;
+    let u = offset.cross_product_magnitude(first_direction) / denominator;
+    let range = -1e-5..=1.0 + 1e-5;
+    (range.contains(&s) && range.contains(&u)).then(|| (s.clamp(0.0, 1.0), u.clamp(0.0, 1.0)))
+}
+
+fn lerp(start: CurvePoint, end: CurvePoint, t: f32) -> CurvePoint {
+    start + (end - start) * t
+}
+
+fn push_unique(intersections: &mut Vec<Intersection>, intersection: Intersection) {
+    const DISTANCE: f32 = 1e-2;
+
+    let duplicate = intersections.iter().any(|other| {
+        other.point.distance_squared(intersection.point) <= DISTANCE * DISTANCE
+            && (other.first - intersection.first).abs() <= DISTANCE
+            && (other.second - intersection.second).abs() <= DISTANCE
+    });
+    if !duplicate {
+        intersections.push(intersection);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::math::point::Point;
+
+    #[test]
+    fn finds_all_crossings_of_bezier_curves() {
+        let first = [(0.0, 0.0), (30.0, 90.0), (70.0, -90.0), (100.0, 0.0)].map(Point::from);
+        let second = [(0.0, 0.0), (100.0, 0.0)].map(Point::from);
+        let intersections = bezier_intersections(&first, &second);
+        assert_eq!(intersections.len(), 3);
+        for intersection in intersections {
+            let on_first = math::de_casteljau(&first, intersection.first);
+            let on_second = math::de_casteljau(&second, intersection.second);
+            assert!(on_first.distance_squared(intersection.point) < 1e-4);
+            assert!(on_second.distance_squared(intersection.point) < 1e-4);
+        }
+    }
+}
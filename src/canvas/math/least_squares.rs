@@ -0,0 +1,106 @@
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::math::point::Point;
+
+/// Returns `None` if the basis functions are linearly dependent at the parameters.
+#[must_use]
+pub fn fit(
+    ts: &[f32],
+    points: &[CurvePoint],
+    basis: impl Fn(f32) -> Vec<f32>,
+) -> Option<Vec<CurvePoint>> {
+    debug_assert_eq!(ts.len(), points.len());
+    let rows = ts
+        .iter()
+        .zip(points)
+        .map(|(t, point)| {
+            let mut row = basis(*t).into_iter().map(f64::from).collect::<Vec<_>>();
+            row.push(f64::from(point.horizontal()));
+            row.push(f64::from(point.vertical()));
+            row
+        })
+        .collect();
+    let solution = solve(rows, 2)?;
+    let coefficients = solution
+        .into_iter()
+        .map(|coefficient| Point::new(coefficient[0] as f32, coefficient[1] as f32))
+        .collect();
+    Some(coefficients)
+}
+
+fn solve(mut rows: Vec<Vec<f64>>, right_sides: usize) -> Option<Vec<Vec<f64>>> {
+    const TOLERANCE: f64 = 1e-10;
+
+    let height = rows.len();
+    let width = rows.first()?.len();
+    let unknowns = width - right_sides;
+    if height < unknowns {
+        return None;
+    }
+    let scale = rows
+        .iter()
+        .flat_map(|row| &row[..unknowns])
+        .fold(0.0, |max: f64, value| max.max(value.abs()));
+
+    for column in 0..unknowns {
+        let norm = (column..height).map(|row| rows[row][column].powi(2)).sum::<f64>().sqrt();
+        if norm <= TOLERANCE * scale.max(1.0) {
+            return None;
+        }
+        let alpha = -norm.copysign(rows[column][column]);
+        let mut reflector = (column..height).map(|row| rows[row][column]).collect::<Vec<_>>();
+        reflector[0] -= alpha;
+        let reflector_norm = reflector.iter().map(|value| value * value).sum::<f64>();
+
+        for other in column + 1..width {
+            let product = rows[column..]
+                .iter()
+                .zip(&reflector)
+                .map(|(row, value)| row[other] * value)
+                .sum::<f64>();
+            let factor = 2.0 * product / reflector_norm;
+            for (row, value) in rows[column..].iter_mut().zip(&reflector) {
+                row[other] -= factor * value;
+            }
+        }
+        rows[column][column] = alpha;
+    }
+
+    let mut solution = vec![vec![0.0; right_sides]; unknowns];
+    for row in (0..unknowns).rev() {
+        for side in 0..right_sides {
+            let known = (row + 1..unknowns)
+                .map(|column| rows[row][column] * solution[column][side])
+                .sum::<f64>();
+            solution[row][side] = (rows[row][unknowns + side] - known) / rows[row][row];
+        }
+    }
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fits_line_to_noisy_points() {
+        let ts = [0.0, 0.25, 0.5, 0.75, 1.0];
+        let offsets = [0.1, -0.1, 0.0, 0.1, -0.1];
+        let points = ts
+            .iter()
+            .zip(offsets)
+            .map(|(t, offset)| Point::new(*t, 2.0 * t + 1.0 + offset))
+            .collect::<Vec<_>>();
+        let coefficients = fit(&ts, &points, |t| vec![1.0, t]).unwrap();
+        assert!(coefficients[0].horizontal().abs() < 1e-5);
+        assert!((coefficients[1].horizontal() - 1.0).abs() < 1e-5);
+        assert!((coefficients[0].vertical() - 1.04).abs() < 1e-5);
+        assert!((coefficients[1].vertical() - 1.92).abs() < 1e-5);
+    }
+
+    #[test]
+    fn rejects_underdetermined_system() {
+        let points = [Point::new(0.0, 0.0), Point::new(1.0, 1.0)];
+        assert!(fit(&[0.0, 1.0], &points, |t| vec![1.0, t, t * t]).is_none());
+        assert!(fit(&[0.5, 0.5], &points, |t| vec![1.0, t]).is_none());
+    }
+}
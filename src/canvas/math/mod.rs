@@ -2,11 +2,24 @@ use num_traits::Euclid;
 
 use crate::canvas::control_points::point::{CurvePoint, PointContainer};
 use crate::canvas::math::point::Point;
+use crate::canvas::math::vector::Vector;
 use crate::canvas::shape::rational_bezier::RationalBezierPoint;
 
+pub mod arc_length;
+pub mod barycentric;
+pub mod bezier_degree;
+pub mod bezier_fitting;
 pub mod convex_hull;
+pub mod cubic_spline;
+pub mod curvature;
+pub mod curve_features;
+pub mod expression;
+pub mod intersection;
+pub mod least_squares;
+pub mod offset;
 pub mod point;
 pub mod polynomial;
+pub mod projection;
 pub mod rectangle;
 pub mod size;
 pub mod vector;
@@ -43,7 +56,13 @@ pub fn chebyshev(n: usize, k: usize) -> f32 {
 
 #[must_use]
 pub fn de_casteljau(points: &[CurvePoint], t: f32) -> CurvePoint {
-    let mut w = Vec::from(points);
+    de_casteljau_in(points, t, &mut Vec::with_capacity(points.len()))
+}
+
+/// Evaluates like [`de_casteljau`] in a reusable buffer.
+pub fn de_casteljau_in(points: &[CurvePoint], t: f32, w: &mut Vec<CurvePoint>) -> CurvePoint {
+    w.clear();
+    w.extend_from_slice(points);
     for k in 1..(points.len()) {
         for i in 0..(points.len() - k) {
             w[i] = Point::new(
@@ -55,26 +74,288 @@ pub fn de_casteljau(points: &[CurvePoint], t: f32) -> CurvePoint {
     w[0]
 }
 
-#[allow(clippy::many_single_char_names)]
+/// Returns control points of the derivative, with vectors stored as points.
+#[must_use]
+pub fn hodograph(points: &[CurvePoint]) -> Vec<CurvePoint> {
+    let degree = points.len().saturating_sub(1) as f32;
+    points.windows(2).map(|pair| Point::zero() + (pair[1] - pair[0]) * degree).collect()
+}
+
+/// Returns `None` for fewer than two points or non-positive weights of the derivative.
+#[must_use]
+pub fn rational_hodograph(points: &[RationalBezierPoint]) -> Option<Vec<RationalBezierPoint>> {
+    if points.len() < 2 {
+        return None;
+    }
+    let degree = points.len() - 1;
+    let weights = points.iter().map(|point| point.weight()).collect::<Vec<_>>();
+    let homogeneous = points
+        .iter()
+        .map(|point| (point.into_point() - Point::zero()) * point.weight())
+        .collect::<Vec<_>>();
+    let derivative = homogeneous.windows(2).map(|pair| (pair[1] - pair[0]) * degree as f32);
+    let weights_derivative = weights.windows(2).map(|pair| (pair[1] - pair[0]) * degree as f32);
+
+    // Numerator of the quotient rule, `H' w - H w'`, as a product of Bernstein polynomials.
+    let mut numerator = vec![Vector::new(0.0, 0.0); 2 * degree];
+    for (i, (derivative, weight_derivative)) in derivative.zip(weights_derivative).enumerate() {
+        for j in 0..=degree {
+            let scale =
+                binomial(degree - 1, i) * binomial(degree, j) / binomial(2 * degree - 1, i + j);
+            numerator[i + j] = numerator[i + j]
+                + (derivative * weights[j] - homogeneous[j] * weight_derivative) * scale;
+        }
+    }
+    let numerator = numerator.into_iter().map(|vector| Point::zero() + vector).collect::<Vec<_>>();
+    let numerator = bezier_degree::elevate(&numerator);
+
+    let mut denominator = vec![0.0; 2 * degree + 1];
+    for i in 0..=degree {
+        for j in 0..=degree {
+            let scale = binomial(degree, i) * binomial(degree, j) / binomial(2 * degree, i + j);
+            denominator[i + j] += weights[i] * weights[j] * scale;
+        }
+    }
+    if denominator.iter().any(|weight| *weight <= 0.0) {
+        return None;
+    }
+    let hodograph = numerator.into_iter().zip(denominator).map(|(point, weight)| {
+        let point = Point::new(point.horizontal() / weight, point.vertical() / weight);
+        RationalBezierPoint::new(point, weight)
+    });
+    Some(hodograph.collect())
+}
+
+fn binomial(n: usize, k: usize) -> f32 {
+    (0..k).map(|i| (n - i) as f32 / (i + 1) as f32).product()
+}
+
+#[must_use]
+pub fn bezier_length(points: &[CurvePoint]) -> f32 {
+    if points.len() < 2 {
+        return 0.0;
+    }
+    let hodograph = hodograph(points);
+    let derivative = |t| de_casteljau(&hodograph, t) - Point::zero();
+    arc_length::length(derivative, 0.0, 1.0, arc_length::TOLERANCE)
+}
+
 #[must_use]
 pub fn rational_de_casteljau(points: &[RationalBezierPoint], t: f32) -> CurvePoint {
+    rational_de_casteljau_in(points, t, &mut Vec::with_capacity(points.len()))
+}
+
+/// Evaluates like [`rational_de_casteljau`] in a reusable buffer.
+#[allow(clippy::many_single_char_names)]
+pub fn rational_de_casteljau_in(
+    points: &[RationalBezierPoint],
+    t: f32,
+    buffer: &mut Vec<(CurvePoint, f32)>,
+) -> CurvePoint {
     let t_1 = 1.0 - t;
-    let (mut q, mut w): (Vec<Point<f32>>, Vec<f32>) =
-        points.iter().map(|point| (point.into_point(), point.weight())).unzip();
+    buffer.clear();
+    buffer.extend(points.iter().map(|point| (point.into_point(), point.weight())));
     for k in 1..(points.len()) {
         for i in 0..(points.len() - k) {
-            let u = t_1 * w[i];
-            let v = t * w[i + 1];
-            w[i] = u + v;
-            let u = u / w[i];
+            let (q_i, w_i) = buffer[i];
+            let (q_j, w_j) = buffer[i + 1];
+            let u = t_1 * w_i;
+            let v = t * w_j;
+            let w = u + v;
+            let u = u / w;
             let v = 1.0 - u;
-            q[i] = Point::new(
-                u * q[i].horizontal() + v * q[i + 1].horizontal(),
-                u * q[i].vertical() + v * q[i + 1].vertical(),
+            let q = Point::new(
+                u * q_i.horizontal() + v * q_j.horizontal(),
+                u * q_i.vertical() + v * q_j.vertical(),
+            );
+            buffer[i] = (q, w);
+        }
+    }
+    buffer[0].0
+}
+
+/// Returns control points of both parts of the curve split at `t`.
+#[must_use]
+pub fn de_casteljau_split(points: &[CurvePoint], t: f32) -> (Vec<CurvePoint>, Vec<CurvePoint>) {
+    let mut w = Vec::from(points);
+    let mut first = Vec::with_capacity(points.len());
+    let mut second = Vec::with_capacity(points.len());
+    first.push(w[0]);
+    second.push(w[w.len() - 1]);
+    for k in 1..(points.len()) {
+        for i in 0..(points.len() - k) {
+            w[i] = w[i] + (w[i + 1] - w[i]) * t;
+        }
+        first.push(w[0]);
+        second.push(w[points.len() - k - 1]);
+    }
+    second.reverse();
+    (first, second)
+}
+
+/// Returns control points of both parts of the curve split at `t`.
+#[must_use]
+pub fn rational_de_casteljau_split(
+    points: &[RationalBezierPoint],
+    t: f32,
+) -> (Vec<RationalBezierPoint>, Vec<RationalBezierPoint>) {
+    let mut w = points
+        .iter()
+        .map(|point| {
+            let weight = point.weight();
+            let point = point.into_point();
+            (point.horizontal() * weight, point.vertical() * weight, weight)
+        })
+        .collect::<Vec<_>>();
+    let from_homogeneous =
+        |(x, y, w): (f32, f32, f32)| RationalBezierPoint::new(Point::new(x / w, y / w), w);
+    let mut first = vec![from_homogeneous(w[0])];
+    let mut second = vec![from_homogeneous(w[w.len() - 1])];
+    for k in 1..(points.len()) {
+        for i in 0..(points.len() - k) {
+            let (x_0, y_0, w_0) = w[i];
+            let (x_1, y_1, w_1) = w[i + 1];
+            w[i] =
+                ((1.0 - t) * x_0 + t * x_1, (1.0 - t) * y_0 + t * y_1, (1.0 - t) * w_0 + t * w_1);
+        }
+        first.push(from_homogeneous(w[0]));
+        second.push(from_homogeneous(w[points.len() - k - 1]));
+    }
+    second.reverse();
+    (first, second)
+}
+
+/// Refines the nearest of the given number of equally spaced samples.
+#[must_use]
+pub fn nearest_parameter(
+    curve: impl Fn(f32) -> CurvePoint,
+    point: CurvePoint,
+    samples: usize,
+) -> f32 {
+    const ITERATIONS: usize = 32;
+    let ratio = (5.0_f32.sqrt() - 1.0) / 2.0;
+
+    let distance = |t: f32| curve(t).distance_squared(point);
+    let step = 1.0 / (samples.max(2) - 1) as f32;
+    let nearest = (0..samples.max(2))
+        .map(|index| index as f32 * step)
+        .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+        .unwrap_or(0.0);
+
+    let (mut start, mut end) = ((nearest - step).max(0.0), (nearest + step).min(1.0));
+    for _ in 0..ITERATIONS {
+        let left = end - ratio * (end - start);
+        let right = start + ratio * (end - start);
+        if distance(left) < distance(right) {
+            end = right;
+        } else {
+            start = left;
+        }
+    }
+    (start + end) / 2.0
+}
+
+/// Assumes `points.len() + degree + 1` non-decreasing knots and `degree < points.len()`.
+#[allow(clippy::many_single_char_names)]
+#[must_use]
+pub fn de_boor(points: &[CurvePoint], knots: &[f32], degree: usize, t: f32) -> CurvePoint {
+    let k = knot_span(knots, degree, points.len(), t);
+    let mut d = Vec::from(&points[(k - degree)..=k]);
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let alpha = de_boor_alpha(knots, t, j + k - degree, j + 1 + k - r);
+            d[j] = Point::new(
+                (1.0 - alpha) * d[j - 1].horizontal() + alpha * d[j].horizontal(),
+                (1.0 - alpha) * d[j - 1].vertical() + alpha * d[j].vertical(),
             );
         }
     }
-    q[0]
+    d[degree]
+}
+
+/// Has the same assumptions as [`de_boor`].
+#[allow(clippy::many_single_char_names)]
+#[must_use]
+pub fn rational_de_boor(
+    points: &[RationalBezierPoint],
+    knots: &[f32],
+    degree: usize,
+    t: f32,
+) -> CurvePoint {
+    let k = knot_span(knots, degree, points.len(), t);
+    let mut d = points[(k - degree)..=k]
+        .iter()
+        .map(|point| {
+            let weight = point.weight();
+            let point = point.into_point();
+            (point.horizontal() * weight, point.vertical() * weight, weight)
+        })
+        .collect::<Vec<_>>();
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let alpha = de_boor_alpha(knots, t, j + k - degree, j + 1 + k - r);
+            let (x_0, y_0, w_0) = d[j - 1];
+            let (x_1, y_1, w_1) = d[j];
+            d[j] = (
+                (1.0 - alpha) * x_0 + alpha * x_1,
+                (1.0 - alpha) * y_0 + alpha * y_1,
+                (1.0 - alpha) * w_0 + alpha * w_1,
+            );
+        }
+    }
+    let (x, y, w) = d[degree];
+    Point::new(x / w, y / w)
+}
+
+/// Returns `k` of the span `[knots[k], knots[k + 1])` containing `t`, clamped to the domain.
+#[must_use]
+pub fn knot_span(knots: &[f32], degree: usize, length: usize, t: f32) -> usize {
+    let lower_knots = knots[degree..length].partition_point(|knot| *knot <= t);
+    degree + lower_knots.saturating_sub(1)
+}
+
+fn de_boor_alpha(knots: &[f32], t: f32, left: usize, right: usize) -> f32 {
+    let denominator = knots[right] - knots[left];
+    if denominator > 0.0 {
+        (t - knots[left]) / denominator
+    } else {
+        0.0
+    }
+}
+
+/// Evaluates segment from `points[1]` to `points[2]` for `t` from `knots[1]` to `knots[2]`.
+#[must_use]
+pub fn catmull_rom(points: &[CurvePoint], knots: &[f32], t: f32) -> CurvePoint {
+    debug_assert!(points.len() == 4 && knots.len() == 4);
+    let interpolate = |start: CurvePoint, end: CurvePoint, from: usize, to: usize| {
+        let ratio = (t - knots[from]) / (knots[to] - knots[from]);
+        start + (end - start) * ratio
+    };
+    let first = interpolate(points[0], points[1], 0, 1);
+    let second = interpolate(points[1], points[2], 1, 2);
+    let third = interpolate(points[2], points[3], 2, 3);
+    let first = interpolate(first, second, 0, 2);
+    let second = interpolate(second, third, 1, 3);
+    interpolate(first, second, 1, 2)
+}
+
+#[must_use]
+pub fn hermite(
+    start: CurvePoint,
+    start_tangent: Vector<f32>,
+    end: CurvePoint,
+    end_tangent: Vector<f32>,
+    t: f32,
+) -> CurvePoint {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let end_weight = -2.0 * t3 + 3.0 * t2;
+    let start_tangent_weight = t3 - 2.0 * t2 + t;
+    let end_tangent_weight = t3 - t2;
+    start
+        + (end - start) * end_weight
+        + start_tangent * start_tangent_weight
+        + end_tangent * end_tangent_weight
 }
 
 #[allow(clippy::assign_op_pattern)]
@@ -143,3 +424,79 @@ pub fn rational_chudy_wozny(points: &[RationalBezierPoint], t: f32) -> CurvePoin
     }
     q
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rational_hodograph_is_derivative() {
+        let points = [
+            RationalBezierPoint::new(Point::new(1.0, 0.0), 1.0),
+            RationalBezierPoint::new(Point::new(1.0, 1.0), 2.0),
+            RationalBezierPoint::new(Point::new(0.0, 3.0), 0.5),
+            RationalBezierPoint::new(Point::new(-2.0, 1.0), 1.0),
+        ];
+        let hodograph = rational_hodograph(&points).unwrap();
+        assert_eq!(hodograph.len(), 7);
+        let step = 1e-3;
+        for t in [0.1, 0.3, 0.5, 0.9] {
+            let difference =
+                rational_de_casteljau(&points, t + step) - rational_de_casteljau(&points, t - step);
+            let expected = Point::zero() + difference / (2.0 * step);
+            let actual = rational_de_casteljau(&hodograph, t);
+            assert!(expected.distance_squared(actual) < 1e-3);
+        }
+    }
+
+    #[test]
+    fn clamped_de_boor_matches_bezier() {
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 0.0),
+        ];
+        let knots = [0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0];
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let expected = de_casteljau(&points, t);
+            let actual = de_boor(&points, &knots, 3, t);
+            assert!(expected.distance_squared(actual) < 1e-10);
+        }
+    }
+
+    #[test]
+    fn clamped_rational_de_boor_matches_rational_bezier() {
+        let points = [
+            RationalBezierPoint::new(Point::new(1.0, 0.0), 1.0),
+            RationalBezierPoint::new(Point::new(1.0, 1.0), f32::sqrt(0.5)),
+            RationalBezierPoint::new(Point::new(0.0, 1.0), 1.0),
+        ];
+        let knots = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let expected = rational_de_casteljau(&points, t);
+            let actual = rational_de_boor(&points, &knots, 2, t);
+            assert!(expected.distance_squared(actual) < 1e-10);
+            // Quarter of the unit circle is represented exactly
+            assert!((actual.distance_squared(Point::zero()) - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn split_parts_cover_rational_bezier() {
+        let points = [
+            RationalBezierPoint::new(Point::new(0.0, 0.0), 1.0),
+            RationalBezierPoint::new(Point::new(1.0, 3.0), 2.0),
+            RationalBezierPoint::new(Point::new(3.0, 2.0), 0.5),
+            RationalBezierPoint::new(Point::new(4.0, 0.0), 1.0),
+        ];
+        let split = 0.3;
+        let (first, second) = rational_de_casteljau_split(&points, split);
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let expected = rational_de_casteljau(&points, t * split);
+            assert!(expected.distance_squared(rational_de_casteljau(&first, t)) < 1e-10);
+            let expected = rational_de_casteljau(&points, split + t * (1.0 - split));
+            assert!(expected.distance_squared(rational_de_casteljau(&second, t)) < 1e-10);
+        }
+    }
+}
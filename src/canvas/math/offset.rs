@@ -0,0 +1,207 @@
+use itertools::Itertools;
+
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::math::bezier_fitting;
+use crate::canvas::math::intersection;
+use crate::canvas::math::vector::Vector;
+
+const JOIN_ANGLE: f32 = std::f32::consts::PI / 16.0;
+/// Relative to the offset distance.
+const DISTANCE_TOLERANCE: f32 = 1e-3;
+
+/// Returns parts of the offset left of the polyline, with cusps and loops trimmed.
+/// Negative distances offset to the right.
+#[must_use]
+pub fn offset_polyline(points: &[CurvePoint], distance: f32) -> Vec<Vec<CurvePoint>> {
+    let points = points
+        .iter()
+        .copied()
+        .dedup_by(|previous, next| previous.distance_squared(*next) <= f32::EPSILON)
+        .collect::<Vec<_>>();
+    if points.len() < 2 || distance == 0.0 {
+        return vec![points];
+    }
+    let raw = raw_offset(&points, distance);
+    let segments = raw.len() - 1;
+    let mut cuts = intersection::polyline_self_intersections(&raw)
+        .into_iter()
+        .flat_map(|intersection| [intersection.first, intersection.second])
+        .map(|parameter| parameter * segments as f32)
+        .chain([0.0, segments as f32])
+        .collect::<Vec<_>>();
+    cuts.sort_by(f32::total_cmp);
+    cuts.dedup_by(|next, previous| *next - *previous <= f32::EPSILON);
+
+    let tolerance = DISTANCE_TOLERANCE * distance.abs();
+    let is_valid = |point| distance_to_polyline(&points, point) >= distance.abs() - tolerance;
+    cuts.into_iter()
+        .tuple_windows()
+        .filter(|(start, end)| is_valid(point_at(&raw, (start + end) / 2.0)))
+        .map(|(start, end)| {
+            let inner = (start.floor() as usize + 1..=end.ceil() as usize)
+                .filter(|index| (*index as f32) > start && (*index as f32) < end)
+                .map(|index| raw[index]);
+            [point_at(&raw, start)].into_iter().chain(inner).chain([point_at(&raw, end)]).collect()
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn into_polyline(parts: &[Vec<CurvePoint>]) -> Vec<CurvePoint> {
+    parts
+        .iter()
+        .flatten()
+        .copied()
+        .dedup_by(|previous, next| previous.distance_squared(*next) <= f32::EPSILON)
+        .collect()
+}
+
+/// Returns control points laid out like in [`bezier_fitting::fit_cubic_spline`].
+#[must_use]
+pub fn into_bezier_spline(parts: &[Vec<CurvePoint>], tolerance: f32) -> Vec<CurvePoint> {
+    let mut spline: Vec<CurvePoint> = Vec::new();
+    for part in parts {
+        let fitted = bezier_fitting::fit_cubic_spline(part, tolerance);
+        let Some(first) = fitted.first().copied() else {
+            continue;
+        };
+        if let Some(last) = spline.last().copied() {
+            if last.distance_squared(first) > f32::EPSILON {
+                let chord = first - last;
+                spline.extend([last + chord / 3.0, last + chord * (2.0 / 3.0), first]);
+            }
+            spline.extend(fitted.into_iter().skip(1));
+        } else {
+            spline = fitted;
+        }
+    }
+    spline
+}
+
+fn raw_offset(points: &[CurvePoint], distance: f32) -> Vec<CurvePoint> {
+    let closed = points.len() > 3 && points[0].distance_squared(points[points.len() - 1]) == 0.0;
+    let segments = points
+        .windows(2)
+        .map(|pair| {
+            let tangent = normalised(pair[1] - pair[0]);
+            let offset = Vector::new(-tangent.vertical(), tangent.horizontal()) * distance;
+            [pair[0] + offset, pair[1] + offset]
+        })
+        .collect::<Vec<_>>();
+
+    let mut raw = Vec::new();
+    if closed {
+        join(&segments[segments.len() - 1], &segments[0], points[0], distance, &mut raw);
+    } else {
+        raw.push(segments[0][0]);
+    }
+    for (index, pair) in segments.windows(2).enumerate() {
+        join(&pair[0], &pair[1], points[index + 1], distance, &mut raw);
+    }
+    if closed {
+        raw.push(raw[0]);
+    } else {
+        raw.push(segments[segments.len() - 1][1]);
+    }
+    raw
+}
+
+fn join(
+    first: &[CurvePoint; 2],
+    second: &[CurvePoint; 2],
+    vertex: CurvePoint,
+    distance: f32,
+    raw: &mut Vec<CurvePoint>,
+) {
+    let (start, end) = (first[1] - vertex, second[0] - vertex);
+    let turn = (first[1] - first[0]).cross_product_magnitude(second[1] - second[0]);
+    if turn * distance > 0.0 {
+        if let Some(intersection) = intersection::polyline_intersections(first, second).first() {
+            raw.push(intersection.point);
+        } else {
+            raw.extend([first[1], second[0]]);
+        }
+        return;
+    }
+    let mut angle = start.cross_product_magnitude(end).atan2(start.dot_product(end));
+    if turn == 0.0 && start.dot_product(end) < 0.0 {
+        // Polyline turns back, so the arc goes around the vertex in the direction of the first
+        // segment.
+        angle = std::f32::consts::PI.copysign(start.cross_product_magnitude(first[1] - first[0]));
+    }
+    let steps = (angle.abs() / JOIN_ANGLE).ceil() as usize;
+    raw.push(first[1]);
+    for step in 1..steps {
+        let (sin, cos) = (angle * step as f32 / steps as f32).sin_cos();
+        let rotated = Vector::new(
+            start.horizontal() * cos - start.vertical() * sin,
+            start.horizontal() * sin + start.vertical() * cos,
+        );
+        raw.push(vertex + rotated);
+    }
+    if steps > 0 {
+        raw.push(second[0]);
+    }
+}
+
+/// Integer part of the position is the index of segment.
+fn point_at(points: &[CurvePoint], position: f32) -> CurvePoint {
+    let index = (position.floor().max(0.0) as usize).min(points.len() - 2);
+    let ratio = position - index as f32;
+    points[index] + (points[index + 1] - points[index]) * ratio
+}
+
+fn distance_to_polyline(points: &[CurvePoint], point: CurvePoint) -> f32 {
+    points
+        .windows(2)
+        .map(|pair| {
+            let segment = pair[1] - pair[0];
+            let length = segment.dot_product(segment);
+            let ratio = if length > f32::EPSILON {
+                ((point - pair[0]).dot_product(segment) / length).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            point.distance_squared(pair[0] + segment * ratio)
+        })
+        .fold(f32::INFINITY, f32::min)
+        .sqrt()
+}
+
+fn normalised(vector: Vector<f32>) -> Vector<f32> {
+    let length = vector.dot_product(vector).sqrt();
+    if length > f32::EPSILON {
+        vector / length
+    } else {
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::math::point::Point;
+
+    #[test]
+    fn offset_is_trimmed_to_points_at_the_distance() {
+        let square = [(0.0, 0.0), (100.0, 0.0), (100.0, 100.0), (0.0, 100.0), (0.0, 0.0)];
+        let square = square.map(Point::from);
+        let offset = into_polyline(&offset_polyline(&square, 10.0));
+        let expected = [(10.0, 10.0), (90.0, 10.0), (90.0, 90.0), (10.0, 90.0), (10.0, 10.0)];
+        assert_eq!(offset.len(), expected.len());
+        assert!(offset
+            .iter()
+            .zip(expected)
+            .all(|(actual, expected)| { actual.distance_squared(Point::from(expected)) < 1e-6 }));
+
+        // Parabola bends at its apex with radius 10, so that its offset by 30 forms a swallowtail.
+        let parabola =
+            (-100..=100).map(|x| Point::new(x as f32, (x * x) as f32 / 20.0)).collect::<Vec<_>>();
+        let offset = into_polyline(&offset_polyline(&parabola, 30.0));
+        assert!(offset.len() > 100);
+        assert!(intersection::polyline_self_intersections(&offset).is_empty());
+        for point in &offset {
+            assert!((distance_to_polyline(&parabola, *point) - 30.0).abs() < 0.1);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::ops::RangeInclusive;
+
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::math::vector::Vector;
+
+const MAX_ITERATIONS: usize = 16;
+const PARAMETER_TOLERANCE: f32 = 1e-6;
+/// Relative to the length of the parameter range.
+const DIFFERENCE_STEP: f32 = 1e-3;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Projection {
+    pub point: CurvePoint,
+    pub t: f32,
+    pub distance: f32,
+}
+
+impl Projection {
+    #[must_use]
+    pub fn new(point: CurvePoint, t: f32, projected: CurvePoint) -> Self {
+        Self { point, t, distance: point.distance_squared(projected).sqrt() }
+    }
+}
+
+/// Parameter of the polyline is scaled so that its points are equally spaced in `[0, 1]`.
+#[must_use]
+pub fn polyline_projection(points: &[CurvePoint], point: CurvePoint) -> Option<Projection> {
+    if let [single] = points {
+        return Some(Projection::new(*single, 0.0, point));
+    }
+    let step = 1.0 / points.len().saturating_sub(1).max(1) as f32;
+    points
+        .windows(2)
+        .enumerate()
+        .map(|(index, pair)| {
+            let segment = pair[1] - pair[0];
+            let length = segment.dot_product(segment);
+            let ratio = if length > f32::EPSILON {
+                ((point - pair[0]).dot_product(segment) / length).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            Projection::new(pair[0] + segment * ratio, (index as f32 + ratio) * step, point)
+        })
+        .min_by(|first, second| first.distance.total_cmp(&second.distance))
+}
+
+/// Returns projection at `start` if the method moves away from the point.
+#[must_use]
+pub fn newton_projection(
+    curve: impl Fn(f32) -> CurvePoint,
+    derivative: impl Fn(f32) -> Vector<f32>,
+    second_derivative: impl Fn(f32) -> Vector<f32>,
+    range: RangeInclusive<f32>,
+    point: CurvePoint,
+    start: f32,
+) -> Projection {
+    let (low, high) = bounds(&range);
+    let mut t = start.clamp(low, high);
+    for _ in 0..MAX_ITERATIONS {
+        let offset = curve(t) - point;
+        let first = derivative(t);
+        let denominator = first.dot_product(first) + offset.dot_product(second_derivative(t));
+        if denominator.abs() <= f32::EPSILON {
+            break;
+        }
+        let next = (t - offset.dot_product(first) / denominator).clamp(low, high);
+        let change = (next - t).abs();
+        t = next;
+        if change <= PARAMETER_TOLERANCE {
+            break;
+        }
+    }
+    let start = start.clamp(low, high);
+    let refined = Projection::new(curve(t), t, point);
+    let initial = Projection::new(curve(start), start, point);
+    if refined.distance <= initial.distance {
+        refined
+    } else {
+        initial
+    }
+}
+
+/// Refines like [`newton_projection`] with derivatives given by finite differences.
+#[must_use]
+pub fn refine_projection(
+    curve: impl Fn(f32) -> CurvePoint,
+    range: RangeInclusive<f32>,
+    point: CurvePoint,
+    start: f32,
+) -> Projection {
+    let (low, high) = bounds(&range);
+    let step = (high - low) * DIFFERENCE_STEP;
+    let center = |t: f32| t.clamp(low + step, high - step);
+    let derivative = |t: f32| {
+        let t = center(t);
+        (curve(t + step) - curve(t - step)) / (2.0 * step)
+    };
+    let second_derivative = |t: f32| {
+        let t = center(t);
+        ((curve(t + step) - curve(t)) - (curve(t) - curve(t - step))) / (step * step)
+    };
+    if step > 0.0 && step.is_finite() {
+        newton_projection(&curve, derivative, second_derivative, range, point, start)
+    } else {
+        Projection::new(curve(low), low, point)
+    }
+}
+
+fn bounds(range: &RangeInclusive<f32>) -> (f32, f32) {
+    let (start, end) = (*range.start(), *range.end());
+    (start.min(end), start.max(end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::math;
+    use crate::canvas::math::point::Point;
+
+    #[test]
+    fn newton_projection_refines_nearest_sample() {
+        let points = [(0.0, 0.0), (30.0, 90.0), (70.0, -90.0), (100.0, 0.0)].map(Point::from);
+        let first = math::hodograph(&points);
+        let second = math::hodograph(&first);
+        let path =
+            (0..=20).map(|t| math::de_casteljau(&points, t as f32 / 20.0)).collect::<Vec<_>>();
+        let point = Point::new(20.0, 40.0);
+
+        let sample = polyline_projection(&path, point).unwrap();
+        let projection = newton_projection(
+            |t| math::de_casteljau(&points, t),
+            |t| math::de_casteljau(&first, t) - Point::zero(),
+            |t| math::de_casteljau(&second, t) - Point::zero(),
+            0.0..=1.0,
+            point,
+            sample.t,
+        );
+        assert!(projection.distance <= sample.distance);
+        let tangent = math::de_casteljau(&first, projection.t) - Point::zero();
+        let normal = projection.point - point;
+        let cosine = tangent.dot_product(normal)
+            / (tangent.dot_product(tangent) * normal.dot_product(normal)).sqrt();
+        assert!(cosine.abs() < 1e-3);
+        let exact = math::nearest_parameter(|t| math::de_casteljau(&points, t), point, 1000);
+        assert!((projection.t - exact).abs() < 1e-3);
+    }
+}
@@ -4,7 +4,7 @@ use num_traits::Num;
 
 use crate::canvas::math::point::Point;
 
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct Vector<T> {
     horizontal: T,
     vertical: T,
@@ -34,6 +34,10 @@ where
         self.horizontal * other.vertical - self.vertical * other.horizontal
     }
 
+    pub fn dot_product(&self, other: Self) -> T {
+        self.horizontal * other.horizontal + self.vertical * other.vertical
+    }
+
     pub fn into_point(self, origin: Point<T>) -> Point<T> {
         origin + self
     }
@@ -8,11 +8,15 @@ use shape::Shape;
 
 use crate::canvas::math::point::Point;
 use crate::canvas::math::rectangle::Rectangle;
-use crate::canvas::objects::Objects;
+use crate::canvas::objects::{ObjectId, Objects};
 use crate::canvas::request::declare::AddPoint;
+use crate::canvas::shape::request::declare::SplitCurve;
+use crate::canvas::shape::DrawOn;
 use crate::canvas::state::CanvasState;
+use crate::canvas::visual_path::point::{VisualPoint, VisualPointProperties};
+use crate::config::rgb::Alpha;
 use crate::config::{CanvasConfig, ShapeType};
-use crate::request::RequestHandlerMut;
+use crate::request::{Error, RequestHandler, RequestHandlerMut};
 
 pub mod base_line;
 pub mod control_points;
@@ -20,6 +24,7 @@ pub mod control_points_curve;
 pub mod math;
 pub mod objects;
 pub mod paint;
+pub mod placement;
 pub mod polygon;
 pub mod request;
 pub mod samples;
@@ -33,6 +38,7 @@ pub struct Canvas {
     objects: Objects,
     size: Rectangle<f32>,
     state: CanvasState,
+    markers: VisualPoint,
     config: CanvasConfig,
 }
 
@@ -40,7 +46,15 @@ impl Canvas {
     #[must_use]
     pub fn new(objects: Objects, size: Rectangle<f32>, config: CanvasConfig) -> Self {
         let state = CanvasState::default();
-        Self { objects, size, state, config }
+        let markers = VisualPoint::new(
+            true,
+            VisualPointProperties::new(
+                config.default_point_radius,
+                config.marker_color,
+                Alpha::OPAQUE,
+            ),
+        );
+        Self { objects, size, state, markers, config }
     }
 
     #[must_use]
@@ -61,12 +75,12 @@ impl Canvas {
     }
 
     pub fn replace_objects_from_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
-        let mut objects = Objects::from_file(path)?;
-        objects.update_all();
-        self.objects = objects;
+        self.objects = Objects::from_file(path)?;
+        self.update_all();
         Ok(())
     }
 
+    /// Paths are not saved, so every layer of a loaded object has to be rebuilt.
     fn update_all(&mut self) {
         self.objects.update_all();
     }
@@ -82,6 +96,7 @@ impl Canvas {
 
     pub fn draw_on_all(&self, pixmap: &mut PixmapMut<'_>) {
         self.objects.draw_on_all(pixmap);
+        self.markers.draw_on(pixmap);
     }
 
     pub fn generate_random_points(&mut self, number_of_points: u32) -> Result<()> {
@@ -112,6 +127,20 @@ impl Canvas {
         self.objects.get_mut(self.state.current_curve).expect("current object id should be valid")
     }
 
+    /// The second part becomes the current curve.
+    fn split_curve(&mut self, id: ObjectId, t: f32) -> Result<(), Error> {
+        let curve = self.objects.get(id).ok_or(Error::NoSuchCurve(id))?;
+        let (first, second) = curve.handle(SplitCurve::new(t))?;
+        self.objects.remove(id);
+        self.objects.add(first);
+        self.state.current_curve = self.objects.add(second);
+        Ok(())
+    }
+
+    pub fn set_markers(&mut self, markers: &[Point<f32>]) {
+        self.markers.rebuild_path(markers.iter().copied());
+    }
+
     #[must_use]
     pub fn objects_length(&self) -> usize {
         self.objects.length()
@@ -3,6 +3,7 @@ use std::path::Path;
 
 use anyhow::Result;
 use indexmap::{indexmap, IndexMap};
+use rayon::prelude::*;
 use serde::{Deserialize, Deserializer};
 use tiny_skia::PixmapMut;
 
@@ -47,9 +48,7 @@ impl Objects {
     }
 
     pub fn update_all(&mut self) {
-        for object in self.objects.values_mut() {
-            object.update();
-        }
+        self.objects.par_values_mut().for_each(Update::update);
     }
 
     pub fn draw_on_all(&self, pixmap: &mut PixmapMut<'_>) {
@@ -0,0 +1,57 @@
+use crate::canvas::math::point::Point;
+use crate::config::CanvasConfig;
+
+pub mod request;
+
+/// Scales, rotates around the origin and then moves points to the position.
+#[derive(Debug, Copy, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Placement {
+    pub position: Point<f32>,
+    pub scale: f32,
+    pub rotation: f32,
+}
+
+impl Placement {
+    #[must_use]
+    pub fn new(position: Point<f32>, scale: f32, rotation: f32) -> Self {
+        Self { position, scale, rotation }
+    }
+
+    #[must_use]
+    pub fn apply(&self, point: Point<f32>) -> Point<f32> {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (horizontal, vertical) = (point.horizontal(), point.vertical());
+        let rotated_horizontal = cos * horizontal - sin * vertical;
+        let rotated_vertical = sin * horizontal + cos * vertical;
+        Point::new(
+            self.position.horizontal() + self.scale * rotated_horizontal,
+            self.position.vertical() + self.scale * rotated_vertical,
+        )
+    }
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Self::new(Point::new(250.0, 250.0), 200.0, 0.0)
+    }
+}
+
+impl From<&CanvasConfig> for Placement {
+    fn from(value: &CanvasConfig) -> Self {
+        value.default_placement
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    #[test]
+    fn apply_scales_rotates_and_moves() {
+        let placement = Placement::new(Point::new(10.0, 20.0), 2.0, FRAC_PI_2);
+        let point = placement.apply(Point::new(1.0, 0.0));
+        assert!(point.distance_squared(Point::new(10.0, 22.0)) < 1e-8);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::canvas::placement::Placement;
+use crate::canvas::shape::request::declare::{
+    GetCurveCenter, GetPlacement, MoveCurve, RotateCurve, SetPlacement,
+};
+use crate::request::{RequestHandler, RequestHandlerMut, Response, ResponseMut};
+
+impl RequestHandlerMut<MoveCurve> for Placement {
+    fn handle_mut(&mut self, request: MoveCurve) -> ResponseMut<MoveCurve> {
+        self.position = self.position + request.shift;
+        Ok(())
+    }
+}
+
+impl RequestHandlerMut<RotateCurve> for Placement {
+    fn handle_mut(&mut self, request: RotateCurve) -> ResponseMut<RotateCurve> {
+        self.rotation += request.angle;
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetCurveCenter> for Placement {
+    fn handle(&self, _request: GetCurveCenter) -> Response<GetCurveCenter> {
+        Ok(Some(self.position))
+    }
+}
+
+impl RequestHandler<GetPlacement> for Placement {
+    fn handle(&self, _request: GetPlacement) -> Response<GetPlacement> {
+        Ok(*self)
+    }
+}
+
+impl RequestHandlerMut<SetPlacement> for Placement {
+    fn handle_mut(&mut self, request: SetPlacement) -> ResponseMut<SetPlacement> {
+        *self = request.0;
+        Ok(())
+    }
+}
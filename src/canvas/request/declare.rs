@@ -1,4 +1,6 @@
+use crate::canvas::math::intersection::Intersection;
 use crate::canvas::math::point::Point;
+use crate::canvas::math::projection::Projection;
 use crate::canvas::math::vector::Vector;
 use crate::config::ShapeType;
 use crate::request::macros::declare_requests;
@@ -13,8 +15,13 @@ declare_requests! {
     // Delegated from curve
     // { SelectPoint { guess: Point<f32>, radius: f32 } -> Option<PointId> },
     { GetCurvesLength () -> usize },
-    { GetLength (usize) -> usize },
+    { GetLength (usize) -> f32 },
+    { GetPointsLength (usize) -> usize },
+    { GetEquidistantPointsOnCurve (usize, usize) -> Vec<Point<f32>> },
     { GetPointOnCurve (usize, PointId) -> Point<f32> },
+    { GetIntersections (usize, usize) -> Vec<Intersection> },
+    { ProjectPointOnCurve (usize, Point<f32>) -> Projection },
+    { SelectCurve { point: Point<f32>, radius: f32 } -> Option<usize> },
 
     { mut ChangeCurrentPointWeight { weight: f32 } -> () },
     { mut DeleteCurrentPoint () -> () },
@@ -22,6 +29,8 @@ declare_requests! {
     { mut AddPoint { point: Point<f32> } -> () },
     { mut ChangeCurrentPointIndex { change: i32 } -> () },
     { mut AddCurve () -> () },
+    { mut AddFreehandCurve { stroke: Vec<Point<f32>> } -> () },
+    { mut AddOffsetCurve { distance: f32, tolerance: Option<f32> } -> () },
     { mut DeleteCurve () -> () },
     { mut ChangeCurrentCurveIndex { change: i32 } -> () },
     { mut SetConvexHull (bool) -> () },
@@ -32,4 +41,8 @@ declare_requests! {
     // Delegated from curve
     // { mut MoveCurve { shift: Vector<f32> } -> () },
     { mut MovePointOnCurve (usize, PointId, Point<f32>) -> () },
+    { mut SplitCurveById { curve: usize, t: f32 } -> () },
+    { mut SplitCurrentCurve { point: Point<f32> } -> () },
+    { mut InsertHodograph () -> () },
+    { mut SetMarkers (Vec<Point<f32>>) -> () },
 }
@@ -1,18 +1,38 @@
+use anyhow::anyhow;
+
 use crate::canvas::control_points::weighted::WeightedPoint;
+use crate::canvas::control_points::ControlPoints;
+use crate::canvas::math::intersection::{self, Intersection};
+use crate::canvas::math::point::Point;
 use crate::canvas::request::declare::{
-    AddCurve, AddPoint, ChangeCurrentCurveIndex, ChangeCurrentPointIndex, ChangeCurrentPointWeight,
-    DeleteCurrentPoint, DeleteCurve, GetConvexHull, GetCurrentPoint, GetCurveType, GetCurvesLength,
-    GetLength, GetPointOnCurve, MoveCurrentPoint, MovePointOnCurve, RotateCurveById, SetConvexHull,
-    SetCurveType,
+    AddCurve, AddFreehandCurve, AddOffsetCurve, AddPoint, ChangeCurrentCurveIndex,
+    ChangeCurrentPointIndex, ChangeCurrentPointWeight, DeleteCurrentPoint, DeleteCurve,
+    GetConvexHull, GetCurrentPoint, GetCurveType, GetCurvesLength, GetEquidistantPointsOnCurve,
+    GetIntersections, GetLength, GetPointOnCurve, GetPointsLength, InsertHodograph,
+    MoveCurrentPoint, MovePointOnCurve, ProjectPointOnCurve, RotateCurveById, SelectCurve,
+    SetConvexHull, SetCurveType, SetMarkers, SplitCurrentCurve, SplitCurveById,
 };
+use crate::canvas::shape::bezier_spline::{BezierSplineProperties, JointContinuity};
 use crate::canvas::shape::request::declare::{
-    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, GetControlPointsLength,
-    GetCurveCenter, GetInterpolationNodes, GetPoint, GetSamples, GetWeight, MoveCurve, MovePoint,
-    RotateCurve, SelectPoint, SetInterpolationNodes, SetSamples,
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSamples, GetSampling, GetSplineBoundary,
+    GetSplineDegree, GetSplineParametrisation, GetTangent, GetTcbParameters, GetWeight, MoveCurve,
+    MovePoint, ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily,
+    SetBezierAlgorithm, SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes,
+    SetJointContinuity, SetKnotVector, SetOverlayParameter, SetOverlayVisibility,
+    SetParametricConstant, SetParametricExpressions, SetPlacement, SetPolygonRadius,
+    SetPolygonVertices, SetSamples, SetSampling, SetSplineBoundary, SetSplineDegree,
+    SetSplineParametrisation, SetTangent, SetTcbParameters,
 };
-use crate::canvas::shape::shape_changer::ShapeChanger;
+use crate::canvas::shape::shape_changer::{ShapeChanger, ShapeCommonValues};
 use crate::canvas::shape::Shape;
 use crate::canvas::{math, Canvas};
+use crate::config::ShapeType;
 use crate::request::macros::delegate_requests;
 use crate::request::{
     Error, RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
@@ -24,14 +44,59 @@ pub mod declare;
 delegate_requests! {
     Canvas {
         { GetSamples => Shape },
+        { GetSampling => Shape },
         { GetInterpolationNodes => Shape },
         { GetCurveCenter => Shape },
         { SelectPoint => Shape },
         { GetPoint => Shape },
         { mut SetSamples => Shape },
+        { mut SetSampling => Shape },
         { mut SetInterpolationNodes => Shape },
         { mut MoveCurve => Shape },
         { mut RotateCurve => Shape },
+        { GetSplineDegree => Shape },
+        { mut SetSplineDegree => Shape },
+        { GetKnotVector => Shape },
+        { mut SetKnotVector => Shape },
+        { GetJointContinuity => Shape },
+        { mut SetJointContinuity => Shape },
+        { GetSplineBoundary => Shape },
+        { mut SetSplineBoundary => Shape },
+        { GetSplineParametrisation => Shape },
+        { mut SetSplineParametrisation => Shape },
+        { GetTcbParameters => Shape },
+        { mut SetTcbParameters => Shape },
+        { GetTangent => Shape },
+        { mut SetTangent => Shape },
+        { GetPolygonVertices => Shape },
+        { mut SetPolygonVertices => Shape },
+        { GetPolygonRadius => Shape },
+        { mut SetPolygonRadius => Shape },
+        { GetPlacement => Shape },
+        { mut SetPlacement => Shape },
+        { GetParametricExpressions => Shape },
+        { mut SetParametricExpressions => Shape },
+        { GetParametricConstants => Shape },
+        { mut SetParametricConstant => Shape },
+        { GetAnalyticFamily => Shape },
+        { mut SetAnalyticFamily => Shape },
+        { GetFittingBasis => Shape },
+        { mut SetFittingBasis => Shape },
+        { GetFittingDegree => Shape },
+        { mut SetFittingDegree => Shape },
+        { mut ElevateDegree => Shape },
+        { mut ReduceDegree => Shape },
+        { GetBezierAlgorithm => Shape },
+        { mut SetBezierAlgorithm => Shape },
+        { GetArcLength => Shape },
+        { GetCurveFeatures => Shape },
+        { ProjectPoint => Shape },
+        { GetOverlayVisibility => Shape },
+        { mut SetOverlayVisibility => Shape },
+        { GetOverlayParameter => Shape },
+        { mut SetOverlayParameter => Shape },
+        { GetCombScale => Shape },
+        { mut SetCombScale => Shape },
 
         // TODO: reimplement in curve internals
         { GetConvexHull => ! },
@@ -61,6 +126,49 @@ impl RequestHandlerMut<AddCurve> for Canvas {
     }
 }
 
+impl RequestHandlerMut<AddFreehandCurve> for Canvas {
+    fn handle_mut(&mut self, event: AddFreehandCurve) -> ResponseMut<AddFreehandCurve> {
+        let points =
+            math::bezier_fitting::fit_cubic_spline(&event.stroke, self.config.freehand_tolerance);
+        let values = ShapeCommonValues {
+            points: Some(ControlPoints::new(points)),
+            bezier_spline_properties: Some(BezierSplineProperties::new(JointContinuity::G1)),
+            ..Default::default()
+        };
+        let curve =
+            ShapeChanger::from_values(values, &self.config).into_shape(ShapeType::BezierSpline);
+        let id = self.objects.add(curve);
+        self.state.current_curve = id;
+        Ok(())
+    }
+}
+
+impl RequestHandlerMut<AddOffsetCurve> for Canvas {
+    fn handle_mut(&mut self, event: AddOffsetCurve) -> ResponseMut<AddOffsetCurve> {
+        let id = self.state.current_curve;
+        let curve = self.objects.get(id).ok_or(Error::NoSuchCurve(id))?;
+        let path = curve.handle(GetSampledPath)?;
+        let parts = math::offset::offset_polyline(&path, event.distance);
+        let (points, shape_type) = match event.tolerance {
+            Some(tolerance) => {
+                (math::offset::into_bezier_spline(&parts, tolerance), ShapeType::BezierSpline)
+            }
+            None => (math::offset::into_polyline(&parts), ShapeType::Polyline),
+        };
+        if points.len() < 2 {
+            return Err(Error::Other(anyhow!("offset of the curve is empty")));
+        }
+        let values = ShapeCommonValues {
+            points: Some(ControlPoints::new(points)),
+            bezier_spline_properties: Some(BezierSplineProperties::new(JointContinuity::C0)),
+            ..Default::default()
+        };
+        let curve = ShapeChanger::from_values(values, &self.config).into_shape(shape_type);
+        self.state.current_curve = self.objects.add(curve);
+        Ok(())
+    }
+}
+
 impl RequestHandlerMut<DeleteCurve> for Canvas {
     fn handle_mut(&mut self, _event: DeleteCurve) -> ResponseMut<DeleteCurve> {
         let current_curve = self.state.current_curve;
@@ -85,12 +193,28 @@ impl RequestHandlerMut<ChangeCurrentCurveIndex> for Canvas {
 
 impl RequestHandler<GetLength> for Canvas {
     fn handle(&self, event: GetLength) -> Response<GetLength> {
+        let object = self.objects.get(event.0).ok_or_else(|| Error::NoSuchCurve(event.0))?;
+        let length = object.handle(GetArcLength)?;
+        Ok(length)
+    }
+}
+
+impl RequestHandler<GetPointsLength> for Canvas {
+    fn handle(&self, event: GetPointsLength) -> Response<GetPointsLength> {
         let object = self.objects.get(event.0).ok_or_else(|| Error::NoSuchCurve(event.0))?;
         let length = object.handle(GetControlPointsLength)?;
         Ok(length)
     }
 }
 
+impl RequestHandler<GetEquidistantPointsOnCurve> for Canvas {
+    fn handle(&self, event: GetEquidistantPointsOnCurve) -> Response<GetEquidistantPointsOnCurve> {
+        let object = self.objects.get(event.0).ok_or_else(|| Error::NoSuchCurve(event.0))?;
+        let points = object.handle(GetEquidistantPoints::new(event.1))?;
+        Ok(points)
+    }
+}
+
 impl RequestHandler<GetCurvesLength> for Canvas {
     fn handle(&self, _event: GetCurvesLength) -> Response<GetCurvesLength> {
         let length = self.objects.length();
@@ -106,6 +230,74 @@ impl RequestHandler<GetPointOnCurve> for Canvas {
     }
 }
 
+impl RequestHandler<ProjectPointOnCurve> for Canvas {
+    fn handle(&self, event: ProjectPointOnCurve) -> Response<ProjectPointOnCurve> {
+        let object = self.objects.get(event.0).ok_or_else(|| Error::NoSuchCurve(event.0))?;
+        object.handle(ProjectPoint::new(event.1))
+    }
+}
+
+impl RequestHandler<SelectCurve> for Canvas {
+    fn handle(&self, event: SelectCurve) -> Response<SelectCurve> {
+        let nearest = self
+            .objects
+            .ids()
+            .filter_map(|id| {
+                let object = self.objects.get(id)?;
+                let projection = object.handle(ProjectPoint::new(event.point)).ok()?;
+                Some((id, projection.distance))
+            })
+            .filter(|(_, distance)| *distance <= event.radius)
+            .min_by(|(_, first), (_, second)| first.total_cmp(second));
+        Ok(nearest.map(|(id, _)| id))
+    }
+}
+
+impl RequestHandler<GetIntersections> for Canvas {
+    fn handle(&self, event: GetIntersections) -> Response<GetIntersections> {
+        if event.0 == event.1 {
+            return Err(Error::Other(anyhow!("cannot intersect curve with itself")));
+        }
+        let first = self.objects.get(event.0).ok_or_else(|| Error::NoSuchCurve(event.0))?;
+        let second = self.objects.get(event.1).ok_or_else(|| Error::NoSuchCurve(event.1))?;
+        if let (Ok(first), Ok(second)) =
+            (first.handle(GetBezierSegments), second.handle(GetBezierSegments))
+        {
+            return Ok(segments_intersections(&first, &second));
+        }
+        let first = first.handle(GetSampledPath)?;
+        let second = second.handle(GetSampledPath)?;
+        Ok(intersection::polyline_intersections(&first, &second))
+    }
+}
+
+/// Parameters of the segments are scaled, so that each curve is parametrised over `[0, 1]`.
+fn segments_intersections(
+    first: &[Vec<Point<f32>>],
+    second: &[Vec<Point<f32>>],
+) -> Vec<Intersection> {
+    let scale = |index: usize, t: f32, length: usize| (index as f32 + t) / length as f32;
+    let mut intersections = Vec::new();
+    for (i, first_segment) in first.iter().enumerate() {
+        for (j, second_segment) in second.iter().enumerate() {
+            let found = intersection::bezier_intersections(first_segment, second_segment);
+            intersections.extend(found.into_iter().map(|found| {
+                let first_t = scale(i, found.first, first.len());
+                let second_t = scale(j, found.second, second.len());
+                Intersection::new(found.point, first_t, second_t)
+            }));
+        }
+    }
+    intersections
+}
+
+impl RequestHandlerMut<SetMarkers> for Canvas {
+    fn handle_mut(&mut self, event: SetMarkers) -> ResponseMut<SetMarkers> {
+        self.set_markers(&event.0);
+        Ok(())
+    }
+}
+
 impl RequestHandlerMut<MovePointOnCurve> for Canvas {
     fn handle_mut(&mut self, event: MovePointOnCurve) -> ResponseMut<MovePointOnCurve> {
         let object = self.objects.get_mut(event.0).ok_or_else(|| Error::NoSuchCurve(event.0))?;
@@ -208,3 +400,28 @@ impl RequestHandlerMut<RotateCurveById> for Canvas {
         Ok(())
     }
 }
+
+impl RequestHandlerMut<SplitCurveById> for Canvas {
+    fn handle_mut(&mut self, event: SplitCurveById) -> ResponseMut<SplitCurveById> {
+        self.split_curve(event.curve, event.t)
+    }
+}
+
+impl RequestHandlerMut<InsertHodograph> for Canvas {
+    fn handle_mut(&mut self, _event: InsertHodograph) -> ResponseMut<InsertHodograph> {
+        let id = self.state.current_curve;
+        let curve = self.objects.get(id).ok_or(Error::NoSuchCurve(id))?;
+        let hodograph = curve.handle(GetHodograph)?;
+        self.state.current_curve = self.objects.add(hodograph);
+        Ok(())
+    }
+}
+
+impl RequestHandlerMut<SplitCurrentCurve> for Canvas {
+    fn handle_mut(&mut self, event: SplitCurrentCurve) -> ResponseMut<SplitCurrentCurve> {
+        let id = self.state.current_curve;
+        let curve = self.objects.get(id).ok_or_else(|| Error::NoSuchCurve(id))?;
+        let t = curve.handle(GetNearestParameter::new(event.point))?;
+        self.split_curve(id, t)
+    }
+}
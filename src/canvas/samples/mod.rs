@@ -1,14 +1,52 @@
+use std::iter;
 use std::ops::{Range, RangeInclusive};
 
+use itertools::Either;
 use num_traits::{Num, NumCast};
+use rayon::prelude::*;
 
+use crate::canvas::control_points::point::CurvePoint;
 use crate::config::CanvasConfig;
 
 pub mod request;
 
+/// Equal parts subdivided separately, so that features symmetric around the middle are found.
+const ADAPTIVE_SEGMENTS: usize = 8;
+const ADAPTIVE_MAX_DEPTH: u32 = 12;
+const PARALLEL_SAMPLES: usize = 256;
+const PARALLEL_CHUNK: usize = 128;
+
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Samples {
     samples: usize,
+    #[serde(default)]
+    sampling: Sampling,
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    clap::Subcommand,
+    parse_display::Display,
+)]
+pub enum Sampling {
+    #[default]
+    #[command()]
+    #[display("equally-spaced")]
+    EquallySpaced,
+
+    /// Tolerance is given in pixels
+    #[command()]
+    #[display("adaptive {tolerance}")]
+    Adaptive {
+        #[arg()]
+        tolerance: f32,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -22,7 +60,60 @@ pub struct EquallySpacedIterator<T> {
 impl Samples {
     #[must_use]
     pub fn new(samples: usize) -> Self {
-        Self { samples }
+        Self { samples, sampling: Sampling::default() }
+    }
+
+    /// Returns points of the curve paired with their parameters.
+    pub fn sample(
+        &self,
+        range: RangeInclusive<f32>,
+        mut curve: impl FnMut(f32) -> CurvePoint,
+    ) -> impl Iterator<Item = (f32, CurvePoint)> {
+        match self.sampling {
+            Sampling::EquallySpaced => {
+                Either::Left(self.equally_spaced(range).map(move |t| (t, curve(t))))
+            }
+            Sampling::Adaptive { tolerance } => {
+                Either::Right(adaptive(range, &mut curve, tolerance).into_iter())
+            }
+        }
+    }
+
+    /// Samples like [`Samples::sample`] on the worker pool, with state created by `init` per task.
+    pub fn sample_parallel<S>(
+        &self,
+        range: RangeInclusive<f32>,
+        init: impl Fn() -> S + Send + Sync,
+        curve: impl Fn(&mut S, f32) -> CurvePoint + Send + Sync,
+    ) -> impl Iterator<Item = (f32, CurvePoint)> {
+        let points = match self.sampling {
+            Sampling::EquallySpaced if self.samples >= PARALLEL_SAMPLES => {
+                let parameters = self.equally_spaced(range);
+                (0..self.samples)
+                    .into_par_iter()
+                    .with_min_len(PARALLEL_CHUNK)
+                    .map_init(init, |state, index| {
+                        let t = parameters.value(index);
+                        (t, curve(state, t))
+                    })
+                    .collect::<Vec<_>>()
+            }
+            Sampling::Adaptive { tolerance } => {
+                let first = (*range.start(), curve(&mut init(), *range.start()));
+                let segments = (0..ADAPTIVE_SEGMENTS)
+                    .into_par_iter()
+                    .map_init(&init, |state, index| {
+                        adaptive_segment(&range, index, &mut |t| curve(state, t), tolerance)
+                    })
+                    .collect::<Vec<_>>();
+                iter::once(first).chain(segments.into_iter().flatten()).collect()
+            }
+            _ => {
+                let mut state = init();
+                self.sample(range, |t| curve(&mut state, t)).collect::<Vec<_>>()
+            }
+        };
+        points.into_iter()
     }
 
     #[must_use]
@@ -37,6 +128,75 @@ impl Samples {
     pub fn samples(&self) -> usize {
         self.samples
     }
+
+    #[must_use]
+    pub fn sampling(&self) -> Sampling {
+        self.sampling
+    }
+}
+
+fn adaptive(
+    range: RangeInclusive<f32>,
+    curve: &mut impl FnMut(f32) -> CurvePoint,
+    tolerance: f32,
+) -> Vec<(f32, CurvePoint)> {
+    let mut points = vec![(*range.start(), curve(*range.start()))];
+    for index in 0..ADAPTIVE_SEGMENTS {
+        points.extend(adaptive_segment(&range, index, curve, tolerance));
+    }
+    points
+}
+
+fn adaptive_segment(
+    range: &RangeInclusive<f32>,
+    index: usize,
+    curve: &mut impl FnMut(f32) -> CurvePoint,
+    tolerance: f32,
+) -> Vec<(f32, CurvePoint)> {
+    let (start, end) = (*range.start(), *range.end());
+    let step = (end - start) / ADAPTIVE_SEGMENTS as f32;
+    let from = start + index as f32 * step;
+    let to = if index + 1 == ADAPTIVE_SEGMENTS { end } else { from + step };
+    let (first, last) = (curve(from), curve(to));
+    let mut points = Vec::new();
+    subdivide(curve, (from, first), (to, last), tolerance, ADAPTIVE_MAX_DEPTH, &mut points);
+    points
+}
+
+fn subdivide(
+    curve: &mut impl FnMut(f32) -> CurvePoint,
+    (start, start_point): (f32, CurvePoint),
+    (end, end_point): (f32, CurvePoint),
+    tolerance: f32,
+    depth: u32,
+    points: &mut Vec<(f32, CurvePoint)>,
+) {
+    let middle = (start + end) / 2.0;
+    let middle_point = curve(middle);
+    let is_flat = [curve((start + middle) / 2.0), middle_point, curve((middle + end) / 2.0)]
+        .into_iter()
+        .all(|point| {
+            let distance = distance_to_segment(point, start_point, end_point);
+            distance <= tolerance || distance.is_nan()
+        });
+    if depth == 0 || is_flat {
+        points.push((end, end_point));
+        return;
+    }
+    let middle = (middle, middle_point);
+    subdivide(curve, (start, start_point), middle, tolerance, depth - 1, points);
+    subdivide(curve, middle, (end, end_point), tolerance, depth - 1, points);
+}
+
+fn distance_to_segment(point: CurvePoint, start: CurvePoint, end: CurvePoint) -> f32 {
+    let segment = end - start;
+    let length = segment.dot_product(segment);
+    let ratio = if length > f32::EPSILON {
+        ((point - start).dot_product(segment) / length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    point.distance_squared(start + segment * ratio).sqrt()
 }
 
 impl<T> EquallySpacedIterator<T>
@@ -58,6 +218,14 @@ where
     pub fn steps(&self) -> usize {
         self.iterator.end
     }
+
+    /// Ignores the position of the iterator.
+    #[must_use]
+    pub fn value(&self, index: usize) -> T {
+        let index = num_traits::cast::<usize, T>(index)
+            .expect("index should be representable by the given type");
+        self.start + (index * self.delta) / self.length
+    }
 }
 
 impl<T> Iterator for EquallySpacedIterator<T>
@@ -68,9 +236,7 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         let index = self.iterator.next()?;
-        let index = num_traits::cast::<usize, T>(index)
-            .expect("index should be representable by the given type");
-        Some(self.start + (index * self.delta) / self.length)
+        Some(self.value(index))
     }
 }
 
@@ -82,13 +248,14 @@ impl Default for Samples {
 
 impl From<&CanvasConfig> for Samples {
     fn from(value: &CanvasConfig) -> Self {
-        Self { samples: value.curve_samples as usize }
+        Self { samples: value.curve_samples as usize, sampling: Sampling::default() }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::canvas::math::point::Point;
 
     #[test]
     fn two_samples_iterator() {
@@ -98,6 +265,48 @@ mod tests {
         assert!(iterator.next().is_none());
     }
 
+    #[test]
+    fn adaptive_sampling_depends_on_flatness() {
+        let samples = Samples { samples: 1000, sampling: Sampling::Adaptive { tolerance: 0.5 } };
+        let line = samples.sample(0.0..=1.0, |t| Point::new(100.0 * t, 50.0 * t)).count();
+        assert_eq!(line, ADAPTIVE_SEGMENTS + 1);
+
+        let circle = |radius: f32| {
+            move |t: f32| {
+                let (sin, cos) = (t * std::f32::consts::TAU).sin_cos();
+                Point::new(radius * cos, radius * sin)
+            }
+        };
+        let small = samples.sample(0.0..=1.0, circle(10.0)).collect::<Vec<_>>();
+        let large = samples.sample(0.0..=1.0, circle(1000.0)).collect::<Vec<_>>();
+        assert!(small.len() < large.len());
+        assert!(large.iter().all(|&(t, point)| point.distance_squared(circle(1000.0)(t)) == 0.0));
+        let large = large.into_iter().map(|(_, point)| point).collect::<Vec<_>>();
+        for pair in large.windows(2) {
+            let middle = pair[0] + (pair[1] - pair[0]) * 0.5;
+            let distance = 1000.0 - middle.distance_squared(Point::zero()).sqrt();
+            assert!(distance <= 0.5 + 1e-2);
+        }
+    }
+
+    #[test]
+    fn parallel_sampling_keeps_order_of_samples() {
+        let samples = Samples::new(10 * PARALLEL_SAMPLES);
+        let curve = |t: f32| Point::new(t, t * t);
+        let serial = samples.sample(-1.0..=1.0, curve).collect::<Vec<_>>();
+        let parallel = samples.sample_parallel(-1.0..=1.0, || (), |(), t| curve(t));
+        assert!(serial.into_iter().eq(parallel));
+    }
+
+    #[test]
+    fn parallel_adaptive_sampling_gives_the_same_points() {
+        let samples = Samples { samples: 1000, sampling: Sampling::Adaptive { tolerance: 0.5 } };
+        let curve = |t: f32| Point::new(300.0 * t, 200.0 * (6.0 * t).sin());
+        let serial = samples.sample(0.0..=1.0, curve).collect::<Vec<_>>();
+        let parallel = samples.sample_parallel(0.0..=1.0, || (), |(), t| curve(t));
+        assert!(serial.into_iter().eq(parallel));
+    }
+
     #[test]
     fn three_samples_iterator() {
         let mut iterator = EquallySpacedIterator::new(0.0..=1.0, 3);
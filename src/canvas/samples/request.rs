@@ -1,6 +1,8 @@
-use crate::canvas::samples::Samples;
-use crate::canvas::shape::request::declare::{GetSamples, SetSamples};
-use crate::request::{RequestHandler, RequestHandlerMut, Response, ResponseMut};
+use anyhow::anyhow;
+
+use crate::canvas::samples::{Samples, Sampling};
+use crate::canvas::shape::request::declare::{GetSamples, GetSampling, SetSamples, SetSampling};
+use crate::request::{Error, RequestHandler, RequestHandlerMut, Response, ResponseMut};
 
 impl RequestHandlerMut<SetSamples> for Samples {
     fn handle_mut(&mut self, request: SetSamples) -> ResponseMut<SetSamples> {
@@ -14,3 +16,21 @@ impl RequestHandler<GetSamples> for Samples {
         Ok(self.samples as u32)
     }
 }
+
+impl RequestHandlerMut<SetSampling> for Samples {
+    fn handle_mut(&mut self, request: SetSampling) -> ResponseMut<SetSampling> {
+        if let Sampling::Adaptive { tolerance } = request.0 {
+            if tolerance.is_nan() || tolerance <= 0.0 {
+                return Err(Error::Other(anyhow!("tolerance of sampling should be positive")));
+            }
+        }
+        self.sampling = request.0;
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetSampling> for Samples {
+    fn handle(&self, _request: GetSampling) -> Response<GetSampling> {
+        Ok(self.sampling)
+    }
+}
@@ -0,0 +1,298 @@
+use std::f32::consts::{PI, TAU};
+use std::ops::RangeInclusive;
+
+use anyhow::anyhow;
+
+use crate::canvas::math::point::Point;
+
+/// Normalised to fit in the unit circle, so that scale of the placement is the outer radius.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    clap::Subcommand,
+    parse_display::Display,
+)]
+pub enum AnalyticFamily {
+    #[command(allow_negative_numbers(true))]
+    #[display("lissajous {0}")]
+    Lissajous(LissajousProperties),
+
+    #[command()]
+    #[display("rose {0}")]
+    Rose(RoseProperties),
+
+    #[command(allow_negative_numbers(true))]
+    #[display("archimedean-spiral {0}")]
+    ArchimedeanSpiral(SpiralProperties),
+
+    #[command(allow_negative_numbers(true))]
+    #[display("logarithmic-spiral {0}")]
+    LogarithmicSpiral(SpiralProperties),
+
+    #[command()]
+    #[display("superellipse {0}")]
+    Superellipse(SuperellipseProperties),
+}
+
+/// Curve `(sin(a t + delta), sin(b t))`.
+#[derive(
+    Debug, Copy, Clone, serde::Serialize, serde::Deserialize, clap::Args, parse_display::Display,
+)]
+#[display("{horizontal_frequency} {vertical_frequency} {phase}")]
+pub struct LissajousProperties {
+    #[arg()]
+    pub horizontal_frequency: f32,
+    #[arg()]
+    pub vertical_frequency: f32,
+    #[arg()]
+    pub phase: f32,
+}
+
+/// Curve `r = cos(n / d t)` in polar coordinates.
+#[derive(
+    Debug, Copy, Clone, serde::Serialize, serde::Deserialize, clap::Args, parse_display::Display,
+)]
+#[display("{numerator} {denominator}")]
+pub struct RoseProperties {
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub numerator: u32,
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub denominator: u32,
+}
+
+/// Spiral `r = a + b t` or `r = a exp(b t)` for `t` in `[0, 2 pi turns]`.
+#[derive(
+    Debug, Copy, Clone, serde::Serialize, serde::Deserialize, clap::Args, parse_display::Display,
+)]
+#[display("{a} {b} {turns}")]
+pub struct SpiralProperties {
+    #[arg()]
+    pub a: f32,
+    #[arg()]
+    pub b: f32,
+    #[arg()]
+    pub turns: f32,
+}
+
+/// Curve `|x / a|^n + |y / b|^n = 1`.
+#[derive(
+    Debug, Copy, Clone, serde::Serialize, serde::Deserialize, clap::Args, parse_display::Display,
+)]
+#[display("{a} {b} {exponent}")]
+pub struct SuperellipseProperties {
+    #[arg()]
+    pub a: f32,
+    #[arg()]
+    pub b: f32,
+    #[arg()]
+    pub exponent: f32,
+}
+
+impl AnalyticFamily {
+    pub fn check(&self) -> anyhow::Result<()> {
+        let error = match *self {
+            AnalyticFamily::Lissajous(LissajousProperties { vertical_frequency: 0.0, .. }) => {
+                Some("vertical frequency should be non-zero")
+            }
+            AnalyticFamily::ArchimedeanSpiral(SpiralProperties { turns, .. })
+            | AnalyticFamily::LogarithmicSpiral(SpiralProperties { turns, .. })
+                if turns <= 0.0 =>
+            {
+                Some("number of turns should be positive")
+            }
+            AnalyticFamily::ArchimedeanSpiral(SpiralProperties { a, b, .. })
+                if a == 0.0 && b == 0.0 =>
+            {
+                Some("spiral should have non-zero size")
+            }
+            AnalyticFamily::LogarithmicSpiral(SpiralProperties { a: 0.0, .. }) => {
+                Some("spiral should have non-zero size")
+            }
+            AnalyticFamily::Superellipse(SuperellipseProperties { exponent, .. })
+                if exponent <= 0.0 =>
+            {
+                Some("exponent should be positive")
+            }
+            AnalyticFamily::Superellipse(SuperellipseProperties { a, b, .. })
+                if a == 0.0 && b == 0.0 =>
+            {
+                Some("superellipse should have non-zero size")
+            }
+            _ => None,
+        };
+        if let Some(error) = error {
+            return Err(anyhow!(error));
+        }
+
+        let range = self.range();
+        let (start, end) = (*range.start(), *range.end());
+        let finite = end.is_finite()
+            && [start, (start + end) / 2.0, end].into_iter().all(|t| {
+                let (x, y) = self.point(t).into();
+                x.is_finite() && y.is_finite()
+            });
+        if !finite {
+            return Err(anyhow!("parameters of {self} give points out of range"));
+        }
+        Ok(())
+    }
+
+    /// Closes the curve or, for spirals, makes the given number of turns.
+    #[must_use]
+    pub fn range(&self) -> RangeInclusive<f32> {
+        let end = match self {
+            AnalyticFamily::Lissajous(properties) => {
+                let LissajousProperties { horizontal_frequency, vertical_frequency, .. } =
+                    *properties;
+                let ratio = horizontal_frequency / vertical_frequency;
+                TAU * denominator(ratio) as f32 / vertical_frequency.abs()
+            }
+            AnalyticFamily::Rose(properties) => {
+                let divisor = gcd(properties.numerator, properties.denominator);
+                let numerator = properties.numerator / divisor;
+                let denominator = properties.denominator / divisor;
+                let period = if numerator % 2 == 1 && denominator % 2 == 1 { PI } else { TAU };
+                period * denominator as f32
+            }
+            AnalyticFamily::ArchimedeanSpiral(properties)
+            | AnalyticFamily::LogarithmicSpiral(properties) => TAU * properties.turns,
+            AnalyticFamily::Superellipse(_) => TAU,
+        };
+        0.0..=end
+    }
+
+    #[must_use]
+    pub fn point(&self, t: f32) -> Point<f32> {
+        match self {
+            AnalyticFamily::Lissajous(LissajousProperties {
+                horizontal_frequency,
+                vertical_frequency,
+                phase,
+            }) => Point::new(
+                f32::sin(horizontal_frequency * t + phase),
+                f32::sin(vertical_frequency * t),
+            ),
+            AnalyticFamily::Rose(RoseProperties { numerator, denominator }) => {
+                let radius = f32::cos(*numerator as f32 / *denominator as f32 * t);
+                polar(radius, t)
+            }
+            AnalyticFamily::ArchimedeanSpiral(SpiralProperties { a, b, turns }) => {
+                let extent = f32::max(a.abs(), (a + b * TAU * turns).abs());
+                polar((a + b * t) / extent, t)
+            }
+            AnalyticFamily::LogarithmicSpiral(SpiralProperties { a, b, turns }) => {
+                let extent = a.abs() * f32::max(1.0, f32::exp(b * TAU * turns));
+                polar(a * f32::exp(b * t) / extent, t)
+            }
+            AnalyticFamily::Superellipse(SuperellipseProperties { a, b, exponent }) => {
+                let extent = f32::max(a.abs(), b.abs());
+                let power = 2.0 / exponent;
+                let (sin, cos) = t.sin_cos();
+                let x = a * cos.abs().powf(power).copysign(cos);
+                let y = b * sin.abs().powf(power).copysign(sin);
+                Point::new(x / extent, y / extent)
+            }
+        }
+    }
+}
+
+impl Default for AnalyticFamily {
+    fn default() -> Self {
+        AnalyticFamily::Lissajous(LissajousProperties {
+            horizontal_frequency: 3.0,
+            vertical_frequency: 2.0,
+            phase: 0.5,
+        })
+    }
+}
+
+fn polar(radius: f32, angle: f32) -> Point<f32> {
+    let (sin, cos) = angle.sin_cos();
+    Point::new(radius * cos, radius * sin)
+}
+
+/// Bounded, because irrational ratios never close.
+fn denominator(ratio: f32) -> u32 {
+    const MAX_DENOMINATOR: u32 = 64;
+    const TOLERANCE: f32 = 1e-4;
+
+    let ratio = ratio.abs();
+    if !ratio.is_finite() {
+        return 1;
+    }
+    let (mut previous, mut current) = (0_u32, 1_u32);
+    let mut remainder = ratio;
+    loop {
+        let numerator = (ratio * current as f32).round();
+        if (numerator / current as f32 - ratio).abs() < TOLERANCE {
+            return current;
+        }
+        remainder = 1.0 / remainder.fract();
+        let next = (remainder as u32).saturating_mul(current).saturating_add(previous);
+        if next > MAX_DENOMINATOR {
+            return current;
+        }
+        (previous, current) = (current, next);
+    }
+}
+
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn denominator_of_rational_ratios() {
+        assert_eq!(denominator(3.0), 1);
+        assert_eq!(denominator(2.5), 2);
+        assert_eq!(denominator(5.0 / 3.0), 3);
+        assert_eq!(denominator(0.25), 4);
+    }
+
+    #[test]
+    fn curves_close_at_the_end_of_range() {
+        let families = [
+            AnalyticFamily::Lissajous(LissajousProperties {
+                horizontal_frequency: 3.0,
+                vertical_frequency: 2.0,
+                phase: 0.5,
+            }),
+            AnalyticFamily::Rose(RoseProperties { numerator: 3, denominator: 5 }),
+            AnalyticFamily::Superellipse(SuperellipseProperties { a: 2.0, b: 1.0, exponent: 4.0 }),
+        ];
+        for family in families {
+            let range = family.range();
+            let start = family.point(*range.start());
+            let end = family.point(*range.end());
+            assert!(start.distance_squared(end) < 1e-6, "{family} does not close");
+        }
+    }
+
+    #[test]
+    fn degenerate_families_are_rejected() {
+        let degenerate = [
+            AnalyticFamily::Lissajous(LissajousProperties {
+                horizontal_frequency: 3.0,
+                vertical_frequency: 0.0,
+                phase: 0.0,
+            }),
+            AnalyticFamily::LogarithmicSpiral(SpiralProperties { a: 0.0, b: 0.2, turns: 3.0 }),
+            AnalyticFamily::LogarithmicSpiral(SpiralProperties { a: 1.0, b: 100.0, turns: 3.0 }),
+            AnalyticFamily::ArchimedeanSpiral(SpiralProperties { a: 1.0, b: 1.0, turns: 0.0 }),
+            AnalyticFamily::Superellipse(SuperellipseProperties { a: 2.0, b: 1.0, exponent: 0.0 }),
+        ];
+        for family in degenerate {
+            assert!(family.check().is_err(), "{family} should be rejected");
+        }
+        assert!(AnalyticFamily::default().check().is_ok());
+    }
+}
@@ -0,0 +1,78 @@
+use std::ops::RangeInclusive;
+
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::math::point::Point;
+use crate::canvas::placement::Placement;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::analytic::family::AnalyticFamily;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::shape_changer::ShapeCommonValues;
+use crate::canvas::shape::{DrawOn, Update};
+use crate::config::CanvasConfig;
+
+pub mod family;
+pub mod request;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct AnalyticCurve {
+    base_line: OpenBaseLine,
+    family: AnalyticFamily,
+    placement: Placement,
+    samples: Samples,
+}
+
+impl AnalyticCurve {
+    #[must_use]
+    pub fn new(
+        base_line: OpenBaseLine,
+        family: AnalyticFamily,
+        placement: Placement,
+        samples: Samples,
+    ) -> Self {
+        Self { base_line, family, placement, samples }
+    }
+}
+
+impl Update for AnalyticCurve {
+    fn update_layers(&mut self, layers: Layers) {
+        if !layers.contains(Layers::CURVE) {
+            return;
+        }
+        let (range, curve) = curve(self.family, self.placement);
+        let path = self.samples.sample_parallel(range, || (), |(), t| curve(t));
+        self.base_line.rebuild_sampled_paths(path);
+    }
+}
+
+fn curve(
+    family: AnalyticFamily,
+    placement: Placement,
+) -> (RangeInclusive<f32>, impl Fn(f32) -> Point<f32> + Sync) {
+    (family.range(), move |t| placement.apply(family.point(t)))
+}
+
+impl DrawOn for AnalyticCurve {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.base_line.draw_on(pixmap);
+    }
+}
+
+impl From<AnalyticCurve> for ShapeCommonValues {
+    fn from(value: AnalyticCurve) -> Self {
+        Self {
+            open_base_line: Some(value.base_line),
+            analytic_family: Some(value.family),
+            placement: Some(value.placement),
+            samples: Some(value.samples),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&CanvasConfig> for AnalyticFamily {
+    fn from(value: &CanvasConfig) -> Self {
+        value.default_analytic_family
+    }
+}
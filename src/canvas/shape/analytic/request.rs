@@ -0,0 +1,181 @@
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::math;
+use crate::canvas::placement::Placement;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::analytic::{curve, AnalyticCurve};
+use crate::canvas::shape::request::declare::{
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSamples, GetSampling, GetSplineBoundary,
+    GetSplineDegree, GetSplineParametrisation, GetTangent, GetTcbParameters, GetWeight, MoveCurve,
+    MovePoint, ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily,
+    SetBezierAlgorithm, SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes,
+    SetJointContinuity, SetKnotVector, SetOverlayParameter, SetOverlayVisibility,
+    SetParametricConstant, SetParametricExpressions, SetPlacement, SetPolygonRadius,
+    SetPolygonVertices, SetSamples, SetSampling, SetSplineBoundary, SetSplineDegree,
+    SetSplineParametrisation, SetTangent, SetTcbParameters, SetTrochoidProperties, SplitCurve,
+};
+use crate::request::macros::delegate_requests;
+use crate::request::{
+    Error, RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
+
+delegate_requests! {
+    AnalyticCurve {
+        // ControlPoints requests
+        { mut AddControlPoint => ! },
+        { mut MovePoint => ! },
+        { mut DeletePoint => ! },
+        { mut RotateCurve => Placement },
+        { mut MoveCurve => Placement },
+        { mut ChangeWeight => ! },
+        { mut AddWeightedControlPoint => ! },
+        { GetControlPointsLength => ! },
+        { GetCurveCenter => Placement },
+        { SelectPoint => ! },
+        { GetPoint => ! },
+        { GetWeight => ! },
+
+        // Samples requests
+        { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
+        { GetSamples => Samples },
+        { GetSampling => Samples },
+
+        // InterpolationCurve requests
+        { mut SetInterpolationNodes => ! },
+        { GetInterpolationNodes => ! },
+
+        // TrochoidCurve requests
+        { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => Placement },
+        { mut SetPlacement => Placement },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetArcLength => OpenBaseLine },
+        { GetEquidistantPoints => OpenBaseLine },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => OpenBaseLine },
+        { GetCurveFeatures => OpenBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => ! },
+        { mut SetOverlayVisibility => ! },
+        { GetOverlayParameter => ! },
+        { mut SetOverlayParameter => ! },
+        { GetCombScale => ! },
+        { mut SetCombScale => ! },
+    }
+}
+
+impl RequestHandler<GetAnalyticFamily> for AnalyticCurve {
+    fn handle(&self, _event: GetAnalyticFamily) -> Response<GetAnalyticFamily> {
+        Ok(self.family)
+    }
+}
+
+impl RequestHandlerMut<SetAnalyticFamily> for AnalyticCurve {
+    fn handle_mut(&mut self, event: SetAnalyticFamily) -> ResponseMut<SetAnalyticFamily> {
+        event.0.check().map_err(Error::Other)?;
+        self.family = event.0;
+        Ok(())
+    }
+}
+
+impl RequestHandler<ProjectPoint> for AnalyticCurve {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.base_line.handle(ProjectPoint::new(event.point))?;
+        let (range, curve) = curve(self.family, self.placement);
+        Ok(math::projection::refine_projection(curve, range, event.point, sample.t))
+    }
+}
+
+impl RequestSubHandler<Placement> for AnalyticCurve {
+    fn sub_handler(&self) -> &Placement {
+        &self.placement
+    }
+}
+
+impl RequestSubHandlerMut<Placement> for AnalyticCurve {
+    fn sub_handler_mut(&mut self) -> &mut Placement {
+        &mut self.placement
+    }
+}
+
+impl RequestSubHandler<Samples> for AnalyticCurve {
+    fn sub_handler(&self) -> &Samples {
+        &self.samples
+    }
+}
+
+impl RequestSubHandlerMut<Samples> for AnalyticCurve {
+    fn sub_handler_mut(&mut self) -> &mut Samples {
+        &mut self.samples
+    }
+}
+
+impl RequestSubHandler<OpenBaseLine> for AnalyticCurve {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.base_line
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::iter;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::VisualBaseLine;
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::control_points::ControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::shape_changer::ShapeCommonValues;
+use crate::canvas::shape::{DrawOn, Update};
+use crate::config::CanvasConfig;
+
+pub mod request;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BSplineCurve {
+    points: ControlPoints<CurvePoint>,
+    control_points: VisualControlPoints,
+    polyline: VisualBaseLine<false>,
+    properties: BSplineCurveProperties,
+    samples: Samples,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BSplineCurveProperties {
+    pub degree: usize,
+    pub knots: KnotVector,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub enum KnotVector {
+    Uniform,
+    #[default]
+    OpenUniform,
+    Custom(Vec<f32>),
+}
+
+impl BSplineCurve {
+    #[must_use]
+    pub fn new(
+        points: ControlPoints<CurvePoint>,
+        control_points: VisualControlPoints,
+        polyline: VisualBaseLine<false>,
+        properties: BSplineCurveProperties,
+        samples: Samples,
+    ) -> Self {
+        Self { points, control_points, polyline, properties, samples }
+    }
+}
+
+impl Update for BSplineCurve {
+    fn update_layers(&mut self, layers: Layers) {
+        let length = self.points.length();
+        if layers.contains(Layers::CURVE) && length > 1 {
+            match curve(self.points.as_slice(), &self.properties) {
+                Some((range, curve)) => {
+                    let path = self.samples.sample(range, curve);
+                    self.polyline.rebuild_sampled_paths(path);
+                }
+                None => self.polyline.rebuild_paths(iter::empty::<CurvePoint>()),
+            }
+        }
+
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.polyline, layers);
+    }
+}
+
+/// Returns `None` if the knots do not fit the control points.
+fn curve<'a>(
+    points: &'a [CurvePoint],
+    properties: &BSplineCurveProperties,
+) -> Option<(RangeInclusive<f32>, impl Fn(f32) -> CurvePoint + 'a)> {
+    let (length, degree) = (points.len(), properties.effective_degree(points.len()));
+    let knots = properties.knots.values(length, degree)?;
+    Some((knots[degree]..=knots[length], move |t| math::de_boor(points, &knots, degree, t)))
+}
+
+impl DrawOn for BSplineCurve {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.polyline.draw_on(pixmap);
+        self.control_points.draw_on(pixmap);
+    }
+}
+
+impl From<BSplineCurve> for ShapeCommonValues {
+    fn from(value: BSplineCurve) -> Self {
+        Self {
+            points: Some(value.points),
+            control_points: Some(value.control_points),
+            open_base_line: Some(value.polyline),
+            b_spline_properties: Some(value.properties),
+            samples: Some(value.samples),
+            ..Default::default()
+        }
+    }
+}
+
+impl BSplineCurveProperties {
+    #[must_use]
+    pub fn new(degree: usize, knots: KnotVector) -> Self {
+        Self { degree, knots }
+    }
+
+    /// Lowered when there are too few control points for the configured degree.
+    #[must_use]
+    pub fn effective_degree(&self, length: usize) -> usize {
+        self.degree.min(length.saturating_sub(1))
+    }
+
+    pub fn check_knots(&self, knots: &KnotVector, length: usize) -> anyhow::Result<()> {
+        let KnotVector::Custom(values) = knots else { return Ok(()) };
+        let degree = self.effective_degree(length);
+        let expected = length + degree + 1;
+        let actual = values.len();
+        if actual != expected {
+            return Err(anyhow!("expected {expected} knots for degree {degree}, got {actual}"));
+        }
+        if knots.values(length, degree).is_none() {
+            return Err(anyhow!("knots do not span a non-empty domain"));
+        }
+        Ok(())
+    }
+
+    pub fn set_degree(&mut self, degree: usize, length: usize) -> anyhow::Result<()> {
+        if degree == 0 {
+            return Err(anyhow!("spline degree should be positive"));
+        }
+        Self { degree, knots: KnotVector::OpenUniform }.check_knots(&self.knots, length)?;
+        self.degree = degree;
+        Ok(())
+    }
+
+    pub fn check_length(&self, length: usize) -> anyhow::Result<()> {
+        self.check_knots(&self.knots, length)
+            .map_err(|error| anyhow!("set knots for {length} control points first: {error}"))
+    }
+}
+
+impl From<&CanvasConfig> for BSplineCurveProperties {
+    fn from(value: &CanvasConfig) -> Self {
+        Self { degree: value.default_spline_degree, knots: value.default_knot_vector.clone() }
+    }
+}
+
+impl Default for BSplineCurveProperties {
+    fn default() -> Self {
+        Self { degree: 3, knots: KnotVector::default() }
+    }
+}
+
+impl KnotVector {
+    /// Returns `None` if custom knots do not fit a spline with `length` control points.
+    #[must_use]
+    pub fn values(&self, length: usize, degree: usize) -> Option<Vec<f32>> {
+        let knots_length = length + degree + 1;
+        match self {
+            KnotVector::Uniform => Some((0..knots_length).map(|index| index as f32).collect()),
+            KnotVector::OpenUniform => {
+                let last = (length - degree) as f32;
+                let knots = (0..knots_length)
+                    .map(|index| {
+                        if index <= degree {
+                            0.0
+                        } else if index >= length {
+                            last
+                        } else {
+                            (index - degree) as f32
+                        }
+                    })
+                    .collect();
+                Some(knots)
+            }
+            KnotVector::Custom(knots) => {
+                let fits = knots.len() == knots_length
+                    && knots.windows(2).all(|pair| pair[0] <= pair[1])
+                    && knots[degree] < knots[length];
+                fits.then(|| knots.clone())
+            }
+        }
+    }
+}
+
+impl Display for KnotVector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            KnotVector::Uniform => write!(f, "uniform"),
+            KnotVector::OpenUniform => write!(f, "open-uniform"),
+            KnotVector::Custom(knots) => {
+                let mut knots = knots.iter();
+                if let Some(knot) = knots.next() {
+                    write!(f, "{knot}")?;
+                }
+                for knot in knots {
+                    write!(f, ",{knot}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for KnotVector {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "uniform" => Ok(KnotVector::Uniform),
+            "open-uniform" => Ok(KnotVector::OpenUniform),
+            _ => {
+                let knots = input.split(',').map(str::parse).collect::<Result<Vec<_>, _>>()?;
+                if knots.windows(2).all(|pair| pair[0] <= pair[1]) {
+                    Ok(KnotVector::Custom(knots))
+                } else {
+                    Err(anyhow!("knots should be non-decreasing"))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::math::point::Point;
+    use crate::canvas::shape::request::declare::{
+        AddControlPoint, DeletePoint, GetKnotVector, SetKnotVector,
+    };
+    use crate::request::{RequestHandler, RequestHandlerMut};
+
+    #[test]
+    fn custom_knots_are_kept_when_points_no_longer_fit() {
+        let points = [(0.0, 0.0), (30.0, 90.0), (70.0, -90.0), (100.0, 0.0)];
+        let points = points.into_iter().map(Point::from).collect();
+        let knots = KnotVector::Custom(vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0]);
+        let config = CanvasConfig::default();
+        let mut curve = BSplineCurve::new(
+            ControlPoints::new(points),
+            (&config).into(),
+            (&config).into(),
+            BSplineCurveProperties::new(3, knots.clone()),
+            Samples::new(50),
+        );
+
+        assert!(curve.handle_mut(AddControlPoint { point: Point::new(120.0, 40.0) }).is_err());
+        assert!(curve.handle_mut(DeletePoint { id: 0 }).is_err());
+        assert_eq!(curve.points.length(), 4);
+        assert_eq!(curve.handle(GetKnotVector).unwrap().to_string(), knots.to_string());
+
+        curve.handle_mut(SetKnotVector(KnotVector::OpenUniform)).unwrap();
+        curve.handle_mut(AddControlPoint { point: Point::new(120.0, 40.0) }).unwrap();
+        assert_eq!(curve.points.length(), 5);
+    }
+}
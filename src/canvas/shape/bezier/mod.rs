@@ -3,9 +3,13 @@ use tiny_skia::PixmapMut;
 use crate::canvas::base_line::VisualBaseLine;
 use crate::canvas::control_points::point::CurvePoint;
 use crate::canvas::control_points::ControlPoints;
-use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::control_points_curve::{Overlay, VisualControlPoints};
 use crate::canvas::math;
+use crate::canvas::math::point::Point;
+use crate::canvas::math::projection::Projection;
+use crate::canvas::math::vector::Vector;
 use crate::canvas::samples::Samples;
+use crate::canvas::shape::layers::Layers;
 use crate::canvas::shape::shape_changer::ShapeCommonValues;
 use crate::canvas::shape::{DrawOn, Update};
 use crate::config::CanvasConfig;
@@ -37,25 +41,115 @@ impl BezierCurve {
     ) -> Self {
         Self { points, control_points, polyline, properties, samples }
     }
+
+    /// Both parts keep the degree of the curve.
+    #[must_use]
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let (first, second) = math::de_casteljau_split(self.points.as_slice(), t);
+        let with_points = |points| {
+            let mut part = Self { points: ControlPoints::new(points), ..self.clone() };
+            part.update();
+            part
+        };
+        (with_points(first), with_points(second))
+    }
+
+    pub fn elevate_degree(&mut self, times: usize) {
+        let mut points = self.points.as_slice().to_vec();
+        for _ in 0..times {
+            points = math::bezier_degree::elevate(&points);
+        }
+        self.points = ControlPoints::new(points);
+    }
+
+    /// Returns the largest distance between the original and the reduced curve.
+    pub fn reduce_degree(&mut self) -> Option<f32> {
+        let (points, error) = math::bezier_degree::reduce(self.points.as_slice())?;
+        self.points = ControlPoints::new(points);
+        Some(error)
+    }
+
+    /// Origin of the hodograph is the first control point of the curve.
+    #[must_use]
+    pub fn hodograph(&self) -> Self {
+        let mut hodograph =
+            Self { points: ControlPoints::new(self.hodograph_points()), ..self.clone() };
+        hodograph.update();
+        hodograph
+    }
+
+    fn hodograph_points(&self) -> Vec<CurvePoint> {
+        let points = self.points.as_slice();
+        let origin = points[0] - Point::zero();
+        math::hodograph(points).into_iter().map(|point| point + origin).collect()
+    }
+
+    /// Assumes that the curve has at least two control points.
+    #[must_use]
+    pub fn project(&self, point: CurvePoint, start: f32) -> Projection {
+        let points = self.points.as_slice();
+        let first = math::hodograph(points);
+        let second = math::hodograph(&first);
+        let second_derivative = |t| match second.as_slice() {
+            [] => Vector::new(0.0, 0.0),
+            second => math::de_casteljau(second, t) - Point::zero(),
+        };
+        math::projection::newton_projection(
+            |t| math::de_casteljau(points, t),
+            |t| math::de_casteljau(&first, t) - Point::zero(),
+            second_derivative,
+            0.0..=1.0,
+            point,
+            start,
+        )
+    }
+
+    #[must_use]
+    pub fn nearest_parameter(&self, point: CurvePoint) -> f32 {
+        let points = self.points.as_slice();
+        math::nearest_parameter(|t| math::de_casteljau(points, t), point, self.samples.samples())
+    }
 }
 
 impl Update for BezierCurve {
-    fn update(&mut self) {
-        if self.points.length() > 1 {
+    fn update_layers(&mut self, layers: Layers) {
+        if layers.contains(Layers::CURVE) && self.points.length() > 1 {
             let points = self.points.as_slice();
 
-            let path = self.samples.equally_spaced(0.0..=1.0);
+            let samples = self.samples;
             match self.properties.algorithm {
                 BezierCurveAlgorithm::DeCasteljau => {
-                    self.polyline.rebuild_paths(path.map(|t| math::de_casteljau(points, t)));
+                    let path = samples.sample_parallel(
+                        0.0..=1.0,
+                        || Vec::with_capacity(points.len()),
+                        |buffer, t| math::de_casteljau_in(points, t, buffer),
+                    );
+                    self.polyline.rebuild_sampled_paths(path);
                 }
                 BezierCurveAlgorithm::ChudyWozny => {
-                    self.polyline.rebuild_paths(path.map(|t| math::chudy_wozny(points, t)));
+                    let path = samples.sample_parallel(
+                        0.0..=1.0,
+                        || (),
+                        |(), t| math::chudy_wozny(points, t),
+                    );
+                    self.polyline.rebuild_sampled_paths(path);
                 }
             };
         }
 
-        self.control_points.rebuild_paths(&self.points);
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.polyline, layers);
+        if layers.intersects(Layers::CURVE | Layers::OVERLAYS)
+            && self.points.length() > 1
+            && self.control_points.is_overlay_visible(Overlay::Hodograph)
+        {
+            let hodograph = self.hodograph_points();
+            let mut buffer = Vec::with_capacity(hodograph.len());
+            let path = self
+                .samples
+                .sample(0.0..=1.0, |t| math::de_casteljau_in(&hodograph, t, &mut buffer));
+            self.control_points.rebuild_hodograph(path.map(|(_, point)| point));
+        }
     }
 }
 
@@ -92,9 +186,69 @@ impl From<&CanvasConfig> for BezierCurveProperties {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default, serde::Serialize, serde::Deserialize, clap::ValueEnum)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+    clap::ValueEnum,
+    parse_display::Display,
+)]
+#[display(style = "kebab-case")]
 pub enum BezierCurveAlgorithm {
     #[default]
     DeCasteljau,
     ChudyWozny,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::shape::request::declare::ProjectPoint;
+    use crate::request::RequestHandler;
+
+    #[test]
+    fn split_parts_are_resampled() {
+        let points = [(0.0, 0.0), (30.0, 90.0), (70.0, -90.0), (100.0, 0.0)];
+        let points = points.into_iter().map(Point::from).collect::<Vec<_>>();
+        let config = CanvasConfig::default();
+        let mut curve = BezierCurve::new(
+            ControlPoints::new(points.clone()),
+            (&config).into(),
+            (&config).into(),
+            BezierCurveProperties::default(),
+            Samples::new(50),
+        );
+        curve.update();
+
+        let (first, second) = curve.split(0.25);
+        let split = math::de_casteljau(&points, 0.25);
+        for (part, start, end) in [(first, points[0], split), (second, split, points[3])] {
+            let path = part.polyline.path_points();
+            assert_eq!(path.len(), 50);
+            assert!(path[0].distance_squared(start) < 1e-6);
+            assert!(path[path.len() - 1].distance_squared(end) < 1e-6);
+        }
+    }
+
+    #[test]
+    fn straight_curves_are_projected() {
+        let points = vec![Point::new(0.0, 0.0), Point::new(100.0, 0.0)];
+        let config = CanvasConfig::default();
+        let mut curve = BezierCurve::new(
+            ControlPoints::new(points),
+            (&config).into(),
+            (&config).into(),
+            BezierCurveProperties::default(),
+            Samples::new(50),
+        );
+        curve.update();
+
+        let projection = curve.handle(ProjectPoint::new(Point::new(33.0, 20.0))).unwrap();
+        assert!((projection.t - 0.33).abs() < 1e-4);
+        assert!(projection.point.distance_squared(Point::new(33.0, 0.0)) < 1e-4);
+        assert!((projection.distance - 20.0).abs() < 1e-4);
+    }
+}
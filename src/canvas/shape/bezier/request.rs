@@ -1,14 +1,36 @@
+use anyhow::anyhow;
+
+use crate::canvas::base_line::OpenBaseLine;
 use crate::canvas::control_points::point::CurveControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::math::bezier_degree::MAX_DEGREE;
+use crate::canvas::math::point::Point;
 use crate::canvas::samples::Samples;
 use crate::canvas::shape::bezier::BezierCurve;
 use crate::canvas::shape::request::declare::{
-    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, GetControlPointsLength,
-    GetCurveCenter, GetInterpolationNodes, GetPoint, GetWeight, MoveCurve, MovePoint, RotateCurve,
-    SelectPoint, SetInterpolationNodes, SetTrochoidProperties,
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSampling, GetSplineBoundary, GetSplineDegree,
+    GetSplineParametrisation, GetTangent, GetTcbParameters, GetWeight, MoveCurve, MovePoint,
+    ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily, SetBezierAlgorithm,
+    SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes, SetJointContinuity,
+    SetKnotVector, SetOverlayParameter, SetOverlayVisibility, SetParametricConstant,
+    SetParametricExpressions, SetPlacement, SetPolygonRadius, SetPolygonVertices, SetSampling,
+    SetSplineBoundary, SetSplineDegree, SetSplineParametrisation, SetTangent, SetTcbParameters,
+    SetTrochoidProperties, SplitCurve,
 };
 use crate::canvas::shape::request::declare::{GetSamples, SetSamples};
+use crate::canvas::shape::Shape;
 use crate::request::macros::delegate_requests;
-use crate::request::{RequestSubHandler, RequestSubHandlerMut};
+use crate::request::{
+    Error, RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
 
 delegate_requests! {
     BezierCurve {
@@ -28,7 +50,9 @@ delegate_requests! {
 
         // Samples requests
         { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
         { GetSamples => Samples },
+        { GetSampling => Samples },
 
         // InterpolationCurve requests
         { mut SetInterpolationNodes => ! },
@@ -36,6 +60,67 @@ delegate_requests! {
 
         // TrochoidCurve requests
         { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => ! },
+        { mut SetPlacement => ! },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // Geometry requests
+        { GetSampledPath => OpenBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => VisualControlPoints },
+        { mut SetOverlayVisibility => VisualControlPoints },
+        { GetOverlayParameter => VisualControlPoints },
+        { mut SetOverlayParameter => VisualControlPoints },
+        { GetCombScale => VisualControlPoints },
+        { mut SetCombScale => VisualControlPoints },
     }
 }
 
@@ -62,3 +147,128 @@ impl RequestSubHandlerMut<Samples> for BezierCurve {
         &mut self.samples
     }
 }
+
+impl RequestHandler<SplitCurve> for BezierCurve {
+    fn handle(&self, event: SplitCurve) -> Response<SplitCurve> {
+        if self.points.length() < 2 {
+            return Err(Error::Other(anyhow!("curve needs at least two control points to split")));
+        }
+        let (first, second) = self.split(event.t.clamp(0.0, 1.0));
+        Ok((Shape::Bezier(Box::new(first)), Shape::Bezier(Box::new(second))))
+    }
+}
+
+impl RequestHandler<GetHodograph> for BezierCurve {
+    fn handle(&self, _event: GetHodograph) -> Response<GetHodograph> {
+        if self.points.length() < 2 {
+            return Err(Error::Other(anyhow!("curve needs at least two control points")));
+        }
+        Ok(Shape::Bezier(Box::new(self.hodograph())))
+    }
+}
+
+impl RequestHandler<GetNearestParameter> for BezierCurve {
+    fn handle(&self, event: GetNearestParameter) -> Response<GetNearestParameter> {
+        if self.points.length() < 2 {
+            return Err(Error::Other(anyhow!("curve needs at least two control points")));
+        }
+        Ok(self.nearest_parameter(event.point))
+    }
+}
+
+impl RequestHandlerMut<ElevateDegree> for BezierCurve {
+    fn handle_mut(&mut self, event: ElevateDegree) -> ResponseMut<ElevateDegree> {
+        if self.points.length() < 2 {
+            return Err(Error::Other(anyhow!("curve needs at least two control points")));
+        }
+        if event.0 > MAX_DEGREE.saturating_sub(self.points.length() - 1) {
+            return Err(Error::Other(anyhow!("degree of the curve cannot exceed {MAX_DEGREE}")));
+        }
+        self.elevate_degree(event.0);
+        Ok(())
+    }
+}
+
+impl RequestHandlerMut<ReduceDegree> for BezierCurve {
+    fn handle_mut(&mut self, _event: ReduceDegree) -> ResponseMut<ReduceDegree> {
+        self.reduce_degree()
+            .ok_or_else(|| Error::Other(anyhow!("degree of the curve cannot be reduced")))
+    }
+}
+
+impl RequestHandler<GetBezierAlgorithm> for BezierCurve {
+    fn handle(&self, _event: GetBezierAlgorithm) -> Response<GetBezierAlgorithm> {
+        Ok(self.properties.algorithm)
+    }
+}
+
+impl RequestHandlerMut<SetBezierAlgorithm> for BezierCurve {
+    fn handle_mut(&mut self, event: SetBezierAlgorithm) -> ResponseMut<SetBezierAlgorithm> {
+        self.properties.algorithm = event.0;
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetArcLength> for BezierCurve {
+    fn handle(&self, _event: GetArcLength) -> Response<GetArcLength> {
+        Ok(math::bezier_length(self.points.as_slice()))
+    }
+}
+
+impl RequestHandler<GetEquidistantPoints> for BezierCurve {
+    fn handle(&self, event: GetEquidistantPoints) -> Response<GetEquidistantPoints> {
+        let points = self.points.as_slice();
+        if points.len() < 2 {
+            return Ok(math::arc_length::resample_polyline(points, event.count));
+        }
+        let hodograph = math::hodograph(points);
+        let derivative = |t| math::de_casteljau(&hodograph, t) - Point::zero();
+        let parameters = math::arc_length::equal_length_parameters(
+            derivative,
+            event.count,
+            math::arc_length::TOLERANCE,
+        );
+        Ok(parameters.into_iter().map(|t| math::de_casteljau(points, t)).collect())
+    }
+}
+
+impl RequestHandler<GetBezierSegments> for BezierCurve {
+    fn handle(&self, _event: GetBezierSegments) -> Response<GetBezierSegments> {
+        Ok(vec![self.points.as_slice().to_vec()])
+    }
+}
+
+impl RequestSubHandler<OpenBaseLine> for BezierCurve {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.polyline
+    }
+}
+
+impl RequestHandler<GetCurveFeatures> for BezierCurve {
+    fn handle(&self, _event: GetCurveFeatures) -> Response<GetCurveFeatures> {
+        let path = self.polyline.handle(GetSampledPath)?;
+        Ok(math::curve_features::bezier_features(self.points.as_slice(), &path))
+    }
+}
+
+impl RequestHandler<ProjectPoint> for BezierCurve {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.polyline.handle(ProjectPoint::new(event.point))?;
+        if self.points.length() < 2 {
+            return Ok(sample);
+        }
+        Ok(self.project(event.point, sample.t))
+    }
+}
+
+impl RequestSubHandler<VisualControlPoints> for BezierCurve {
+    fn sub_handler(&self) -> &VisualControlPoints {
+        &self.control_points
+    }
+}
+
+impl RequestSubHandlerMut<VisualControlPoints> for BezierCurve {
+    fn sub_handler_mut(&mut self) -> &mut VisualControlPoints {
+        &mut self.control_points
+    }
+}
@@ -0,0 +1,377 @@
+use std::ops::RangeInclusive;
+
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::VisualBaseLine;
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::control_points::ControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::math::vector::Vector;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::shape_changer::ShapeCommonValues;
+use crate::canvas::shape::{DrawOn, Update};
+use crate::config::CanvasConfig;
+
+pub mod request;
+
+/// Segment `k` is built from points `3k..=3k + 3` and joint `k` is the anchor at `3k`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BezierSpline {
+    points: ControlPoints<CurvePoint>,
+    control_points: VisualControlPoints,
+    polyline: VisualBaseLine<false>,
+    properties: BezierSplineProperties,
+    samples: Samples,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct BezierSplineProperties {
+    default_continuity: JointContinuity,
+    joints: Vec<JointContinuity>,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    clap::ValueEnum,
+    parse_display::Display,
+)]
+#[display(style = "lowercase")]
+pub enum JointContinuity {
+    C0,
+    G1,
+    #[default]
+    C1,
+    C2,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Constraint {
+    Near(usize),
+    Far(usize),
+}
+
+const SEGMENT_LENGTH: usize = 3;
+
+impl BezierSpline {
+    #[must_use]
+    pub fn new(
+        points: ControlPoints<CurvePoint>,
+        control_points: VisualControlPoints,
+        polyline: VisualBaseLine<false>,
+        properties: BezierSplineProperties,
+        samples: Samples,
+    ) -> Self {
+        Self { points, control_points, polyline, properties, samples }
+    }
+
+    #[must_use]
+    pub fn joints_length(&self) -> usize {
+        self.points.length().saturating_sub(2) / SEGMENT_LENGTH
+    }
+
+    /// The last segment may be of lower degree.
+    pub fn segments(&self) -> impl Iterator<Item = &[CurvePoint]> {
+        let points = self.points.as_slice();
+        (0..points.len().saturating_sub(1))
+            .step_by(SEGMENT_LENGTH)
+            .map(|start| &points[start..=(start + SEGMENT_LENGTH).min(points.len() - 1)])
+    }
+
+    /// Anchors take their handles with them, handles drag the opposite handles around.
+    pub fn move_point(&mut self, id: usize, shift: Vector<f32>) -> Option<()> {
+        self.points.shift(id, shift)?;
+        if id % SEGMENT_LENGTH == 0 {
+            let handles = [id.checked_sub(1), Some(id + 1)];
+            for handle in handles.into_iter().flatten() {
+                if self.points.shift(handle, shift).is_some() {
+                    self.restore_continuity(Constraint::Far(handle));
+                }
+            }
+        } else {
+            self.restore_continuity(Constraint::Near(id));
+            self.restore_continuity(Constraint::Far(id));
+        }
+        Some(())
+    }
+
+    pub fn restore_joint(&mut self, joint: usize) {
+        if let Some(handle) = (joint * SEGMENT_LENGTH).checked_sub(1) {
+            self.restore_continuity(Constraint::Near(handle));
+        }
+    }
+
+    fn restore_continuity(&mut self, constraint: Constraint) {
+        let mut pending = Some(constraint);
+        while let Some(constraint) = pending {
+            pending = match constraint {
+                Constraint::Near(handle) => self.restore_near(handle),
+                Constraint::Far(handle) => self.restore_far(handle),
+            };
+        }
+    }
+
+    fn restore_near(&mut self, handle: usize) -> Option<Constraint> {
+        let anchor = near_anchor(handle)?;
+        let continuity = self.continuity(anchor)?;
+        let opposite = opposite(handle, anchor);
+        let anchor_point = self.point(anchor)?;
+        let handle_point = self.point(handle)?;
+        let opposite_point = self.point(opposite)?;
+        match continuity {
+            JointContinuity::C0 => None,
+            JointContinuity::G1 => {
+                let direction = anchor_point - handle_point;
+                let direction_length = vector_length(direction);
+                if direction_length <= f32::EPSILON {
+                    return None;
+                }
+                let length = vector_length(opposite_point - anchor_point);
+                let point = anchor_point + direction * (length / direction_length);
+                self.set_point(opposite, point);
+                Some(Constraint::Far(opposite))
+            }
+            JointContinuity::C1 => {
+                self.set_point(opposite, mirror(handle_point, anchor_point));
+                Some(Constraint::Far(opposite))
+            }
+            JointContinuity::C2 => {
+                let opposite_point = mirror(handle_point, anchor_point);
+                self.set_point(opposite, opposite_point);
+                let handle_far = self.point(far_handle(handle, anchor));
+                let opposite_far = far_handle(opposite, anchor);
+                match (handle_far, self.point(opposite_far)) {
+                    (Some(handle_far), Some(_)) => {
+                        let point =
+                            second_derivative_match(handle_far, handle_point, opposite_point);
+                        self.set_point(opposite_far, point);
+                        Some(Constraint::Near(opposite_far))
+                    }
+                    _ => Some(Constraint::Far(opposite)),
+                }
+            }
+        }
+    }
+
+    fn restore_far(&mut self, handle: usize) -> Option<Constraint> {
+        let anchor = far_anchor(handle)?;
+        if self.continuity(anchor)? != JointContinuity::C2 {
+            return None;
+        }
+        let near = if handle > anchor { anchor + 1 } else { anchor - 1 };
+        let opposite = opposite(near, anchor);
+        let opposite_far = far_handle(opposite, anchor);
+        let point =
+            second_derivative_match(self.point(handle)?, self.point(near)?, self.point(opposite)?);
+        self.point(opposite_far)?;
+        self.set_point(opposite_far, point);
+        Some(Constraint::Near(opposite_far))
+    }
+
+    fn continuity(&self, anchor: usize) -> Option<JointContinuity> {
+        let is_joint = anchor > 0 && anchor + 1 < self.points.length();
+        is_joint.then(|| self.properties.continuity(anchor / SEGMENT_LENGTH))
+    }
+
+    fn point(&self, index: usize) -> Option<CurvePoint> {
+        self.points.get(index).copied()
+    }
+
+    fn set_point(&mut self, index: usize, point: CurvePoint) {
+        if let Some(current) = self.points.get_mut(index) {
+            *current = point;
+        }
+    }
+}
+
+fn near_anchor(handle: usize) -> Option<usize> {
+    match handle % SEGMENT_LENGTH {
+        1 => Some(handle - 1),
+        2 => Some(handle + 1),
+        _ => None,
+    }
+}
+
+fn far_anchor(handle: usize) -> Option<usize> {
+    match handle % SEGMENT_LENGTH {
+        1 => Some(handle + 2),
+        2 => handle.checked_sub(2),
+        _ => None,
+    }
+}
+
+fn opposite(handle: usize, anchor: usize) -> usize {
+    2 * anchor - handle
+}
+
+fn far_handle(handle: usize, anchor: usize) -> usize {
+    if handle > anchor {
+        handle + 1
+    } else {
+        handle - 1
+    }
+}
+
+fn mirror(point: CurvePoint, center: CurvePoint) -> CurvePoint {
+    center + (center - point)
+}
+
+fn vector_length(vector: Vector<f32>) -> f32 {
+    vector.horizontal().hypot(vector.vertical())
+}
+
+/// Solves `far - 2 * near = opposite_far - 2 * opposite` for `opposite_far`.
+fn second_derivative_match(far: CurvePoint, near: CurvePoint, opposite: CurvePoint) -> CurvePoint {
+    far + (opposite - near) * 2.0
+}
+
+/// Integer part of the parameter is the index of the segment.
+fn range(points: &[CurvePoint]) -> RangeInclusive<f32> {
+    0.0..=(points.len() - 1).div_ceil(SEGMENT_LENGTH) as f32
+}
+
+fn segment_at(points: &[CurvePoint], t: f32, buffer: &mut Vec<CurvePoint>) -> CurvePoint {
+    let segments = (points.len() - 1).div_ceil(SEGMENT_LENGTH);
+    let segment = (t as usize).min(segments - 1);
+    let start = segment * SEGMENT_LENGTH;
+    let end = (start + SEGMENT_LENGTH).min(points.len() - 1);
+    math::de_casteljau_in(&points[start..=end], t - segment as f32, buffer)
+}
+
+impl Update for BezierSpline {
+    fn update_layers(&mut self, layers: Layers) {
+        if layers.contains(Layers::CURVE) && self.points.length() > 1 {
+            let points = self.points.as_slice();
+            let init = || Vec::with_capacity(SEGMENT_LENGTH + 1);
+            let path = self
+                .samples
+                .sample_parallel(range(points), init, |buffer, t| segment_at(points, t, buffer));
+            self.polyline.rebuild_sampled_paths(path);
+        }
+
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.polyline, layers);
+    }
+}
+
+impl DrawOn for BezierSpline {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.polyline.draw_on(pixmap);
+        self.control_points.draw_on(pixmap);
+    }
+}
+
+impl From<BezierSpline> for ShapeCommonValues {
+    fn from(value: BezierSpline) -> Self {
+        Self {
+            points: Some(value.points),
+            control_points: Some(value.control_points),
+            open_base_line: Some(value.polyline),
+            bezier_spline_properties: Some(value.properties),
+            samples: Some(value.samples),
+            ..Default::default()
+        }
+    }
+}
+
+impl BezierSplineProperties {
+    #[must_use]
+    pub fn new(default_continuity: JointContinuity) -> Self {
+        Self { default_continuity, joints: Vec::new() }
+    }
+
+    #[must_use]
+    pub fn continuity(&self, joint: usize) -> JointContinuity {
+        joint
+            .checked_sub(1)
+            .and_then(|index| self.joints.get(index))
+            .copied()
+            .unwrap_or(self.default_continuity)
+    }
+
+    pub fn set_continuity(&mut self, joint: usize, continuity: JointContinuity) {
+        let Some(index) = joint.checked_sub(1) else { return };
+        if self.joints.len() <= index {
+            self.joints.resize(index + 1, self.default_continuity);
+        }
+        self.joints[index] = continuity;
+    }
+
+    pub fn set_all_continuities(&mut self, continuity: JointContinuity) {
+        self.default_continuity = continuity;
+        self.joints.clear();
+    }
+}
+
+impl From<&CanvasConfig> for BezierSplineProperties {
+    fn from(value: &CanvasConfig) -> Self {
+        Self::new(value.default_joint_continuity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canvas::control_points::ControlPoints;
+    use crate::canvas::math::point::Point;
+    use crate::canvas::math::vector::Vector;
+    use crate::canvas::samples::Samples;
+    use crate::canvas::shape::bezier_spline::{
+        BezierSpline, BezierSplineProperties, JointContinuity,
+    };
+    use crate::config::CanvasConfig;
+
+    fn spline(continuity: JointContinuity) -> BezierSpline {
+        let points = [(0.0, 0.0), (1.0, 1.0), (2.0, 1.0), (3.0, 0.0), (4.0, -1.0), (5.0, -1.0)];
+        let points = points.into_iter().map(Point::from).collect();
+        let config = CanvasConfig::default();
+        BezierSpline::new(
+            ControlPoints::new(points),
+            (&config).into(),
+            (&config).into(),
+            BezierSplineProperties::new(continuity),
+            Samples::new(2),
+        )
+    }
+
+    fn second_derivatives(spline: &BezierSpline) -> (Vector<f32>, Vector<f32>) {
+        let p = spline.points.as_slice();
+        let left = (p[1] - p[2]) - (p[2] - p[3]);
+        let right = (p[3] - p[4]) - (p[4] - p[5]);
+        (left, right)
+    }
+
+    #[test]
+    fn c1_mirrors_opposite_handle() {
+        let mut spline = spline(JointContinuity::C1);
+        spline.move_point(2, Vector::new(0.0, 1.0));
+        let opposite = spline.points.as_slice()[4];
+        assert_eq!(<(f32, f32)>::from(opposite), (4.0, -2.0));
+    }
+
+    #[test]
+    fn g1_keeps_opposite_handle_length() {
+        let mut spline = spline(JointContinuity::G1);
+        spline.move_point(2, Vector::new(-1.0, -1.0));
+        let opposite = spline.points.as_slice()[4];
+        let (horizontal, vertical) = opposite.into();
+        assert!((horizontal - (3.0 + 2.0_f32.sqrt())).abs() < 1e-5);
+        assert!(vertical.abs() < 1e-5);
+    }
+
+    #[test]
+    fn c2_matches_second_derivatives() {
+        let mut spline = spline(JointContinuity::C2);
+        spline.move_point(1, Vector::new(0.5, 2.0));
+        let (left, right) = second_derivatives(&spline);
+        assert!((left - right).horizontal().abs() < 1e-5);
+        assert!((left - right).vertical().abs() < 1e-5);
+    }
+}
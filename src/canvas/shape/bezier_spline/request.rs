@@ -0,0 +1,231 @@
+use anyhow::anyhow;
+
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::control_points::point::CurveControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::bezier_spline::{range, segment_at, BezierSpline};
+use crate::canvas::shape::request::declare::{
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSampling, GetSplineBoundary, GetSplineDegree,
+    GetSplineParametrisation, GetTangent, GetTcbParameters, GetWeight, MoveCurve, MovePoint,
+    ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily, SetBezierAlgorithm,
+    SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes, SetJointContinuity,
+    SetKnotVector, SetOverlayParameter, SetOverlayVisibility, SetParametricConstant,
+    SetParametricExpressions, SetPlacement, SetPolygonRadius, SetPolygonVertices, SetSampling,
+    SetSplineBoundary, SetSplineDegree, SetSplineParametrisation, SetTangent, SetTcbParameters,
+    SetTrochoidProperties, SplitCurve,
+};
+use crate::canvas::shape::request::declare::{GetSamples, SetSamples};
+use crate::request::macros::delegate_requests;
+use crate::request::{
+    Error, RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
+
+delegate_requests! {
+    BezierSpline {
+        // ControlPoints requests
+        { mut AddControlPoint => CurveControlPoints },
+        { mut DeletePoint => CurveControlPoints },
+        { mut RotateCurve => CurveControlPoints },
+        { mut MoveCurve => CurveControlPoints },
+        { mut ChangeWeight => ! },
+        { mut AddWeightedControlPoint => ! },
+        { GetControlPointsLength => CurveControlPoints },
+        { GetCurveCenter => CurveControlPoints },
+        { SelectPoint => CurveControlPoints },
+        { GetPoint => CurveControlPoints },
+        { GetWeight => ! },
+
+        // Samples requests
+        { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
+        { GetSamples => Samples },
+        { GetSampling => Samples },
+
+        // InterpolationCurve requests
+        { mut SetInterpolationNodes => ! },
+        { GetInterpolationNodes => ! },
+
+        // TrochoidCurve requests
+        { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => ! },
+        { mut SetPlacement => ! },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetEquidistantPoints => OpenBaseLine },
+        { GetSampledPath => OpenBaseLine },
+        { GetCurveFeatures => OpenBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => VisualControlPoints },
+        { mut SetOverlayVisibility => VisualControlPoints },
+        { GetOverlayParameter => VisualControlPoints },
+        { mut SetOverlayParameter => VisualControlPoints },
+        { GetCombScale => VisualControlPoints },
+        { mut SetCombScale => VisualControlPoints },
+    }
+}
+
+impl RequestHandlerMut<MovePoint> for BezierSpline {
+    fn handle_mut(&mut self, event: MovePoint) -> ResponseMut<MovePoint> {
+        self.move_point(event.id, event.shift).ok_or_else(|| Error::NoSuchPoint(event.id))
+    }
+}
+
+impl RequestHandler<GetJointContinuity> for BezierSpline {
+    fn handle(&self, event: GetJointContinuity) -> Response<GetJointContinuity> {
+        check_joint(self, event.joint)?;
+        Ok(self.properties.continuity(event.joint))
+    }
+}
+
+impl RequestHandlerMut<SetJointContinuity> for BezierSpline {
+    fn handle_mut(&mut self, event: SetJointContinuity) -> ResponseMut<SetJointContinuity> {
+        if let Some(joint) = event.joint {
+            check_joint(self, joint)?;
+            self.properties.set_continuity(joint, event.continuity);
+            self.restore_joint(joint);
+        } else {
+            self.properties.set_all_continuities(event.continuity);
+            for joint in 1..=self.joints_length() {
+                self.restore_joint(joint);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_joint(spline: &BezierSpline, joint: usize) -> Result<(), Error> {
+    if joint == 0 || joint > spline.joints_length() {
+        return Err(Error::Other(anyhow!("no such joint: {joint}")));
+    }
+    Ok(())
+}
+
+impl RequestHandler<ProjectPoint> for BezierSpline {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.polyline.handle(ProjectPoint::new(event.point))?;
+        if self.points.length() < 2 {
+            return Ok(sample);
+        }
+        let points = self.points.as_slice();
+        let curve = |t| segment_at(points, t, &mut Vec::new());
+        Ok(math::projection::refine_projection(curve, range(points), event.point, sample.t))
+    }
+}
+
+impl RequestSubHandler<CurveControlPoints> for BezierSpline {
+    fn sub_handler(&self) -> &CurveControlPoints {
+        &self.points
+    }
+}
+
+impl RequestSubHandlerMut<CurveControlPoints> for BezierSpline {
+    fn sub_handler_mut(&mut self) -> &mut CurveControlPoints {
+        &mut self.points
+    }
+}
+
+impl RequestSubHandler<Samples> for BezierSpline {
+    fn sub_handler(&self) -> &Samples {
+        &self.samples
+    }
+}
+
+impl RequestSubHandlerMut<Samples> for BezierSpline {
+    fn sub_handler_mut(&mut self) -> &mut Samples {
+        &mut self.samples
+    }
+}
+
+impl RequestSubHandler<OpenBaseLine> for BezierSpline {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.polyline
+    }
+}
+
+impl RequestHandler<GetArcLength> for BezierSpline {
+    fn handle(&self, _event: GetArcLength) -> Response<GetArcLength> {
+        let length = self.segments().map(math::bezier_length).sum();
+        Ok(length)
+    }
+}
+
+impl RequestHandler<GetBezierSegments> for BezierSpline {
+    fn handle(&self, _event: GetBezierSegments) -> Response<GetBezierSegments> {
+        Ok(self.segments().map(<[_]>::to_vec).collect())
+    }
+}
+
+impl RequestSubHandler<VisualControlPoints> for BezierSpline {
+    fn sub_handler(&self) -> &VisualControlPoints {
+        &self.control_points
+    }
+}
+
+impl RequestSubHandlerMut<VisualControlPoints> for BezierSpline {
+    fn sub_handler_mut(&mut self) -> &mut VisualControlPoints {
+        &mut self.control_points
+    }
+}
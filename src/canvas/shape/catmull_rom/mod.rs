@@ -0,0 +1,142 @@
+use std::ops::RangeInclusive;
+
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::VisualBaseLine;
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::control_points::ControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::cubic_spline::SplineParametrisation;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::shape_changer::ShapeCommonValues;
+use crate::canvas::shape::{DrawOn, Update};
+use crate::config::CanvasConfig;
+
+pub mod request;
+
+/// Uniform, centripetal and chordal variants have alpha 0, 0.5 and 1.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CatmullRomCurve {
+    points: ControlPoints<CurvePoint>,
+    control_points: VisualControlPoints,
+    polyline: VisualBaseLine<false>,
+    properties: CatmullRomCurveProperties,
+    samples: Samples,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct CatmullRomCurveProperties {
+    pub parametrisation: SplineParametrisation,
+}
+
+impl CatmullRomCurve {
+    #[must_use]
+    pub fn new(
+        points: ControlPoints<CurvePoint>,
+        control_points: VisualControlPoints,
+        polyline: VisualBaseLine<false>,
+        properties: CatmullRomCurveProperties,
+        samples: Samples,
+    ) -> Self {
+        Self { points, control_points, polyline, properties, samples }
+    }
+}
+
+impl Update for CatmullRomCurve {
+    fn update_layers(&mut self, layers: Layers) {
+        if layers.contains(Layers::CURVE) && self.points.length() > 1 {
+            let (range, curve) = curve(&self.points, &self.properties);
+            let path = self.samples.sample(range, curve);
+            self.polyline.rebuild_sampled_paths(path);
+        }
+
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.polyline, layers);
+    }
+}
+
+/// Integer part of the parameter is the index of the segment.
+fn curve(
+    points: &ControlPoints<CurvePoint>,
+    properties: &CatmullRomCurveProperties,
+) -> (RangeInclusive<f32>, impl Fn(f32) -> CurvePoint) {
+    let segments = points.length() - 1;
+    let points = with_phantom_ends(points.points_iterator().collect());
+    let knots = properties.parametrisation.knots(&points);
+    let curve = move |t: f32| {
+        let segment = (t as usize).min(segments - 1);
+        let (start, end) = (knots[segment + 1], knots[segment + 2]);
+        let t = start + (t - segment as f32) * (end - start);
+        let range = segment..segment + 4;
+        math::catmull_rom(&points[range.clone()], &knots[range], t)
+    };
+    (0.0..=segments as f32, curve)
+}
+
+impl DrawOn for CatmullRomCurve {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.polyline.draw_on(pixmap);
+        self.control_points.draw_on(pixmap);
+    }
+}
+
+impl From<CatmullRomCurve> for ShapeCommonValues {
+    fn from(value: CatmullRomCurve) -> Self {
+        Self {
+            points: Some(value.points),
+            control_points: Some(value.control_points),
+            open_base_line: Some(value.polyline),
+            catmull_rom_properties: Some(value.properties),
+            samples: Some(value.samples),
+            ..Default::default()
+        }
+    }
+}
+
+impl CatmullRomCurveProperties {
+    #[must_use]
+    pub fn new(parametrisation: SplineParametrisation) -> Self {
+        Self { parametrisation }
+    }
+}
+
+impl From<&CanvasConfig> for CatmullRomCurveProperties {
+    fn from(value: &CanvasConfig) -> Self {
+        Self { parametrisation: value.default_spline_parametrisation }
+    }
+}
+
+/// Reflects the end points, so that every segment has both neighbours.
+#[must_use]
+pub fn with_phantom_ends(mut points: Vec<CurvePoint>) -> Vec<CurvePoint> {
+    let last = points.len() - 1;
+    let first = points[0] + (points[0] - points[1]);
+    let last = points[last] + (points[last] - points[last - 1]);
+    points.insert(0, first);
+    points.push(last);
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segment_passes_through_inner_points() {
+        let points = [(0.0, 0.0), (30.0, 90.0), (70.0, -90.0), (100.0, 0.0)];
+        let points = points.map(CurvePoint::from);
+        for parametrisation in [
+            SplineParametrisation::Uniform,
+            SplineParametrisation::Chordal,
+            SplineParametrisation::Centripetal,
+        ] {
+            let knots = parametrisation.knots(&points);
+            for index in [1, 2] {
+                let point = math::catmull_rom(&points, &knots, knots[index]);
+                assert!(point.distance_squared(points[index]) < 1e-6);
+            }
+        }
+    }
+}
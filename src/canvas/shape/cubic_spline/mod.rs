@@ -0,0 +1,248 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::VisualBaseLine;
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::control_points::ControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math::cubic_spline::CubicSpline;
+use crate::canvas::math::point::Point;
+use crate::canvas::math::vector::Vector;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::shape_changer::ShapeCommonValues;
+use crate::canvas::shape::{DrawOn, Update};
+use crate::config::CanvasConfig;
+
+pub mod request;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CubicSplineCurve {
+    points: ControlPoints<CurvePoint>,
+    control_points: VisualControlPoints,
+    polyline: VisualBaseLine<false>,
+    properties: CubicSplineCurveProperties,
+    samples: Samples,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct CubicSplineCurveProperties {
+    pub boundary: SplineBoundary,
+    pub parametrisation: SplineParametrisation,
+}
+
+#[derive(Debug, Copy, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub enum SplineBoundary {
+    #[default]
+    Natural,
+    /// Tangents are estimated from the outermost chords if they are not given.
+    Clamped(Option<EndTangents>),
+    Periodic,
+}
+
+/// With respect to the parameter normalised to `[0, 1]`.
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EndTangents {
+    pub start: Vector<f32>,
+    pub end: Vector<f32>,
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+    clap::ValueEnum,
+    parse_display::Display,
+)]
+#[display(style = "kebab-case")]
+pub enum SplineParametrisation {
+    Uniform,
+    Chordal,
+    #[default]
+    Centripetal,
+}
+
+impl CubicSplineCurve {
+    #[must_use]
+    pub fn new(
+        points: ControlPoints<CurvePoint>,
+        control_points: VisualControlPoints,
+        polyline: VisualBaseLine<false>,
+        properties: CubicSplineCurveProperties,
+        samples: Samples,
+    ) -> Self {
+        Self { points, control_points, polyline, properties, samples }
+    }
+}
+
+impl Update for CubicSplineCurve {
+    fn update_layers(&mut self, layers: Layers) {
+        if layers.contains(Layers::CURVE) && self.points.length() > 1 {
+            let (range, curve) = curve(&self.points, &self.properties);
+            let path = self.samples.sample(range, curve);
+            self.polyline.rebuild_sampled_paths(path);
+        }
+
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.polyline, layers);
+    }
+}
+
+fn curve(
+    points: &ControlPoints<CurvePoint>,
+    properties: &CubicSplineCurveProperties,
+) -> (RangeInclusive<f32>, impl Fn(f32) -> CurvePoint) {
+    let mut points = points.points_iterator().collect::<Vec<_>>();
+    if let SplineBoundary::Periodic = properties.boundary {
+        points.push(points[0]);
+    }
+    let knots = properties.parametrisation.knots(&points);
+    let (xs, ys): (Vec<_>, Vec<_>) = points.iter().copied().map(<(f32, f32)>::from).unzip();
+
+    let (horizontal, vertical) = match properties.boundary {
+        SplineBoundary::Natural => {
+            (CubicSpline::natural(knots.clone(), xs), CubicSpline::natural(knots, ys))
+        }
+        SplineBoundary::Clamped(tangents) => {
+            let EndTangents { start, end } =
+                tangents.unwrap_or_else(|| EndTangents::estimate(&points, &knots));
+            (
+                CubicSpline::clamped(knots.clone(), xs, start.horizontal(), end.horizontal()),
+                CubicSpline::clamped(knots, ys, start.vertical(), end.vertical()),
+            )
+        }
+        SplineBoundary::Periodic => {
+            (CubicSpline::periodic(knots.clone(), xs), CubicSpline::periodic(knots, ys))
+        }
+    };
+
+    (0.0..=1.0, move |t| Point::new(horizontal.evaluate(t), vertical.evaluate(t)))
+}
+
+impl DrawOn for CubicSplineCurve {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.polyline.draw_on(pixmap);
+        self.control_points.draw_on(pixmap);
+    }
+}
+
+impl From<CubicSplineCurve> for ShapeCommonValues {
+    fn from(value: CubicSplineCurve) -> Self {
+        Self {
+            points: Some(value.points),
+            control_points: Some(value.control_points),
+            open_base_line: Some(value.polyline),
+            cubic_spline_properties: Some(value.properties),
+            samples: Some(value.samples),
+            ..Default::default()
+        }
+    }
+}
+
+impl CubicSplineCurveProperties {
+    #[must_use]
+    pub fn new(boundary: SplineBoundary, parametrisation: SplineParametrisation) -> Self {
+        Self { boundary, parametrisation }
+    }
+}
+
+impl From<&CanvasConfig> for CubicSplineCurveProperties {
+    fn from(value: &CanvasConfig) -> Self {
+        Self {
+            boundary: value.default_spline_boundary,
+            parametrisation: value.default_spline_parametrisation,
+        }
+    }
+}
+
+impl EndTangents {
+    #[must_use]
+    pub fn new(start: Vector<f32>, end: Vector<f32>) -> Self {
+        Self { start, end }
+    }
+
+    fn estimate(points: &[CurvePoint], knots: &[f32]) -> Self {
+        let last = points.len() - 1;
+        let start = (points[1] - points[0]) / (knots[1] - knots[0]);
+        let end = (points[last] - points[last - 1]) / (knots[last] - knots[last - 1]);
+        Self { start, end }
+    }
+}
+
+impl Display for SplineBoundary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SplineBoundary::Natural => write!(f, "natural"),
+            SplineBoundary::Clamped(None) => write!(f, "clamped"),
+            SplineBoundary::Clamped(Some(EndTangents { start, end })) => write!(
+                f,
+                "clamped:{},{},{},{}",
+                start.horizontal(),
+                start.vertical(),
+                end.horizontal(),
+                end.vertical()
+            ),
+            SplineBoundary::Periodic => write!(f, "periodic"),
+        }
+    }
+}
+
+impl FromStr for SplineBoundary {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "natural" => Ok(SplineBoundary::Natural),
+            "periodic" => Ok(SplineBoundary::Periodic),
+            "clamped" => Ok(SplineBoundary::Clamped(None)),
+            _ => {
+                let tangents = input
+                    .strip_prefix("clamped:")
+                    .ok_or_else(|| anyhow!("unknown spline boundary: {input}"))?;
+                let values = tangents.split(',').map(str::parse).collect::<Result<Vec<_>, _>>()?;
+                let [start_x, start_y, end_x, end_y] = values[..] else {
+                    return Err(anyhow!("expected 4 tangent coordinates, got {}", values.len()));
+                };
+                let start = Vector::new(start_x, start_y);
+                let end = Vector::new(end_x, end_y);
+                Ok(SplineBoundary::Clamped(Some(EndTangents::new(start, end))))
+            }
+        }
+    }
+}
+
+impl SplineParametrisation {
+    #[must_use]
+    pub fn exponent(self) -> f32 {
+        match self {
+            SplineParametrisation::Uniform => 0.0,
+            SplineParametrisation::Chordal => 1.0,
+            SplineParametrisation::Centripetal => 0.5,
+        }
+    }
+
+    /// Increasing knots in `[0, 1]`, one for each point.
+    #[must_use]
+    pub fn knots(self, points: &[CurvePoint]) -> Vec<f32> {
+        let exponent = self.exponent() / 2.0;
+        let mut knots = Vec::with_capacity(points.len());
+        knots.push(0.0);
+        for pair in points.windows(2) {
+            let step = pair[0].distance_squared(pair[1]).powf(exponent).max(f32::EPSILON);
+            knots.push(knots[knots.len() - 1] + step);
+        }
+        let last = knots[knots.len() - 1];
+        for knot in &mut knots {
+            *knot /= last;
+        }
+        knots
+    }
+}
@@ -0,0 +1,213 @@
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::control_points::point::CurveControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::cubic_spline::{curve, CubicSplineCurve};
+use crate::canvas::shape::request::declare::{
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSampling, GetSplineDegree, GetTangent, GetTcbParameters,
+    GetWeight, MoveCurve, MovePoint, ProjectPoint, ReduceDegree, RotateCurve, SelectPoint,
+    SetAnalyticFamily, SetBezierAlgorithm, SetCombScale, SetFittingBasis, SetFittingDegree,
+    SetInterpolationNodes, SetJointContinuity, SetKnotVector, SetOverlayParameter,
+    SetOverlayVisibility, SetParametricConstant, SetParametricExpressions, SetPlacement,
+    SetPolygonRadius, SetPolygonVertices, SetSampling, SetSplineDegree, SetTangent,
+    SetTcbParameters, SetTrochoidProperties, SplitCurve,
+};
+use crate::canvas::shape::request::declare::{
+    GetSamples, GetSplineBoundary, GetSplineParametrisation, SetSamples, SetSplineBoundary,
+    SetSplineParametrisation,
+};
+use crate::request::macros::delegate_requests;
+use crate::request::{
+    RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
+
+delegate_requests! {
+    CubicSplineCurve {
+        // ControlPoints requests
+        { mut AddControlPoint => CurveControlPoints },
+        { mut MovePoint => CurveControlPoints },
+        { mut DeletePoint => CurveControlPoints },
+        { mut RotateCurve => CurveControlPoints },
+        { mut MoveCurve => CurveControlPoints },
+        { mut ChangeWeight => ! },
+        { mut AddWeightedControlPoint => ! },
+        { GetControlPointsLength => CurveControlPoints },
+        { GetCurveCenter => CurveControlPoints },
+        { SelectPoint => CurveControlPoints },
+        { GetPoint => CurveControlPoints },
+        { GetWeight => ! },
+
+        // Samples requests
+        { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
+        { GetSamples => Samples },
+        { GetSampling => Samples },
+
+        // InterpolationCurve requests
+        { mut SetInterpolationNodes => ! },
+        { GetInterpolationNodes => ! },
+
+        // TrochoidCurve requests
+        { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => ! },
+        { mut SetPlacement => ! },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetArcLength => OpenBaseLine },
+        { GetEquidistantPoints => OpenBaseLine },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => OpenBaseLine },
+        { GetCurveFeatures => OpenBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => VisualControlPoints },
+        { mut SetOverlayVisibility => VisualControlPoints },
+        { GetOverlayParameter => VisualControlPoints },
+        { mut SetOverlayParameter => VisualControlPoints },
+        { GetCombScale => VisualControlPoints },
+        { mut SetCombScale => VisualControlPoints },
+    }
+}
+
+impl RequestHandler<GetSplineBoundary> for CubicSplineCurve {
+    fn handle(&self, _event: GetSplineBoundary) -> Response<GetSplineBoundary> {
+        Ok(self.properties.boundary)
+    }
+}
+
+impl RequestHandlerMut<SetSplineBoundary> for CubicSplineCurve {
+    fn handle_mut(&mut self, event: SetSplineBoundary) -> ResponseMut<SetSplineBoundary> {
+        self.properties.boundary = event.0;
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetSplineParametrisation> for CubicSplineCurve {
+    fn handle(&self, _event: GetSplineParametrisation) -> Response<GetSplineParametrisation> {
+        Ok(self.properties.parametrisation)
+    }
+}
+
+impl RequestHandlerMut<SetSplineParametrisation> for CubicSplineCurve {
+    fn handle_mut(
+        &mut self,
+        event: SetSplineParametrisation,
+    ) -> ResponseMut<SetSplineParametrisation> {
+        self.properties.parametrisation = event.0;
+        Ok(())
+    }
+}
+
+impl RequestHandler<ProjectPoint> for CubicSplineCurve {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.polyline.handle(ProjectPoint::new(event.point))?;
+        if self.points.length() < 2 {
+            return Ok(sample);
+        }
+        let (range, curve) = curve(&self.points, &self.properties);
+        Ok(math::projection::refine_projection(curve, range, event.point, sample.t))
+    }
+}
+
+impl RequestSubHandler<CurveControlPoints> for CubicSplineCurve {
+    fn sub_handler(&self) -> &CurveControlPoints {
+        &self.points
+    }
+}
+
+impl RequestSubHandlerMut<CurveControlPoints> for CubicSplineCurve {
+    fn sub_handler_mut(&mut self) -> &mut CurveControlPoints {
+        &mut self.points
+    }
+}
+
+impl RequestSubHandler<Samples> for CubicSplineCurve {
+    fn sub_handler(&self) -> &Samples {
+        &self.samples
+    }
+}
+
+impl RequestSubHandlerMut<Samples> for CubicSplineCurve {
+    fn sub_handler_mut(&mut self) -> &mut Samples {
+        &mut self.samples
+    }
+}
+
+impl RequestSubHandler<OpenBaseLine> for CubicSplineCurve {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.polyline
+    }
+}
+
+impl RequestSubHandler<VisualControlPoints> for CubicSplineCurve {
+    fn sub_handler(&self) -> &VisualControlPoints {
+        &self.control_points
+    }
+}
+
+impl RequestSubHandlerMut<VisualControlPoints> for CubicSplineCurve {
+    fn sub_handler_mut(&mut self) -> &mut VisualControlPoints {
+        &mut self.control_points
+    }
+}
@@ -0,0 +1,210 @@
+use std::iter;
+
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::VisualBaseLine;
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::control_points::ControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::math::point::Point;
+use crate::canvas::math::polynomial::Polynomial;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::cubic_spline::SplineParametrisation;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::shape_changer::ShapeCommonValues;
+use crate::canvas::shape::{DrawOn, Update};
+use crate::config::CanvasConfig;
+
+pub mod request;
+
+/// Parameters of the control points are chosen as knots of an interpolating spline.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FittingCurve {
+    points: ControlPoints<CurvePoint>,
+    control_points: VisualControlPoints,
+    polyline: VisualBaseLine<false>,
+    properties: FittingCurveProperties,
+    samples: Samples,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct FittingCurveProperties {
+    pub basis: FittingBasis,
+    pub degree: usize,
+    pub parametrisation: SplineParametrisation,
+}
+
+/// Bernstein basis gives the same curve, but it is much better conditioned for higher degrees.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+    clap::ValueEnum,
+    parse_display::Display,
+)]
+#[display(style = "kebab-case")]
+pub enum FittingBasis {
+    #[default]
+    Bezier,
+    Polynomial,
+}
+
+impl FittingCurve {
+    #[must_use]
+    pub fn new(
+        points: ControlPoints<CurvePoint>,
+        control_points: VisualControlPoints,
+        polyline: VisualBaseLine<false>,
+        properties: FittingCurveProperties,
+        samples: Samples,
+    ) -> Self {
+        Self { points, control_points, polyline, properties, samples }
+    }
+}
+
+impl Update for FittingCurve {
+    fn update_layers(&mut self, layers: Layers) {
+        if layers.contains(Layers::CURVE) && self.points.length() > 1 {
+            match curve(&self.points, &self.properties) {
+                Some(curve) => {
+                    let path = self.samples.sample(0.0..=1.0, curve);
+                    self.polyline.rebuild_sampled_paths(path);
+                }
+                None => self.polyline.rebuild_paths(iter::empty::<CurvePoint>()),
+            }
+        }
+
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.polyline, layers);
+    }
+}
+
+/// Returns `None` if the fit fails.
+fn curve(
+    points: &ControlPoints<CurvePoint>,
+    properties: &FittingCurveProperties,
+) -> Option<impl Fn(f32) -> CurvePoint> {
+    let degree = properties.degree.min(points.length() - 1);
+    let points = points.points_iterator().collect::<Vec<_>>();
+    let ts = properties.parametrisation.knots(&points);
+    let basis = properties.basis;
+    let coefficients = math::least_squares::fit(&ts, &points, |t| basis.at(degree, t))?;
+    Some(basis.curve(coefficients))
+}
+
+impl DrawOn for FittingCurve {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.polyline.draw_on(pixmap);
+        self.control_points.draw_on(pixmap);
+    }
+}
+
+impl From<FittingCurve> for ShapeCommonValues {
+    fn from(value: FittingCurve) -> Self {
+        Self {
+            points: Some(value.points),
+            control_points: Some(value.control_points),
+            open_base_line: Some(value.polyline),
+            fitting_properties: Some(value.properties),
+            samples: Some(value.samples),
+            ..Default::default()
+        }
+    }
+}
+
+impl FittingCurveProperties {
+    #[must_use]
+    pub fn new(basis: FittingBasis, degree: usize, parametrisation: SplineParametrisation) -> Self {
+        Self { basis, degree, parametrisation }
+    }
+}
+
+impl From<&CanvasConfig> for FittingCurveProperties {
+    fn from(value: &CanvasConfig) -> Self {
+        Self {
+            basis: value.default_fitting_basis,
+            degree: value.default_fitting_degree,
+            parametrisation: value.default_spline_parametrisation,
+        }
+    }
+}
+
+impl FittingBasis {
+    #[must_use]
+    pub fn at(self, degree: usize, t: f32) -> Vec<f32> {
+        match self {
+            FittingBasis::Bezier => {
+                (0..=degree as u32).map(|k| math::bernstein(degree as u32, k, t)).collect()
+            }
+            FittingBasis::Polynomial => (0..=degree as i32).map(|k| t.powi(k)).collect(),
+        }
+    }
+
+    pub fn curve(self, coefficients: Vec<CurvePoint>) -> impl Fn(f32) -> CurvePoint {
+        let polynomials = match self {
+            FittingBasis::Bezier => None,
+            FittingBasis::Polynomial => {
+                let (xs, ys) = coefficients.iter().copied().map(<(f32, f32)>::from).unzip();
+                Some((Polynomial::new(xs), Polynomial::new(ys)))
+            }
+        };
+        move |t| match &polynomials {
+            Some((horizontal, vertical)) => {
+                Point::new(horizontal.evaluate(t), vertical.evaluate(t))
+            }
+            None => math::de_casteljau(&coefficients, t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bases_give_the_same_curve() {
+        let points = [(0.0, 0.0), (1.0, 2.0), (2.0, 3.0), (3.0, 2.5), (4.0, 0.0), (5.0, -1.0)]
+            .map(|(x, y)| Point::new(x, y));
+        let ts = SplineParametrisation::Chordal.knots(&points);
+        let curves = [FittingBasis::Bezier, FittingBasis::Polynomial].map(|basis| {
+            let coefficients = math::least_squares::fit(&ts, &points, |t| basis.at(3, t)).unwrap();
+            basis.curve(coefficients)
+        });
+        for t in [0.0, 0.3, 0.5, 0.8, 1.0] {
+            let [bezier, polynomial] = curves.each_ref().map(|curve| curve(t));
+            assert!(bezier.distance_squared(polynomial) < 1e-6);
+        }
+    }
+
+    #[test]
+    fn failed_fit_clears_path() {
+        let points =
+            [(0.0, 0.0), (1.0, 2.0), (2.0, 3.0), (3.0, 2.5)].map(|(x, y)| Point::new(x, y));
+        let config = CanvasConfig::default();
+        let properties = FittingCurveProperties::new(
+            FittingBasis::Polynomial,
+            3,
+            SplineParametrisation::Uniform,
+        );
+        let mut curve = FittingCurve::new(
+            ControlPoints::new(points.to_vec()),
+            (&config).into(),
+            (&config).into(),
+            properties,
+            Samples::new(50),
+        );
+        curve.update();
+        assert_eq!(curve.polyline.path_points().len(), 50);
+
+        // Chordal parameters of the coincident points are too close to tell them apart.
+        let points = [(0.0, 0.0), (0.0, 0.0), (0.0, 0.0), (1000.0, 1000.0)];
+        curve.points = ControlPoints::new(points.map(|(x, y)| Point::new(x, y)).to_vec());
+        curve.properties.parametrisation = SplineParametrisation::Chordal;
+        curve.update_layers(Layers::CURVE);
+        assert!(curve.polyline.path_points().is_empty());
+    }
+}
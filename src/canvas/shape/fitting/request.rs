@@ -0,0 +1,228 @@
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::control_points::point::CurveControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::fitting::{curve, FittingCurve};
+use crate::canvas::shape::request::declare::{
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSampling, GetSplineDegree, GetTangent, GetTcbParameters,
+    GetWeight, MoveCurve, MovePoint, ProjectPoint, ReduceDegree, RotateCurve, SelectPoint,
+    SetAnalyticFamily, SetBezierAlgorithm, SetCombScale, SetFittingBasis, SetFittingDegree,
+    SetInterpolationNodes, SetJointContinuity, SetKnotVector, SetOverlayParameter,
+    SetOverlayVisibility, SetParametricConstant, SetParametricExpressions, SetPlacement,
+    SetPolygonRadius, SetPolygonVertices, SetSampling, SetSplineDegree, SetTangent,
+    SetTcbParameters, SetTrochoidProperties, SplitCurve,
+};
+use crate::canvas::shape::request::declare::{
+    GetSamples, GetSplineBoundary, GetSplineParametrisation, SetSamples, SetSplineBoundary,
+    SetSplineParametrisation,
+};
+use crate::request::macros::delegate_requests;
+use crate::request::{
+    RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
+
+delegate_requests! {
+    FittingCurve {
+        // ControlPoints requests
+        { mut AddControlPoint => CurveControlPoints },
+        { mut MovePoint => CurveControlPoints },
+        { mut DeletePoint => CurveControlPoints },
+        { mut RotateCurve => CurveControlPoints },
+        { mut MoveCurve => CurveControlPoints },
+        { mut ChangeWeight => ! },
+        { mut AddWeightedControlPoint => ! },
+        { GetControlPointsLength => CurveControlPoints },
+        { GetCurveCenter => CurveControlPoints },
+        { SelectPoint => CurveControlPoints },
+        { GetPoint => CurveControlPoints },
+        { GetWeight => ! },
+
+        // Samples requests
+        { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
+        { GetSamples => Samples },
+        { GetSampling => Samples },
+
+        // InterpolationCurve requests
+        { mut SetInterpolationNodes => ! },
+        { GetInterpolationNodes => ! },
+
+        // TrochoidCurve requests
+        { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => ! },
+        { mut SetPlacement => ! },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetArcLength => OpenBaseLine },
+        { GetEquidistantPoints => OpenBaseLine },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => OpenBaseLine },
+        { GetCurveFeatures => OpenBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => VisualControlPoints },
+        { mut SetOverlayVisibility => VisualControlPoints },
+        { GetOverlayParameter => VisualControlPoints },
+        { mut SetOverlayParameter => VisualControlPoints },
+        { GetCombScale => VisualControlPoints },
+        { mut SetCombScale => VisualControlPoints },
+    }
+}
+
+impl RequestHandler<GetSplineParametrisation> for FittingCurve {
+    fn handle(&self, _event: GetSplineParametrisation) -> Response<GetSplineParametrisation> {
+        Ok(self.properties.parametrisation)
+    }
+}
+
+impl RequestHandlerMut<SetSplineParametrisation> for FittingCurve {
+    fn handle_mut(
+        &mut self,
+        event: SetSplineParametrisation,
+    ) -> ResponseMut<SetSplineParametrisation> {
+        self.properties.parametrisation = event.0;
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetFittingBasis> for FittingCurve {
+    fn handle(&self, _event: GetFittingBasis) -> Response<GetFittingBasis> {
+        Ok(self.properties.basis)
+    }
+}
+
+impl RequestHandlerMut<SetFittingBasis> for FittingCurve {
+    fn handle_mut(&mut self, event: SetFittingBasis) -> ResponseMut<SetFittingBasis> {
+        self.properties.basis = event.0;
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetFittingDegree> for FittingCurve {
+    fn handle(&self, _event: GetFittingDegree) -> Response<GetFittingDegree> {
+        Ok(self.properties.degree)
+    }
+}
+
+impl RequestHandlerMut<SetFittingDegree> for FittingCurve {
+    fn handle_mut(&mut self, event: SetFittingDegree) -> ResponseMut<SetFittingDegree> {
+        self.properties.degree = event.0;
+        Ok(())
+    }
+}
+
+impl RequestHandler<ProjectPoint> for FittingCurve {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.polyline.handle(ProjectPoint::new(event.point))?;
+        if self.points.length() < 2 {
+            return Ok(sample);
+        }
+        Ok(match curve(&self.points, &self.properties) {
+            Some(curve) => {
+                math::projection::refine_projection(curve, 0.0..=1.0, event.point, sample.t)
+            }
+            None => sample,
+        })
+    }
+}
+
+impl RequestSubHandler<CurveControlPoints> for FittingCurve {
+    fn sub_handler(&self) -> &CurveControlPoints {
+        &self.points
+    }
+}
+
+impl RequestSubHandlerMut<CurveControlPoints> for FittingCurve {
+    fn sub_handler_mut(&mut self) -> &mut CurveControlPoints {
+        &mut self.points
+    }
+}
+
+impl RequestSubHandler<Samples> for FittingCurve {
+    fn sub_handler(&self) -> &Samples {
+        &self.samples
+    }
+}
+
+impl RequestSubHandlerMut<Samples> for FittingCurve {
+    fn sub_handler_mut(&mut self) -> &mut Samples {
+        &mut self.samples
+    }
+}
+
+impl RequestSubHandler<OpenBaseLine> for FittingCurve {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.polyline
+    }
+}
+
+impl RequestSubHandler<VisualControlPoints> for FittingCurve {
+    fn sub_handler(&self) -> &VisualControlPoints {
+        &self.control_points
+    }
+}
+
+impl RequestSubHandlerMut<VisualControlPoints> for FittingCurve {
+    fn sub_handler_mut(&mut self) -> &mut VisualControlPoints {
+        &mut self.control_points
+    }
+}
@@ -0,0 +1,269 @@
+use std::ops::RangeInclusive;
+
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::VisualBaseLine;
+use crate::canvas::control_points::point::{CurvePoint, PointContainer};
+use crate::canvas::control_points::tangent::TangentPoint;
+use crate::canvas::control_points::ControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::shape_changer::ShapeCommonValues;
+use crate::canvas::shape::{DrawOn, Update};
+use crate::canvas::visual_path::line::{VisualLineProperties, VisualSegments};
+use crate::canvas::visual_path::point::{VisualPoint, VisualPointProperties};
+use crate::config::rgb::Alpha;
+use crate::config::CanvasConfig;
+
+pub mod request;
+
+/// Places handles at the control points of the equivalent cubic Bezier curve.
+pub const DEFAULT_HANDLE_SCALE: f32 = 1.0 / 3.0;
+
+/// Handle of the point `i` has id `i + n`, where `n` is the number of control points.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct HermiteCurve {
+    points: HermiteControlPoints,
+    control_points: VisualControlPoints,
+    tangent_handles: TangentHandles,
+    polyline: VisualBaseLine<false>,
+    properties: HermiteCurveProperties,
+    samples: Samples,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct HermiteCurveProperties {
+    pub handle_scale: f32,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TangentHandles {
+    points: VisualPoint,
+    lines: VisualSegments,
+}
+
+pub type HermiteControlPoints = ControlPoints<HermitePoint>;
+pub type HermitePoint = TangentPoint<f32>;
+
+impl HermiteCurve {
+    #[must_use]
+    pub fn new(
+        points: HermiteControlPoints,
+        control_points: VisualControlPoints,
+        tangent_handles: TangentHandles,
+        polyline: VisualBaseLine<false>,
+        properties: HermiteCurveProperties,
+        samples: Samples,
+    ) -> Self {
+        Self { points, control_points, tangent_handles, polyline, properties, samples }
+    }
+
+    fn handle_index(&self, id: usize) -> Option<usize> {
+        id.checked_sub(self.points.length()).filter(|&index| index < self.points.length())
+    }
+
+    fn handles(&self) -> impl ExactSizeIterator<Item = CurvePoint> + '_ {
+        let scale = self.properties.handle_scale;
+        self.points.iterator().map(move |point| point.into_point() + point.tangent() * scale)
+    }
+}
+
+impl Update for HermiteCurve {
+    fn update_layers(&mut self, layers: Layers) {
+        if layers.contains(Layers::CURVE) && self.points.length() > 1 {
+            let (range, curve) = curve(self.points.as_slice());
+            let path = self.samples.sample(range, curve);
+            self.polyline.rebuild_sampled_paths(path);
+        }
+
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.polyline, layers);
+
+        if !layers.contains(Layers::CONTROL_POINTS) {
+            return;
+        }
+        let handles = self.handles().collect::<Vec<_>>();
+        let lines = self
+            .points
+            .points_iterator()
+            .zip(handles.iter().copied())
+            .flat_map(|(point, handle)| [point, handle])
+            .collect::<Vec<_>>();
+        self.tangent_handles.points.rebuild_path(handles.into_iter());
+        self.tangent_handles.lines.rebuild_path(lines.into_iter());
+    }
+}
+
+/// Integer part of the parameter is the index of the segment.
+fn curve(points: &[HermitePoint]) -> (RangeInclusive<f32>, impl Fn(f32) -> CurvePoint + '_) {
+    let segments = points.len() - 1;
+    let curve = move |t: f32| {
+        let segment = (t as usize).min(segments - 1);
+        let (start, end) = (points[segment], points[segment + 1]);
+        math::hermite(
+            start.into_point(),
+            start.tangent(),
+            end.into_point(),
+            end.tangent(),
+            t - segment as f32,
+        )
+    };
+    (0.0..=segments as f32, curve)
+}
+
+impl DrawOn for HermiteCurve {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.polyline.draw_on(pixmap);
+        self.control_points.draw_on(pixmap);
+        self.tangent_handles.lines.draw_on(pixmap);
+        self.tangent_handles.points.draw_on(pixmap);
+    }
+}
+
+impl From<HermiteCurve> for ShapeCommonValues {
+    fn from(value: HermiteCurve) -> Self {
+        Self {
+            tangent_points: Some(value.points),
+            control_points: Some(value.control_points),
+            tangent_handles: Some(value.tangent_handles),
+            open_base_line: Some(value.polyline),
+            hermite_properties: Some(value.properties),
+            samples: Some(value.samples),
+            ..Default::default()
+        }
+    }
+}
+
+impl HermiteCurveProperties {
+    #[must_use]
+    pub fn new(handle_scale: f32) -> Self {
+        Self { handle_scale }
+    }
+}
+
+impl From<&CanvasConfig> for HermiteCurveProperties {
+    fn from(value: &CanvasConfig) -> Self {
+        // Shifts of dragged handles are divided by the scale, so it has to be positive.
+        let handle_scale = value.default_tangent_handle_scale;
+        let valid = handle_scale > 0.0 && handle_scale.is_finite();
+        Self { handle_scale: if valid { handle_scale } else { DEFAULT_HANDLE_SCALE } }
+    }
+}
+
+impl TangentHandles {
+    #[must_use]
+    pub fn new(points: VisualPoint, lines: VisualSegments) -> Self {
+        Self { points, lines }
+    }
+}
+
+impl From<&CanvasConfig> for TangentHandles {
+    fn from(value: &CanvasConfig) -> Self {
+        Self {
+            points: VisualPoint::new(
+                true,
+                VisualPointProperties::new(
+                    value.default_point_radius * 0.75,
+                    value.convex_hull_color,
+                    Alpha::OPAQUE,
+                ),
+            ),
+            lines: VisualSegments::new(
+                true,
+                VisualLineProperties::new(
+                    value.default_line_width / 2.0,
+                    value.convex_hull_color,
+                    Alpha::OPAQUE,
+                ),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::canvas::math::point::Point;
+    use crate::canvas::math::vector::Vector;
+    use crate::canvas::samples::Sampling;
+    use crate::canvas::shape::request::declare::{
+        GetPoint, MovePoint, ProjectPoint, SelectPoint, SetSampling,
+    };
+    use crate::request::{Error, RequestHandler, RequestHandlerMut};
+
+    fn assert_close(vector: Vector<f32>, expected: Vector<f32>) {
+        let difference = vector - expected;
+        assert!(difference.horizontal().hypot(difference.vertical()) < 1e-4);
+    }
+
+    #[test]
+    fn tangents_rotate_with_points() {
+        let points = vec![
+            TangentPoint::new(Point::new(0.0, 0.0), Vector::new(10.0, 0.0)),
+            TangentPoint::new(Point::new(20.0, 0.0), Vector::new(0.0, 5.0)),
+        ];
+        let mut points = ControlPoints::new(points);
+        points.rotate_all(FRAC_PI_2);
+        assert_close(points.get(0).unwrap().tangent(), Vector::new(0.0, 10.0));
+        assert_close(points.get(1).unwrap().tangent(), Vector::new(-5.0, 0.0));
+    }
+
+    #[test]
+    fn handles_follow_control_points_in_ids() {
+        let points = [(0.0, 0.0), (30.0, 90.0), (70.0, -90.0)];
+        let points = points
+            .into_iter()
+            .map(|(x, y)| TangentPoint::new(Point::new(x, y), Vector::new(30.0, 0.0)))
+            .collect();
+        let config = CanvasConfig { default_tangent_handle_scale: 0.0, ..CanvasConfig::default() };
+        let mut curve = HermiteCurve::new(
+            ControlPoints::new(points),
+            (&config).into(),
+            (&config).into(),
+            (&config).into(),
+            (&config).into(),
+            Samples::new(50),
+        );
+        assert_eq!(curve.properties.handle_scale, DEFAULT_HANDLE_SCALE);
+
+        let handle = curve.handle(GetPoint(4)).unwrap();
+        assert!(handle.distance_squared(Point::new(40.0, 90.0)) < 1e-4);
+        assert_eq!(curve.handle(SelectPoint { guess: handle, radius: 1.0 }).unwrap(), Some(4));
+        assert_eq!(curve.handle(GetPoint(1)).unwrap(), Point::new(30.0, 90.0));
+        assert!(matches!(curve.handle(GetPoint(6)), Err(Error::NoSuchPoint(6))));
+
+        curve.handle_mut(MovePoint { id: 4, shift: Vector::new(0.0, 10.0) }).unwrap();
+        assert_close(curve.points.get(1).unwrap().tangent(), Vector::new(30.0, 30.0));
+        assert_eq!(curve.points.get(1).unwrap().into_point(), Point::new(30.0, 90.0));
+    }
+
+    #[test]
+    fn projection_gives_parameter_of_adaptively_sampled_curve() {
+        let points = [(0.0, 0.0), (30.0, 90.0), (70.0, -90.0)];
+        let points = points
+            .into_iter()
+            .map(|(x, y)| TangentPoint::new(Point::new(x, y), Vector::new(300.0, 0.0)))
+            .collect::<Vec<_>>();
+        let config = CanvasConfig::default();
+        let mut samples = Samples::new(50);
+        samples.handle_mut(SetSampling(Sampling::Adaptive { tolerance: 0.5 })).unwrap();
+        let mut curve = HermiteCurve::new(
+            ControlPoints::new(points.clone()),
+            (&config).into(),
+            (&config).into(),
+            (&config).into(),
+            (&config).into(),
+            samples,
+        );
+        curve.update();
+
+        let (_, evaluate) = super::curve(&points);
+        let projection = curve.handle(ProjectPoint::new(evaluate(1.3))).unwrap();
+        assert!((projection.t - 1.3).abs() < 1e-3);
+        assert!(projection.distance < 1e-2);
+    }
+}
@@ -0,0 +1,251 @@
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::control_points::point::PointContainer;
+use crate::canvas::control_points::tangent::TangentPoint;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::math::vector::Vector;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::hermite::{curve, HermiteControlPoints, HermiteCurve};
+use crate::canvas::shape::request::declare::{
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSampling, GetSplineDegree, GetWeight, MoveCurve,
+    MovePoint, ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily,
+    SetBezierAlgorithm, SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes,
+    SetJointContinuity, SetKnotVector, SetOverlayParameter, SetOverlayVisibility,
+    SetParametricConstant, SetParametricExpressions, SetPlacement, SetPolygonRadius,
+    SetPolygonVertices, SetSampling, SetSplineDegree, SetTrochoidProperties, SplitCurve,
+};
+use crate::canvas::shape::request::declare::{
+    GetSamples, GetSplineBoundary, GetSplineParametrisation, GetTangent, GetTcbParameters,
+    SetSamples, SetSplineBoundary, SetSplineParametrisation, SetTangent, SetTcbParameters,
+};
+use crate::request::macros::delegate_requests;
+use crate::request::{
+    Error, RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
+
+delegate_requests! {
+    HermiteCurve {
+        // ControlPoints requests
+        { mut DeletePoint => HermiteControlPoints },
+        { mut RotateCurve => HermiteControlPoints },
+        { mut MoveCurve => HermiteControlPoints },
+        { mut ChangeWeight => ! },
+        { mut AddWeightedControlPoint => ! },
+        { GetControlPointsLength => HermiteControlPoints },
+        { GetCurveCenter => HermiteControlPoints },
+        { GetWeight => ! },
+
+        // Samples requests
+        { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
+        { GetSamples => Samples },
+        { GetSampling => Samples },
+
+        // InterpolationCurve requests
+        { mut SetInterpolationNodes => ! },
+        { GetInterpolationNodes => ! },
+
+        // TrochoidCurve requests
+        { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => ! },
+        { mut SetPlacement => ! },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetArcLength => OpenBaseLine },
+        { GetEquidistantPoints => OpenBaseLine },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => OpenBaseLine },
+        { GetCurveFeatures => OpenBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => VisualControlPoints },
+        { mut SetOverlayVisibility => VisualControlPoints },
+        { GetOverlayParameter => VisualControlPoints },
+        { mut SetOverlayParameter => VisualControlPoints },
+        { GetCombScale => VisualControlPoints },
+        { mut SetCombScale => VisualControlPoints },
+    }
+}
+
+impl RequestHandlerMut<AddControlPoint> for HermiteCurve {
+    fn handle_mut(&mut self, event: AddControlPoint) -> ResponseMut<AddControlPoint> {
+        let length = self.points.length();
+        let tangent = match self.points.get(length.wrapping_sub(1)) {
+            Some(previous) => event.point - previous.into_point(),
+            None => Vector::new(0.0, 0.0),
+        };
+        if length == 1 {
+            // The first point has no neighbour to estimate its tangent from until now.
+            *self.points.get_mut(0).unwrap().tangent_mut() = tangent;
+        }
+        self.points.add(TangentPoint::new(event.point, tangent));
+        Ok(())
+    }
+}
+
+impl RequestHandlerMut<MovePoint> for HermiteCurve {
+    fn handle_mut(&mut self, event: MovePoint) -> ResponseMut<MovePoint> {
+        let Some(index) = self.handle_index(event.id) else {
+            return self.points.handle_mut(event);
+        };
+        let scale = self.properties.handle_scale;
+        let point = self.points.get_mut(index).ok_or_else(|| Error::NoSuchPoint(event.id))?;
+        *point.tangent_mut() = point.tangent() + event.shift / scale;
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetPoint> for HermiteCurve {
+    fn handle(&self, event: GetPoint) -> Response<GetPoint> {
+        let Some(index) = self.handle_index(event.0) else {
+            return self.points.handle(event);
+        };
+        let handle = self.handles().nth(index).ok_or_else(|| Error::NoSuchPoint(event.0))?;
+        Ok(handle)
+    }
+}
+
+impl RequestHandler<SelectPoint> for HermiteCurve {
+    fn handle(&self, event: SelectPoint) -> Response<SelectPoint> {
+        let SelectPoint { guess, radius } = event;
+        if let Some(id) = self.points.select_point(guess, radius) {
+            return Ok(Some(id));
+        }
+        let radius_squared = radius * radius;
+        let handle = self
+            .handles()
+            .position(|handle| guess.distance_squared(handle) <= radius_squared)
+            .map(|index| index + self.points.length());
+        Ok(handle)
+    }
+}
+
+impl RequestHandler<GetTangent> for HermiteCurve {
+    fn handle(&self, event: GetTangent) -> Response<GetTangent> {
+        let point = self.points.get(event.id).ok_or_else(|| Error::NoSuchPoint(event.id))?;
+        Ok(point.tangent())
+    }
+}
+
+impl RequestHandlerMut<SetTangent> for HermiteCurve {
+    fn handle_mut(&mut self, event: SetTangent) -> ResponseMut<SetTangent> {
+        let point = self.points.get_mut(event.id).ok_or_else(|| Error::NoSuchPoint(event.id))?;
+        *point.tangent_mut() = event.tangent;
+        Ok(())
+    }
+}
+
+impl RequestHandler<ProjectPoint> for HermiteCurve {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.polyline.handle(ProjectPoint::new(event.point))?;
+        if self.points.length() < 2 {
+            return Ok(sample);
+        }
+        let (range, curve) = curve(self.points.as_slice());
+        Ok(math::projection::refine_projection(curve, range, event.point, sample.t))
+    }
+}
+
+impl RequestSubHandler<HermiteControlPoints> for HermiteCurve {
+    fn sub_handler(&self) -> &HermiteControlPoints {
+        &self.points
+    }
+}
+
+impl RequestSubHandlerMut<HermiteControlPoints> for HermiteCurve {
+    fn sub_handler_mut(&mut self) -> &mut HermiteControlPoints {
+        &mut self.points
+    }
+}
+
+impl RequestSubHandler<Samples> for HermiteCurve {
+    fn sub_handler(&self) -> &Samples {
+        &self.samples
+    }
+}
+
+impl RequestSubHandlerMut<Samples> for HermiteCurve {
+    fn sub_handler_mut(&mut self) -> &mut Samples {
+        &mut self.samples
+    }
+}
+
+impl RequestSubHandler<OpenBaseLine> for HermiteCurve {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.polyline
+    }
+}
+
+impl RequestSubHandler<VisualControlPoints> for HermiteCurve {
+    fn sub_handler(&self) -> &VisualControlPoints {
+        &self.control_points
+    }
+}
+
+impl RequestSubHandlerMut<VisualControlPoints> for HermiteCurve {
+    fn sub_handler_mut(&mut self) -> &mut VisualControlPoints {
+        &mut self.control_points
+    }
+}
@@ -4,10 +4,12 @@ use crate::canvas::base_line::VisualBaseLine;
 use crate::canvas::control_points::point::CurvePoint;
 use crate::canvas::control_points::ControlPoints;
 use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math::barycentric::BarycentricNodes;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::layers::Layers;
 use crate::canvas::shape::shape_changer::ShapeCommonValues;
 use crate::canvas::shape::{DrawOn, Update};
 use crate::config::CanvasConfig;
-use crate::{canvas::math, canvas::math::point::Point, canvas::samples::Samples};
 
 pub mod request;
 
@@ -18,6 +20,9 @@ pub struct InterpolationCurve {
     polyline: VisualBaseLine<false>,
     properties: InterpolationCurveProperties,
     samples: Samples,
+    /// Nodes used in the last update, reused until their number or kind changes.
+    #[serde(skip)]
+    nodes: Option<(InterpolationNodes, BarycentricNodes)>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -25,7 +30,17 @@ pub struct InterpolationCurveProperties {
     pub nodes: InterpolationNodes,
 }
 
-#[derive(Debug, Copy, Clone, Default, serde::Serialize, serde::Deserialize, clap::ValueEnum)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+    clap::ValueEnum,
+)]
 pub enum InterpolationNodes {
     EquallySpaced,
     #[default]
@@ -41,41 +56,31 @@ impl InterpolationCurve {
         properties: InterpolationCurveProperties,
         samples: Samples,
     ) -> Self {
-        Self { points, control_points, polyline, properties, samples }
+        Self { points, control_points, polyline, properties, samples, nodes: None }
     }
 }
 
 impl Update for InterpolationCurve {
-    fn update(&mut self) {
-        if self.points.length() > 1 {
-            let length = self.points.length();
-            let (ts, first, last) = match self.properties.nodes {
-                InterpolationNodes::Chebyshev => {
-                    let ts = (1..=length)
-                        .map(|index| math::chebyshev(length, index))
-                        .collect::<Vec<_>>();
-                    let first = ts[0];
-                    let last = ts[length - 1];
-                    (ts, first, last)
-                }
-                InterpolationNodes::EquallySpaced => {
-                    let ts = (0..length)
-                        .map(|index| index as f32 / (length - 1) as f32)
-                        .collect::<Vec<_>>();
-                    (ts, 0.0, 1.0)
-                }
-            };
-
-            let (xs, ys): (Vec<_>, Vec<_>) =
-                self.points.points_iterator().map(<(f32, f32)>::from).unzip();
-            let path = self
-                .samples
-                .equally_spaced(first..=last)
-                .map(move |t| Point::new(math::lagrange(t, &ts, &xs), math::lagrange(t, &ts, &ys)));
-            self.polyline.rebuild_paths(path);
+    fn update_layers(&mut self, layers: Layers) {
+        if layers.contains(Layers::CURVE) && self.points.length() > 1 {
+            let (kind, length) = (self.properties.nodes, self.points.length());
+            if !matches!(&self.nodes, Some((cached, nodes)) if *cached == kind && nodes.len() == length)
+            {
+                self.nodes = Some((kind, kind.barycentric(length)));
+            }
+            let (_, nodes) = self.nodes.as_ref().expect("nodes should be cached");
+            let (first, last) = (nodes.nodes()[0], nodes.nodes()[nodes.len() - 1]);
+            let points = self.points.as_slice();
+            let path = self.samples.sample_parallel(
+                first..=last,
+                || (),
+                |(), t| nodes.interpolate(t, points),
+            );
+            self.polyline.rebuild_sampled_paths(path);
         }
 
-        self.control_points.rebuild_paths(&self.points);
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.polyline, layers);
     }
 }
 
@@ -106,6 +111,16 @@ impl InterpolationCurveProperties {
     }
 }
 
+impl InterpolationNodes {
+    #[must_use]
+    pub fn barycentric(self, length: usize) -> BarycentricNodes {
+        match self {
+            InterpolationNodes::EquallySpaced => BarycentricNodes::equally_spaced(length),
+            InterpolationNodes::Chebyshev => BarycentricNodes::chebyshev(length),
+        }
+    }
+}
+
 impl From<&CanvasConfig> for InterpolationCurveProperties {
     fn from(value: &CanvasConfig) -> Self {
         Self { nodes: value.default_interpolation_nodes }
@@ -1,10 +1,24 @@
+use crate::canvas::base_line::OpenBaseLine;
 use crate::canvas::control_points::point::CurveControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
 use crate::canvas::samples::Samples;
 use crate::canvas::shape::interpolation::InterpolationCurve;
 use crate::canvas::shape::request::declare::{
-    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, GetControlPointsLength,
-    GetCurveCenter, GetInterpolationNodes, GetPoint, GetWeight, MoveCurve, MovePoint, RotateCurve,
-    SelectPoint, SetInterpolationNodes, SetTrochoidProperties,
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSampling, GetSplineBoundary, GetSplineDegree,
+    GetSplineParametrisation, GetTangent, GetTcbParameters, GetWeight, MoveCurve, MovePoint,
+    ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily, SetBezierAlgorithm,
+    SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes, SetJointContinuity,
+    SetKnotVector, SetOverlayParameter, SetOverlayVisibility, SetParametricConstant,
+    SetParametricExpressions, SetPlacement, SetPolygonRadius, SetPolygonVertices, SetSampling,
+    SetSplineBoundary, SetSplineDegree, SetSplineParametrisation, SetTangent, SetTcbParameters,
+    SetTrochoidProperties, SplitCurve,
 };
 use crate::canvas::shape::request::declare::{GetSamples, SetSamples};
 use crate::request::macros::delegate_requests;
@@ -31,10 +45,88 @@ delegate_requests! {
 
         // Samples requests
         { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
         { GetSamples => Samples },
+        { GetSampling => Samples },
 
         // TrochoidCurve requests
         { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => ! },
+        { mut SetPlacement => ! },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetArcLength => OpenBaseLine },
+        { GetEquidistantPoints => OpenBaseLine },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => OpenBaseLine },
+        { GetCurveFeatures => OpenBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => VisualControlPoints },
+        { mut SetOverlayVisibility => VisualControlPoints },
+        { GetOverlayParameter => VisualControlPoints },
+        { mut SetOverlayParameter => VisualControlPoints },
+        { GetCombScale => VisualControlPoints },
+        { mut SetCombScale => VisualControlPoints },
     }
 }
 
@@ -51,6 +143,20 @@ impl RequestHandlerMut<SetInterpolationNodes> for InterpolationCurve {
     }
 }
 
+impl RequestHandler<ProjectPoint> for InterpolationCurve {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.polyline.handle(ProjectPoint::new(event.point))?;
+        match &self.nodes {
+            Some((kind, nodes)) if *kind == self.properties.nodes && nodes.len() > 1 => {
+                let range = nodes.nodes()[0]..=nodes.nodes()[nodes.len() - 1];
+                let curve = |t| nodes.interpolate(t, self.points.as_slice());
+                Ok(math::projection::refine_projection(curve, range, event.point, sample.t))
+            }
+            _ => Ok(sample),
+        }
+    }
+}
+
 impl RequestSubHandler<CurveControlPoints> for InterpolationCurve {
     fn sub_handler(&self) -> &CurveControlPoints {
         &self.points
@@ -74,3 +180,21 @@ impl RequestSubHandlerMut<Samples> for InterpolationCurve {
         &mut self.samples
     }
 }
+
+impl RequestSubHandler<OpenBaseLine> for InterpolationCurve {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.polyline
+    }
+}
+
+impl RequestSubHandler<VisualControlPoints> for InterpolationCurve {
+    fn sub_handler(&self) -> &VisualControlPoints {
+        &self.control_points
+    }
+}
+
+impl RequestSubHandlerMut<VisualControlPoints> for InterpolationCurve {
+    fn sub_handler_mut(&mut self) -> &mut VisualControlPoints {
+        &mut self.control_points
+    }
+}
@@ -0,0 +1,152 @@
+use std::ops::RangeInclusive;
+
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::VisualBaseLine;
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::control_points::tcb::{TcbParameters, TcbPoint};
+use crate::canvas::control_points::ControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::math::vector::Vector;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::catmull_rom;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::shape_changer::ShapeCommonValues;
+use crate::canvas::shape::{DrawOn, Update};
+use crate::config::CanvasConfig;
+
+pub mod request;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct KochanekBartelsCurve {
+    points: ControlPoints<KochanekBartelsPoint>,
+    control_points: VisualControlPoints,
+    polyline: VisualBaseLine<false>,
+    properties: KochanekBartelsCurveProperties,
+    samples: Samples,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct KochanekBartelsCurveProperties {
+    pub default_parameters: TcbParameters,
+}
+
+pub type TcbControlPoints = ControlPoints<KochanekBartelsPoint>;
+pub type KochanekBartelsPoint = TcbPoint<f32>;
+
+impl KochanekBartelsCurve {
+    #[must_use]
+    pub fn new(
+        points: ControlPoints<KochanekBartelsPoint>,
+        control_points: VisualControlPoints,
+        polyline: VisualBaseLine<false>,
+        properties: KochanekBartelsCurveProperties,
+        samples: Samples,
+    ) -> Self {
+        Self { points, control_points, polyline, properties, samples }
+    }
+}
+
+impl Update for KochanekBartelsCurve {
+    fn update_layers(&mut self, layers: Layers) {
+        if layers.contains(Layers::CURVE) && self.points.length() > 1 {
+            let (range, curve) = curve(&self.points);
+            let path = self.samples.sample(range, curve);
+            self.polyline.rebuild_sampled_paths(path);
+        }
+
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.polyline, layers);
+    }
+}
+
+/// Integer part of the parameter is the index of the segment.
+fn curve(points: &TcbControlPoints) -> (RangeInclusive<f32>, impl Fn(f32) -> CurvePoint) {
+    let segments = points.length() - 1;
+    let tangents = catmull_rom::with_phantom_ends(points.points_iterator().collect())
+        .windows(3)
+        .zip(points.iterator())
+        .map(|(points, point)| tangents(points, point.parameters()))
+        .collect::<Vec<_>>();
+    let points = points.points_iterator().collect::<Vec<_>>();
+    let curve = move |t: f32| {
+        let segment = (t as usize).min(segments - 1);
+        let (_, outgoing) = tangents[segment];
+        let (incoming, _) = tangents[segment + 1];
+        let (start, end) = (points[segment], points[segment + 1]);
+        math::hermite(start, outgoing, end, incoming, t - segment as f32)
+    };
+    (0.0..=segments as f32, curve)
+}
+
+fn tangents(points: &[CurvePoint], parameters: TcbParameters) -> (Vector<f32>, Vector<f32>) {
+    let TcbParameters { tension, continuity, bias } = parameters;
+    let before = points[1] - points[0];
+    let after = points[2] - points[1];
+    let scale = (1.0 - tension) / 2.0;
+    let incoming = before * (scale * (1.0 + bias) * (1.0 + continuity))
+        + after * (scale * (1.0 - bias) * (1.0 - continuity));
+    let outgoing = before * (scale * (1.0 + bias) * (1.0 - continuity))
+        + after * (scale * (1.0 - bias) * (1.0 + continuity));
+    (incoming, outgoing)
+}
+
+impl DrawOn for KochanekBartelsCurve {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.polyline.draw_on(pixmap);
+        self.control_points.draw_on(pixmap);
+    }
+}
+
+impl From<KochanekBartelsCurve> for ShapeCommonValues {
+    fn from(value: KochanekBartelsCurve) -> Self {
+        Self {
+            tcb_points: Some(value.points),
+            control_points: Some(value.control_points),
+            open_base_line: Some(value.polyline),
+            kochanek_bartels_properties: Some(value.properties),
+            samples: Some(value.samples),
+            ..Default::default()
+        }
+    }
+}
+
+impl KochanekBartelsCurveProperties {
+    #[must_use]
+    pub fn new(default_parameters: TcbParameters) -> Self {
+        Self { default_parameters }
+    }
+}
+
+impl From<&CanvasConfig> for KochanekBartelsCurveProperties {
+    fn from(value: &CanvasConfig) -> Self {
+        Self { default_parameters: value.default_tcb_parameters }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::shape::cubic_spline::SplineParametrisation;
+
+    #[test]
+    fn zero_parameters_give_uniform_catmull_rom() {
+        let points = [(0.0, 0.0), (30.0, 90.0), (70.0, -90.0), (100.0, 0.0), (160.0, 40.0)];
+        let points = catmull_rom::with_phantom_ends(points.map(CurvePoint::from).to_vec());
+        let knots = SplineParametrisation::Uniform.knots(&points);
+        let parameters = TcbParameters::default();
+        for segment in 0..(points.len() - 3) {
+            let (_, outgoing) = tangents(&points[segment..segment + 3], parameters);
+            let (incoming, _) = tangents(&points[segment + 1..segment + 4], parameters);
+            let (start, end) = (points[segment + 1], points[segment + 2]);
+            for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+                let point = math::hermite(start, outgoing, end, incoming, t);
+                let knot = knots[segment + 1] + t * (knots[segment + 2] - knots[segment + 1]);
+                let range = segment..segment + 4;
+                let expected = math::catmull_rom(&points[range.clone()], &knots[range], knot);
+                assert!(point.distance_squared(expected) < 1e-3);
+            }
+        }
+    }
+}
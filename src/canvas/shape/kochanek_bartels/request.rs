@@ -0,0 +1,215 @@
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::control_points::tcb::TcbPoint;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::kochanek_bartels::{curve, KochanekBartelsCurve, TcbControlPoints};
+use crate::canvas::shape::request::declare::{
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSampling, GetSplineDegree, GetTangent, GetWeight,
+    MoveCurve, MovePoint, ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily,
+    SetBezierAlgorithm, SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes,
+    SetJointContinuity, SetKnotVector, SetOverlayParameter, SetOverlayVisibility,
+    SetParametricConstant, SetParametricExpressions, SetPlacement, SetPolygonRadius,
+    SetPolygonVertices, SetSampling, SetSplineDegree, SetTangent, SetTrochoidProperties,
+    SplitCurve,
+};
+use crate::canvas::shape::request::declare::{
+    GetSamples, GetSplineBoundary, GetSplineParametrisation, GetTcbParameters, SetSamples,
+    SetSplineBoundary, SetSplineParametrisation, SetTcbParameters,
+};
+use crate::request::macros::delegate_requests;
+use crate::request::{
+    Error, RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
+
+delegate_requests! {
+    KochanekBartelsCurve {
+        // ControlPoints requests
+        { mut MovePoint => TcbControlPoints },
+        { mut DeletePoint => TcbControlPoints },
+        { mut RotateCurve => TcbControlPoints },
+        { mut MoveCurve => TcbControlPoints },
+        { mut ChangeWeight => ! },
+        { mut AddWeightedControlPoint => ! },
+        { GetControlPointsLength => TcbControlPoints },
+        { GetCurveCenter => TcbControlPoints },
+        { SelectPoint => TcbControlPoints },
+        { GetPoint => TcbControlPoints },
+        { GetWeight => ! },
+
+        // Samples requests
+        { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
+        { GetSamples => Samples },
+        { GetSampling => Samples },
+
+        // InterpolationCurve requests
+        { mut SetInterpolationNodes => ! },
+        { GetInterpolationNodes => ! },
+
+        // TrochoidCurve requests
+        { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => ! },
+        { mut SetPlacement => ! },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetArcLength => OpenBaseLine },
+        { GetEquidistantPoints => OpenBaseLine },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => OpenBaseLine },
+        { GetCurveFeatures => OpenBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => VisualControlPoints },
+        { mut SetOverlayVisibility => VisualControlPoints },
+        { GetOverlayParameter => VisualControlPoints },
+        { mut SetOverlayParameter => VisualControlPoints },
+        { GetCombScale => VisualControlPoints },
+        { mut SetCombScale => VisualControlPoints },
+    }
+}
+
+impl RequestHandlerMut<AddControlPoint> for KochanekBartelsCurve {
+    fn handle_mut(&mut self, event: AddControlPoint) -> ResponseMut<AddControlPoint> {
+        let parameters = self.properties.default_parameters;
+        self.points.add(TcbPoint::new(event.point, parameters));
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetTcbParameters> for KochanekBartelsCurve {
+    fn handle(&self, event: GetTcbParameters) -> Response<GetTcbParameters> {
+        let point = self.points.get(event.id).ok_or_else(|| Error::NoSuchPoint(event.id))?;
+        Ok(point.parameters())
+    }
+}
+
+impl RequestHandlerMut<SetTcbParameters> for KochanekBartelsCurve {
+    fn handle_mut(&mut self, event: SetTcbParameters) -> ResponseMut<SetTcbParameters> {
+        if let Some(id) = event.id {
+            let point = self.points.get_mut(id).ok_or_else(|| Error::NoSuchPoint(id))?;
+            *point.parameters_mut() = event.parameters;
+        } else {
+            self.properties.default_parameters = event.parameters;
+            for point in self.points.iterator_mut() {
+                *point.parameters_mut() = event.parameters;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RequestHandler<ProjectPoint> for KochanekBartelsCurve {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.polyline.handle(ProjectPoint::new(event.point))?;
+        if self.points.length() < 2 {
+            return Ok(sample);
+        }
+        let (range, curve) = curve(&self.points);
+        Ok(math::projection::refine_projection(curve, range, event.point, sample.t))
+    }
+}
+
+impl RequestSubHandler<TcbControlPoints> for KochanekBartelsCurve {
+    fn sub_handler(&self) -> &TcbControlPoints {
+        &self.points
+    }
+}
+
+impl RequestSubHandlerMut<TcbControlPoints> for KochanekBartelsCurve {
+    fn sub_handler_mut(&mut self) -> &mut TcbControlPoints {
+        &mut self.points
+    }
+}
+
+impl RequestSubHandler<Samples> for KochanekBartelsCurve {
+    fn sub_handler(&self) -> &Samples {
+        &self.samples
+    }
+}
+
+impl RequestSubHandlerMut<Samples> for KochanekBartelsCurve {
+    fn sub_handler_mut(&mut self) -> &mut Samples {
+        &mut self.samples
+    }
+}
+
+impl RequestSubHandler<OpenBaseLine> for KochanekBartelsCurve {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.polyline
+    }
+}
+
+impl RequestSubHandler<VisualControlPoints> for KochanekBartelsCurve {
+    fn sub_handler(&self) -> &VisualControlPoints {
+        &self.control_points
+    }
+}
+
+impl RequestSubHandlerMut<VisualControlPoints> for KochanekBartelsCurve {
+    fn sub_handler_mut(&mut self) -> &mut VisualControlPoints {
+        &mut self.control_points
+    }
+}
@@ -0,0 +1,79 @@
+use std::ops::{BitOr, BitOrAssign};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct Layers(u8);
+
+impl Layers {
+    pub const NONE: Self = Self(0);
+    pub const CURVE: Self = Self(1);
+    /// Overlays computed from the current samples of the curve.
+    pub const OVERLAYS: Self = Self(1 << 1);
+    pub const CONTROL_POINTS: Self = Self(1 << 2);
+    pub const CONVEX_HULL: Self = Self(1 << 3);
+    pub const CENTER_OF_MASS: Self = Self(1 << 4);
+    pub const ALL: Self = Self::CURVE
+        .union(Self::OVERLAYS)
+        .union(Self::CONTROL_POINTS)
+        .union(Self::CONVEX_HULL)
+        .union(Self::CENTER_OF_MASS);
+
+    #[must_use]
+    pub const fn union(self, layers: Self) -> Self {
+        Self(self.0 | layers.0)
+    }
+
+    #[must_use]
+    pub fn contains(self, layers: Self) -> bool {
+        self.0 & layers.0 == layers.0
+    }
+
+    #[must_use]
+    pub fn intersects(self, layers: Self) -> bool {
+        self.0 & layers.0 != 0
+    }
+}
+
+impl BitOr for Layers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for Layers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Layers of the shape to rebuild after handling the request.
+pub trait Invalidates {
+    const LAYERS: Layers;
+}
+
+macro_rules! invalidates {
+    ($($layers:expr => [$($request:ty),+ $(,)?]),+ $(,)?) => {
+        $($(
+            impl $crate::canvas::shape::layers::Invalidates for $request {
+                const LAYERS: $crate::canvas::shape::layers::Layers = $layers;
+            }
+        )+)+
+    };
+}
+
+pub(crate) use invalidates;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::shape::request::declare::{ChangeWeight, MovePoint};
+
+    #[test]
+    fn change_weight_keeps_control_points_and_convex_hull() {
+        assert!(ChangeWeight::LAYERS.contains(Layers::CURVE));
+        assert!(!ChangeWeight::LAYERS.intersects(Layers::CONVEX_HULL));
+        assert!(!ChangeWeight::LAYERS.intersects(Layers::CONTROL_POINTS));
+        assert_eq!(MovePoint::LAYERS, Layers::ALL);
+    }
+}
@@ -1,7 +1,18 @@
 use tiny_skia::PixmapMut;
 
+use crate::canvas::shape::analytic::AnalyticCurve;
+use crate::canvas::shape::b_spline::BSplineCurve;
 use crate::canvas::shape::bezier::BezierCurve;
+use crate::canvas::shape::bezier_spline::BezierSpline;
+use crate::canvas::shape::catmull_rom::CatmullRomCurve;
+use crate::canvas::shape::cubic_spline::CubicSplineCurve;
+use crate::canvas::shape::fitting::FittingCurve;
+use crate::canvas::shape::hermite::HermiteCurve;
 use crate::canvas::shape::interpolation::InterpolationCurve;
+use crate::canvas::shape::kochanek_bartels::KochanekBartelsCurve;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::nurbs::NurbsCurve;
+use crate::canvas::shape::parametric::ParametricCurve;
 use crate::canvas::shape::polyline::PolylineCurve;
 use crate::canvas::shape::rational_bezier::RationalBezierCurve;
 use crate::canvas::shape::regular_polygon::RegularPolygon;
@@ -9,8 +20,19 @@ use crate::canvas::shape::shape_changer::{ShapeChanger, ShapeCommonValues};
 use crate::canvas::shape::trochoid::TrochoidCurve;
 use crate::config::{CanvasConfig, ShapeType};
 
+pub mod analytic;
+pub mod b_spline;
 pub mod bezier;
+pub mod bezier_spline;
+pub mod catmull_rom;
+pub mod cubic_spline;
+pub mod fitting;
+pub mod hermite;
 pub mod interpolation;
+pub mod kochanek_bartels;
+pub mod layers;
+pub mod nurbs;
+pub mod parametric;
 pub mod polyline;
 pub mod rational_bezier;
 pub mod regular_polygon;
@@ -26,7 +48,12 @@ pub trait DrawOn {
 // TODO: in event handler add mut events that will call that method (all curves
 // must implement this trait)
 pub trait Update {
-    fn update(&mut self);
+    fn update(&mut self) {
+        self.update_layers(Layers::ALL);
+    }
+
+    /// Rebuilds only the given layers, the other ones are left as they are.
+    fn update_layers(&mut self, layers: Layers);
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -37,6 +64,16 @@ pub enum Shape {
     RationalBezier(Box<RationalBezierCurve>),
     Trochoid(Box<TrochoidCurve>),
     RegularPolygon(Box<RegularPolygon>),
+    BSpline(Box<BSplineCurve>),
+    Nurbs(Box<NurbsCurve>),
+    BezierSpline(Box<BezierSpline>),
+    CubicSpline(Box<CubicSplineCurve>),
+    CatmullRom(Box<CatmullRomCurve>),
+    KochanekBartels(Box<KochanekBartelsCurve>),
+    Hermite(Box<HermiteCurve>),
+    Parametric(Box<ParametricCurve>),
+    Analytic(Box<AnalyticCurve>),
+    Fitting(Box<FittingCurve>),
 }
 
 impl Shape {
@@ -55,19 +92,39 @@ impl Shape {
             Shape::RationalBezier(_) => ShapeType::RationalBezier,
             Shape::Trochoid(_) => ShapeType::Trochoid,
             Shape::RegularPolygon(_) => ShapeType::RegularPolygon,
+            Shape::BSpline(_) => ShapeType::BSpline,
+            Shape::Nurbs(_) => ShapeType::Nurbs,
+            Shape::BezierSpline(_) => ShapeType::BezierSpline,
+            Shape::CubicSpline(_) => ShapeType::CubicSpline,
+            Shape::CatmullRom(_) => ShapeType::CatmullRom,
+            Shape::KochanekBartels(_) => ShapeType::KochanekBartels,
+            Shape::Hermite(_) => ShapeType::Hermite,
+            Shape::Parametric(_) => ShapeType::Parametric,
+            Shape::Analytic(_) => ShapeType::Analytic,
+            Shape::Fitting(_) => ShapeType::Fitting,
         }
     }
 }
 
 impl Update for Shape {
-    fn update(&mut self) {
+    fn update_layers(&mut self, layers: Layers) {
         match self {
-            Shape::Polyline(curve) => curve.update(),
-            Shape::Interpolation(curve) => curve.update(),
-            Shape::Bezier(curve) => curve.update(),
-            Shape::RationalBezier(curve) => curve.update(),
-            Shape::Trochoid(curve) => curve.update(),
-            Shape::RegularPolygon(shape) => shape.update(),
+            Shape::Polyline(curve) => curve.update_layers(layers),
+            Shape::Interpolation(curve) => curve.update_layers(layers),
+            Shape::Bezier(curve) => curve.update_layers(layers),
+            Shape::RationalBezier(curve) => curve.update_layers(layers),
+            Shape::Trochoid(curve) => curve.update_layers(layers),
+            Shape::RegularPolygon(shape) => shape.update_layers(layers),
+            Shape::BSpline(curve) => curve.update_layers(layers),
+            Shape::Nurbs(curve) => curve.update_layers(layers),
+            Shape::BezierSpline(curve) => curve.update_layers(layers),
+            Shape::CubicSpline(curve) => curve.update_layers(layers),
+            Shape::CatmullRom(curve) => curve.update_layers(layers),
+            Shape::KochanekBartels(curve) => curve.update_layers(layers),
+            Shape::Hermite(curve) => curve.update_layers(layers),
+            Shape::Parametric(curve) => curve.update_layers(layers),
+            Shape::Analytic(curve) => curve.update_layers(layers),
+            Shape::Fitting(curve) => curve.update_layers(layers),
         }
     }
 }
@@ -81,6 +138,16 @@ impl DrawOn for Shape {
             Shape::RationalBezier(curve) => curve.draw_on(pixmap),
             Shape::Trochoid(curve) => curve.draw_on(pixmap),
             Shape::RegularPolygon(shape) => shape.draw_on(pixmap),
+            Shape::BSpline(curve) => curve.draw_on(pixmap),
+            Shape::Nurbs(curve) => curve.draw_on(pixmap),
+            Shape::BezierSpline(curve) => curve.draw_on(pixmap),
+            Shape::CubicSpline(curve) => curve.draw_on(pixmap),
+            Shape::CatmullRom(curve) => curve.draw_on(pixmap),
+            Shape::KochanekBartels(curve) => curve.draw_on(pixmap),
+            Shape::Hermite(curve) => curve.draw_on(pixmap),
+            Shape::Parametric(curve) => curve.draw_on(pixmap),
+            Shape::Analytic(curve) => curve.draw_on(pixmap),
+            Shape::Fitting(curve) => curve.draw_on(pixmap),
         }
     }
 }
@@ -93,7 +160,17 @@ impl From<Shape> for ShapeCommonValues {
             Shape::Bezier(shape) => (*shape).into(),
             Shape::RationalBezier(shape) => (*shape).into(),
             Shape::Trochoid(shape) => (*shape).into(),
-            Shape::RegularPolygon(_) => todo!(),
+            Shape::RegularPolygon(shape) => (*shape).into(),
+            Shape::BSpline(shape) => (*shape).into(),
+            Shape::Nurbs(shape) => (*shape).into(),
+            Shape::BezierSpline(shape) => (*shape).into(),
+            Shape::CubicSpline(shape) => (*shape).into(),
+            Shape::CatmullRom(shape) => (*shape).into(),
+            Shape::KochanekBartels(shape) => (*shape).into(),
+            Shape::Hermite(shape) => (*shape).into(),
+            Shape::Parametric(shape) => (*shape).into(),
+            Shape::Analytic(shape) => (*shape).into(),
+            Shape::Fitting(shape) => (*shape).into(),
         }
     }
 }
@@ -0,0 +1,89 @@
+use std::iter;
+use std::ops::RangeInclusive;
+
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::VisualBaseLine;
+use crate::canvas::control_points::point::CurvePoint;
+use crate::canvas::control_points::ControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::b_spline::BSplineCurveProperties;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::rational_bezier::RationalBezierPoint;
+use crate::canvas::shape::shape_changer::ShapeCommonValues;
+use crate::canvas::shape::{DrawOn, Update};
+
+pub mod request;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NurbsCurve {
+    points: ControlPoints<RationalBezierPoint>,
+    control_points: VisualControlPoints,
+    base_line: VisualBaseLine<false>,
+    properties: BSplineCurveProperties,
+    samples: Samples,
+}
+
+impl NurbsCurve {
+    #[must_use]
+    pub fn new(
+        points: ControlPoints<RationalBezierPoint>,
+        control_points: VisualControlPoints,
+        base_line: VisualBaseLine<false>,
+        properties: BSplineCurveProperties,
+        samples: Samples,
+    ) -> Self {
+        Self { points, control_points, base_line, properties, samples }
+    }
+}
+
+impl Update for NurbsCurve {
+    fn update_layers(&mut self, layers: Layers) {
+        let length = self.points.length();
+        if layers.contains(Layers::CURVE) && length > 1 {
+            match curve(self.points.as_slice(), &self.properties) {
+                Some((range, curve)) => {
+                    let path = self.samples.sample(range, curve);
+                    self.base_line.rebuild_sampled_paths(path);
+                }
+                None => self.base_line.rebuild_paths(iter::empty::<CurvePoint>()),
+            }
+        }
+
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.base_line, layers);
+    }
+}
+
+/// Returns `None` if the knots do not fit the control points.
+fn curve<'a>(
+    points: &'a [RationalBezierPoint],
+    properties: &BSplineCurveProperties,
+) -> Option<(RangeInclusive<f32>, impl Fn(f32) -> CurvePoint + 'a)> {
+    let (length, degree) = (points.len(), properties.effective_degree(points.len()));
+    let knots = properties.knots.values(length, degree)?;
+    let range = knots[degree]..=knots[length];
+    Some((range, move |t| math::rational_de_boor(points, &knots, degree, t)))
+}
+
+impl DrawOn for NurbsCurve {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.base_line.draw_on(pixmap);
+        self.control_points.draw_on(pixmap);
+    }
+}
+
+impl From<NurbsCurve> for ShapeCommonValues {
+    fn from(value: NurbsCurve) -> Self {
+        Self {
+            weighted_points: Some(value.points),
+            control_points: Some(value.control_points),
+            open_base_line: Some(value.base_line),
+            b_spline_properties: Some(value.properties),
+            samples: Some(value.samples),
+            ..Default::default()
+        }
+    }
+}
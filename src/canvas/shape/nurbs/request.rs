@@ -0,0 +1,231 @@
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::b_spline::BSplineCurveProperties;
+use crate::canvas::shape::nurbs::{curve, NurbsCurve};
+use crate::canvas::shape::rational_bezier::WeightedControlPoints;
+use crate::canvas::shape::request::declare::{
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSampling, GetSplineBoundary, GetSplineDegree,
+    GetSplineParametrisation, GetTangent, GetTcbParameters, GetWeight, MoveCurve, MovePoint,
+    ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily, SetBezierAlgorithm,
+    SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes, SetJointContinuity,
+    SetKnotVector, SetOverlayParameter, SetOverlayVisibility, SetParametricConstant,
+    SetParametricExpressions, SetPlacement, SetPolygonRadius, SetPolygonVertices, SetSampling,
+    SetSplineBoundary, SetSplineDegree, SetSplineParametrisation, SetTangent, SetTcbParameters,
+    SetTrochoidProperties, SplitCurve,
+};
+use crate::canvas::shape::request::declare::{GetSamples, SetSamples};
+use crate::request::macros::delegate_requests;
+use crate::request::{
+    Error, RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
+
+delegate_requests! {
+    NurbsCurve {
+        // ControlPoints requests
+        { mut AddControlPoint => ! },
+        { mut MovePoint => WeightedControlPoints },
+        { mut RotateCurve => WeightedControlPoints },
+        { mut MoveCurve => WeightedControlPoints },
+        { mut ChangeWeight => WeightedControlPoints },
+        { GetControlPointsLength => WeightedControlPoints },
+        { GetCurveCenter => WeightedControlPoints },
+        { SelectPoint => WeightedControlPoints },
+        { GetPoint => WeightedControlPoints },
+        { GetWeight => WeightedControlPoints },
+
+        // Samples requests
+        { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
+        { GetSamples => Samples },
+        { GetSampling => Samples },
+
+        // InterpolationCurve requests
+        { mut SetInterpolationNodes => ! },
+        { GetInterpolationNodes => ! },
+
+        // TrochoidCurve requests
+        { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => BSplineCurveProperties },
+        { GetKnotVector => BSplineCurveProperties },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => ! },
+        { mut SetPlacement => ! },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetArcLength => OpenBaseLine },
+        { GetEquidistantPoints => OpenBaseLine },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => OpenBaseLine },
+        { GetCurveFeatures => OpenBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => VisualControlPoints },
+        { mut SetOverlayVisibility => VisualControlPoints },
+        { GetOverlayParameter => VisualControlPoints },
+        { mut SetOverlayParameter => VisualControlPoints },
+        { GetCombScale => VisualControlPoints },
+        { mut SetCombScale => VisualControlPoints },
+    }
+}
+
+impl RequestHandlerMut<AddWeightedControlPoint> for NurbsCurve {
+    fn handle_mut(
+        &mut self,
+        event: AddWeightedControlPoint,
+    ) -> ResponseMut<AddWeightedControlPoint> {
+        self.properties.check_length(self.points.length() + 1).map_err(Error::Other)?;
+        self.points.handle_mut(event)
+    }
+}
+
+impl RequestHandlerMut<DeletePoint> for NurbsCurve {
+    fn handle_mut(&mut self, event: DeletePoint) -> ResponseMut<DeletePoint> {
+        self.points.get(event.id).ok_or(Error::NoSuchPoint(event.id))?;
+        self.properties.check_length(self.points.length() - 1).map_err(Error::Other)?;
+        self.points.handle_mut(event)
+    }
+}
+
+impl RequestHandlerMut<SetSplineDegree> for NurbsCurve {
+    fn handle_mut(&mut self, event: SetSplineDegree) -> ResponseMut<SetSplineDegree> {
+        self.properties.set_degree(event.0, self.points.length()).map_err(Error::Other)
+    }
+}
+
+impl RequestHandlerMut<SetKnotVector> for NurbsCurve {
+    fn handle_mut(&mut self, event: SetKnotVector) -> ResponseMut<SetKnotVector> {
+        let knots = event.0;
+        self.properties.check_knots(&knots, self.points.length()).map_err(Error::Other)?;
+        self.properties.knots = knots;
+        Ok(())
+    }
+}
+
+impl RequestHandler<ProjectPoint> for NurbsCurve {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.base_line.handle(ProjectPoint::new(event.point))?;
+        Ok(match curve(self.points.as_slice(), &self.properties) {
+            Some((range, curve)) => {
+                math::projection::refine_projection(curve, range, event.point, sample.t)
+            }
+            None => sample,
+        })
+    }
+}
+
+impl RequestSubHandler<WeightedControlPoints> for NurbsCurve {
+    fn sub_handler(&self) -> &WeightedControlPoints {
+        &self.points
+    }
+}
+
+impl RequestSubHandlerMut<WeightedControlPoints> for NurbsCurve {
+    fn sub_handler_mut(&mut self) -> &mut WeightedControlPoints {
+        &mut self.points
+    }
+}
+
+impl RequestSubHandler<Samples> for NurbsCurve {
+    fn sub_handler(&self) -> &Samples {
+        &self.samples
+    }
+}
+
+impl RequestSubHandlerMut<Samples> for NurbsCurve {
+    fn sub_handler_mut(&mut self) -> &mut Samples {
+        &mut self.samples
+    }
+}
+
+impl RequestSubHandler<BSplineCurveProperties> for NurbsCurve {
+    fn sub_handler(&self) -> &BSplineCurveProperties {
+        &self.properties
+    }
+}
+
+impl RequestSubHandlerMut<BSplineCurveProperties> for NurbsCurve {
+    fn sub_handler_mut(&mut self) -> &mut BSplineCurveProperties {
+        &mut self.properties
+    }
+}
+
+impl RequestSubHandler<OpenBaseLine> for NurbsCurve {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.base_line
+    }
+}
+
+impl RequestSubHandler<VisualControlPoints> for NurbsCurve {
+    fn sub_handler(&self) -> &VisualControlPoints {
+        &self.control_points
+    }
+}
+
+impl RequestSubHandlerMut<VisualControlPoints> for NurbsCurve {
+    fn sub_handler_mut(&mut self) -> &mut VisualControlPoints {
+        &mut self.control_points
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+use std::f32::consts::TAU;
+use std::ops::RangeInclusive;
+
+use anyhow::anyhow;
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::math::expression::Expression;
+use crate::canvas::math::point::Point;
+use crate::canvas::placement::Placement;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::shape_changer::ShapeCommonValues;
+use crate::canvas::shape::{DrawOn, Update};
+use crate::config::CanvasConfig;
+
+pub mod request;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ParametricCurve {
+    base_line: OpenBaseLine,
+    properties: ParametricCurveProperties,
+    placement: Placement,
+    samples: Samples,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ParametricCurveProperties {
+    pub expressions: ParametricExpressions,
+    pub constants: BTreeMap<String, f32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, parse_display::Display)]
+#[display("\"{horizontal}\" \"{vertical}\" {range_start} {range_end}")]
+pub struct ParametricExpressions {
+    pub horizontal: Expression,
+    pub vertical: Expression,
+    pub range_start: f32,
+    pub range_end: f32,
+}
+
+pub const PARAMETER: &str = "t";
+
+impl ParametricCurve {
+    #[must_use]
+    pub fn new(
+        base_line: OpenBaseLine,
+        properties: ParametricCurveProperties,
+        placement: Placement,
+        samples: Samples,
+    ) -> Self {
+        Self { base_line, properties, placement, samples }
+    }
+}
+
+impl Update for ParametricCurve {
+    fn update_layers(&mut self, layers: Layers) {
+        if !layers.contains(Layers::CURVE) {
+            return;
+        }
+        let (range, curve) = curve(&self.properties, self.placement);
+        let path = self.samples.sample_parallel(range, || (), |(), t| curve(t));
+        self.base_line.rebuild_sampled_paths(path);
+    }
+}
+
+fn curve(
+    properties: &ParametricCurveProperties,
+    placement: Placement,
+) -> (RangeInclusive<f32>, impl Fn(f32) -> Point<f32> + Sync + '_) {
+    let ParametricExpressions { horizontal, vertical, range_start, range_end } =
+        &properties.expressions;
+    let constants = &properties.constants;
+    let curve = move |t| {
+        let variable = |name: &str| {
+            if name == PARAMETER {
+                Some(t)
+            } else {
+                constants.get(name).copied()
+            }
+        };
+        placement.apply(Point::new(horizontal.evaluate(&variable), vertical.evaluate(&variable)))
+    };
+    (*range_start..=*range_end, curve)
+}
+
+impl DrawOn for ParametricCurve {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.base_line.draw_on(pixmap);
+    }
+}
+
+impl From<ParametricCurve> for ShapeCommonValues {
+    fn from(value: ParametricCurve) -> Self {
+        Self {
+            open_base_line: Some(value.base_line),
+            parametric_properties: Some(value.properties),
+            placement: Some(value.placement),
+            samples: Some(value.samples),
+            ..Default::default()
+        }
+    }
+}
+
+impl ParametricCurveProperties {
+    #[must_use]
+    pub fn new(expressions: ParametricExpressions, constants: BTreeMap<String, f32>) -> Self {
+        Self { expressions, constants }
+    }
+
+    pub fn check_expressions(&self, expressions: &ParametricExpressions) -> anyhow::Result<()> {
+        let undefined = [&expressions.horizontal, &expressions.vertical]
+            .into_iter()
+            .flat_map(Expression::variables)
+            .filter(|&name| name != PARAMETER && !self.constants.contains_key(name))
+            .collect::<Vec<_>>();
+        if undefined.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!("undefined variables: {}", undefined.join(", ")))
+        }
+    }
+}
+
+impl ParametricExpressions {
+    #[must_use]
+    pub fn new(
+        horizontal: Expression,
+        vertical: Expression,
+        range_start: f32,
+        range_end: f32,
+    ) -> Self {
+        Self { horizontal, vertical, range_start, range_end }
+    }
+}
+
+impl From<&CanvasConfig> for ParametricCurveProperties {
+    fn from(_value: &CanvasConfig) -> Self {
+        let horizontal = "cos(t)".parse().expect("expression should be valid");
+        let vertical = "sin(t)".parse().expect("expression should be valid");
+        let expressions = ParametricExpressions::new(horizontal, vertical, 0.0, TAU);
+        Self { expressions, constants: BTreeMap::new() }
+    }
+}
@@ -0,0 +1,202 @@
+use anyhow::anyhow;
+
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::math;
+use crate::canvas::math::expression;
+use crate::canvas::placement::Placement;
+use crate::canvas::samples::Samples;
+use crate::canvas::shape::parametric::{curve, ParametricCurve, PARAMETER};
+use crate::canvas::shape::request::declare::{
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSamples, GetSampling, GetSplineBoundary,
+    GetSplineDegree, GetSplineParametrisation, GetTangent, GetTcbParameters, GetWeight, MoveCurve,
+    MovePoint, ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily,
+    SetBezierAlgorithm, SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes,
+    SetJointContinuity, SetKnotVector, SetOverlayParameter, SetOverlayVisibility,
+    SetParametricConstant, SetParametricExpressions, SetPlacement, SetPolygonRadius,
+    SetPolygonVertices, SetSamples, SetSampling, SetSplineBoundary, SetSplineDegree,
+    SetSplineParametrisation, SetTangent, SetTcbParameters, SetTrochoidProperties, SplitCurve,
+};
+use crate::request::macros::delegate_requests;
+use crate::request::{
+    Error, RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
+
+delegate_requests! {
+    ParametricCurve {
+        // ControlPoints requests
+        { mut AddControlPoint => ! },
+        { mut MovePoint => ! },
+        { mut DeletePoint => ! },
+        { mut RotateCurve => Placement },
+        { mut MoveCurve => Placement },
+        { mut ChangeWeight => ! },
+        { mut AddWeightedControlPoint => ! },
+        { GetControlPointsLength => ! },
+        { GetCurveCenter => Placement },
+        { SelectPoint => ! },
+        { GetPoint => ! },
+        { GetWeight => ! },
+
+        // Samples requests
+        { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
+        { GetSamples => Samples },
+        { GetSampling => Samples },
+
+        // InterpolationCurve requests
+        { mut SetInterpolationNodes => ! },
+        { GetInterpolationNodes => ! },
+
+        // TrochoidCurve requests
+        { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => Placement },
+        { mut SetPlacement => Placement },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetArcLength => OpenBaseLine },
+        { GetEquidistantPoints => OpenBaseLine },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => OpenBaseLine },
+        { GetCurveFeatures => OpenBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => ! },
+        { mut SetOverlayVisibility => ! },
+        { GetOverlayParameter => ! },
+        { mut SetOverlayParameter => ! },
+        { GetCombScale => ! },
+        { mut SetCombScale => ! },
+    }
+}
+
+impl RequestHandler<GetParametricExpressions> for ParametricCurve {
+    fn handle(&self, _event: GetParametricExpressions) -> Response<GetParametricExpressions> {
+        Ok(self.properties.expressions.clone())
+    }
+}
+
+impl RequestHandlerMut<SetParametricExpressions> for ParametricCurve {
+    fn handle_mut(
+        &mut self,
+        event: SetParametricExpressions,
+    ) -> ResponseMut<SetParametricExpressions> {
+        self.properties.check_expressions(&event.0).map_err(Error::Other)?;
+        self.properties.expressions = event.0;
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetParametricConstants> for ParametricCurve {
+    fn handle(&self, _event: GetParametricConstants) -> Response<GetParametricConstants> {
+        Ok(self.properties.constants.clone())
+    }
+}
+
+impl RequestHandlerMut<SetParametricConstant> for ParametricCurve {
+    fn handle_mut(&mut self, event: SetParametricConstant) -> ResponseMut<SetParametricConstant> {
+        let SetParametricConstant { name, value } = event;
+        if name == PARAMETER || !expression::is_variable_name(&name) {
+            return Err(Error::Other(anyhow!("invalid constant name: {name}")));
+        }
+        self.properties.constants.insert(name, value);
+        Ok(())
+    }
+}
+
+impl RequestHandler<ProjectPoint> for ParametricCurve {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.base_line.handle(ProjectPoint::new(event.point))?;
+        let (range, curve) = curve(&self.properties, self.placement);
+        Ok(math::projection::refine_projection(curve, range, event.point, sample.t))
+    }
+}
+
+impl RequestSubHandler<Placement> for ParametricCurve {
+    fn sub_handler(&self) -> &Placement {
+        &self.placement
+    }
+}
+
+impl RequestSubHandlerMut<Placement> for ParametricCurve {
+    fn sub_handler_mut(&mut self) -> &mut Placement {
+        &mut self.placement
+    }
+}
+
+impl RequestSubHandler<Samples> for ParametricCurve {
+    fn sub_handler(&self) -> &Samples {
+        &self.samples
+    }
+}
+
+impl RequestSubHandlerMut<Samples> for ParametricCurve {
+    fn sub_handler_mut(&mut self) -> &mut Samples {
+        &mut self.samples
+    }
+}
+
+impl RequestSubHandler<OpenBaseLine> for ParametricCurve {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.base_line
+    }
+}
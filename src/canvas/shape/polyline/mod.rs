@@ -4,6 +4,7 @@ use crate::canvas::base_line::VisualBaseLine;
 use crate::canvas::control_points::point::CurvePoint;
 use crate::canvas::control_points::ControlPoints;
 use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::shape::layers::Layers;
 use crate::canvas::shape::shape_changer::ShapeCommonValues;
 use crate::canvas::shape::{DrawOn, Update};
 
@@ -28,11 +29,14 @@ impl PolylineCurve {
 }
 
 impl Update for PolylineCurve {
-    fn update(&mut self) {
-        let points = self.points.points_iterator();
-        self.base_line.rebuild_paths(points);
+    fn update_layers(&mut self, layers: Layers) {
+        if layers.contains(Layers::CURVE) {
+            let points = self.points.points_iterator();
+            self.base_line.rebuild_paths(points);
+        }
 
-        self.control_points.rebuild_paths(&self.points);
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.base_line, layers);
     }
 }
 
@@ -1,9 +1,13 @@
 use tiny_skia::PixmapMut;
 
 use crate::canvas::base_line::VisualBaseLine;
+use crate::canvas::control_points::point::PointContainer;
 use crate::canvas::control_points::weighted::WeightedPoint;
 use crate::canvas::control_points::ControlPoints;
-use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::control_points_curve::{Overlay, VisualControlPoints};
+use crate::canvas::math::point::Point;
+use crate::canvas::math::projection::Projection;
+use crate::canvas::shape::layers::Layers;
 use crate::canvas::shape::shape_changer::ShapeCommonValues;
 use crate::canvas::shape::{DrawOn, Update};
 use crate::config::CanvasConfig;
@@ -43,30 +47,121 @@ impl RationalBezierCurve {
     ) -> Self {
         Self { points, control_points, base_line, properties, samples }
     }
+
+    /// Both parts keep the degree of the curve.
+    #[must_use]
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let (first, second) = math::rational_de_casteljau_split(self.points.as_slice(), t);
+        let with_points = |points| {
+            let mut part = Self { points: ControlPoints::new(points), ..self.clone() };
+            part.update();
+            part
+        };
+        (with_points(first), with_points(second))
+    }
+
+    pub fn elevate_degree(&mut self, times: usize) {
+        let mut points = self.points.as_slice().to_vec();
+        for _ in 0..times {
+            points = math::bezier_degree::rational_elevate(&points);
+        }
+        self.points = ControlPoints::new(points);
+    }
+
+    /// Returns the largest distance between the original and the reduced curve.
+    pub fn reduce_degree(&mut self) -> Option<f32> {
+        let (points, error) = math::bezier_degree::rational_reduce(self.points.as_slice())?;
+        self.points = ControlPoints::new(points);
+        Some(error)
+    }
+
+    /// Returns `None` if the hodograph cannot be represented with positive weights.
+    #[must_use]
+    pub fn hodograph(&self) -> Option<Self> {
+        let points = ControlPoints::new(self.hodograph_points()?);
+        let mut hodograph = Self { points, ..self.clone() };
+        hodograph.update();
+        Some(hodograph)
+    }
+
+    fn hodograph_points(&self) -> Option<Vec<RationalBezierPoint>> {
+        let points = self.points.as_slice();
+        let origin = points.first()?.into_point() - Point::zero();
+        let hodograph = math::rational_hodograph(points)?
+            .into_iter()
+            .map(|point| RationalBezierPoint::new(point.into_point() + origin, point.weight()));
+        Some(hodograph.collect())
+    }
+
+    /// Returns `None` if derivatives cannot be represented with positive weights.
+    #[must_use]
+    pub fn project(&self, point: Point<f32>, start: f32) -> Option<Projection> {
+        let points = self.points.as_slice();
+        let first = math::rational_hodograph(points)?;
+        let second = math::rational_hodograph(&first)?;
+        Some(math::projection::newton_projection(
+            |t| math::rational_de_casteljau(points, t),
+            |t| math::rational_de_casteljau(&first, t) - Point::zero(),
+            |t| math::rational_de_casteljau(&second, t) - Point::zero(),
+            0.0..=1.0,
+            point,
+            start,
+        ))
+    }
+
+    #[must_use]
+    pub fn nearest_parameter(&self, point: Point<f32>) -> f32 {
+        let points = self.points.as_slice();
+        math::nearest_parameter(
+            |t| math::rational_de_casteljau(points, t),
+            point,
+            self.samples.samples(),
+        )
+    }
 }
 pub type WeightedControlPoints = ControlPoints<RationalBezierPoint>;
 pub type RationalBezierWeight = f32;
 pub type RationalBezierPoint = WeightedPoint<f32, RationalBezierWeight>;
 
 impl Update for RationalBezierCurve {
-    fn update(&mut self) {
-        if self.points.length() > 1 {
+    fn update_layers(&mut self, layers: Layers) {
+        if layers.contains(Layers::CURVE) && self.points.length() > 1 {
             let points = self.points.as_slice();
 
-            let path = self.samples.equally_spaced(0.0..=1.0);
+            let samples = self.samples;
             match self.properties.algorithm {
                 RationalBezierCurveAlgorithm::DeCasteljau => {
-                    self.base_line
-                        .rebuild_paths(path.map(|t| math::rational_de_casteljau(points, t)));
+                    let path = samples.sample_parallel(
+                        0.0..=1.0,
+                        || Vec::with_capacity(points.len()),
+                        |buffer, t| math::rational_de_casteljau_in(points, t, buffer),
+                    );
+                    self.base_line.rebuild_sampled_paths(path);
                 }
                 RationalBezierCurveAlgorithm::ChudyWozny => {
-                    self.base_line
-                        .rebuild_paths(path.map(|t| math::rational_chudy_wozny(points, t)));
+                    let path = samples.sample_parallel(
+                        0.0..=1.0,
+                        || (),
+                        |(), t| math::rational_chudy_wozny(points, t),
+                    );
+                    self.base_line.rebuild_sampled_paths(path);
                 }
             };
         }
 
-        self.control_points.rebuild_paths(&self.points);
+        self.control_points.rebuild_paths(&self.points, layers);
+        self.control_points.rebuild_overlays(&self.base_line, layers);
+        if layers.intersects(Layers::CURVE | Layers::OVERLAYS)
+            && self.control_points.is_overlay_visible(Overlay::Hodograph)
+        {
+            if let Some(hodograph) = self.hodograph_points() {
+                let mut buffer = Vec::with_capacity(hodograph.len());
+                let path = self.samples.sample(0.0..=1.0, |t| {
+                    math::rational_de_casteljau_in(&hodograph, t, &mut buffer)
+                });
+                self.control_points.rebuild_hodograph(path.map(|(_, point)| point));
+            }
+        }
     }
 }
 
@@ -102,3 +197,39 @@ impl From<&CanvasConfig> for RationalBezierCurveProperties {
         Self { algorithm: value.default_rational_bezier_algorithm }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::math::vector::Vector;
+    use crate::canvas::shape::request::declare::ChangeWeight;
+    use crate::canvas::shape::Shape;
+    use crate::request::RequestHandlerMut;
+
+    #[test]
+    fn change_weight_leaves_convex_hull_untouched() {
+        let points = [(0.0, 0.0), (30.0, 90.0), (70.0, -90.0), (100.0, 0.0)];
+        let points = points.map(|(x, y)| RationalBezierPoint::new(Point::new(x, y), 1.0));
+        let config = CanvasConfig::default();
+        let mut curve = RationalBezierCurve::new(
+            ControlPoints::new(points.to_vec()),
+            (&config).into(),
+            (&config).into(),
+            RationalBezierCurveProperties::default(),
+            Samples::new(50),
+        );
+        curve.update();
+        let hull = curve.control_points.convex_hull_points().to_vec();
+        let path = curve.base_line.path_points();
+
+        // Hull is rebuilt only from the control points, so moving one without rebuilding any
+        // layer shows whether the request touched it.
+        curve.points.shift(1, Vector::new(0.0, 110.0)).unwrap();
+        let mut shape = Shape::RationalBezier(Box::new(curve));
+        shape.handle_mut(ChangeWeight::new(1, 3.0)).unwrap();
+        let Shape::RationalBezier(curve) = shape else { unreachable!() };
+
+        assert_eq!(curve.control_points.convex_hull_points(), hull);
+        assert_ne!(curve.base_line.path_points(), path);
+    }
+}
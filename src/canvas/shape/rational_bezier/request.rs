@@ -1,13 +1,36 @@
+use anyhow::anyhow;
+
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math::bezier_degree::MAX_DEGREE;
 use crate::canvas::samples::Samples;
-use crate::canvas::shape::rational_bezier::{RationalBezierCurve, WeightedControlPoints};
+use crate::canvas::shape::bezier::BezierCurveAlgorithm;
+use crate::canvas::shape::rational_bezier::{
+    RationalBezierCurve, RationalBezierCurveAlgorithm, WeightedControlPoints,
+};
 use crate::canvas::shape::request::declare::{
-    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, GetControlPointsLength,
-    GetCurveCenter, GetInterpolationNodes, GetPoint, GetWeight, MoveCurve, MovePoint, RotateCurve,
-    SelectPoint, SetInterpolationNodes, SetTrochoidProperties,
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSampling, GetSplineBoundary, GetSplineDegree,
+    GetSplineParametrisation, GetTangent, GetTcbParameters, GetWeight, MoveCurve, MovePoint,
+    ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily, SetBezierAlgorithm,
+    SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes, SetJointContinuity,
+    SetKnotVector, SetOverlayParameter, SetOverlayVisibility, SetParametricConstant,
+    SetParametricExpressions, SetPlacement, SetPolygonRadius, SetPolygonVertices, SetSampling,
+    SetSplineBoundary, SetSplineDegree, SetSplineParametrisation, SetTangent, SetTcbParameters,
+    SetTrochoidProperties, SplitCurve,
 };
 use crate::canvas::shape::request::declare::{GetSamples, SetSamples};
+use crate::canvas::shape::Shape;
 use crate::request::macros::delegate_requests;
-use crate::request::{RequestSubHandler, RequestSubHandlerMut};
+use crate::request::{
+    Error, RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
 
 delegate_requests! {
     RationalBezierCurve {
@@ -27,7 +50,9 @@ delegate_requests! {
 
         // Samples requests
         { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
         { GetSamples => Samples },
+        { GetSampling => Samples },
 
         // InterpolationCurve requests
         { mut SetInterpolationNodes => ! },
@@ -35,6 +60,73 @@ delegate_requests! {
 
         // TrochoidCurve requests
         { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => ! },
+        { mut SetPlacement => ! },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // Length requests
+        { GetArcLength => OpenBaseLine },
+        { GetEquidistantPoints => OpenBaseLine },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => OpenBaseLine },
+        { GetCurveFeatures => OpenBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => VisualControlPoints },
+        { mut SetOverlayVisibility => VisualControlPoints },
+        { GetOverlayParameter => VisualControlPoints },
+        { mut SetOverlayParameter => VisualControlPoints },
+        { GetCombScale => VisualControlPoints },
+        { mut SetCombScale => VisualControlPoints },
     }
 }
 
@@ -61,3 +153,102 @@ impl RequestSubHandlerMut<Samples> for RationalBezierCurve {
         &mut self.samples
     }
 }
+
+impl RequestHandler<SplitCurve> for RationalBezierCurve {
+    fn handle(&self, event: SplitCurve) -> Response<SplitCurve> {
+        if self.points.length() < 2 {
+            return Err(Error::Other(anyhow!("curve needs at least two control points to split")));
+        }
+        let (first, second) = self.split(event.t.clamp(0.0, 1.0));
+        Ok((Shape::RationalBezier(Box::new(first)), Shape::RationalBezier(Box::new(second))))
+    }
+}
+
+impl RequestHandler<GetHodograph> for RationalBezierCurve {
+    fn handle(&self, _event: GetHodograph) -> Response<GetHodograph> {
+        if self.points.length() < 2 {
+            return Err(Error::Other(anyhow!("curve needs at least two control points")));
+        }
+        let hodograph = self.hodograph().ok_or_else(|| {
+            Error::Other(anyhow!("hodograph of the curve needs positive weights"))
+        })?;
+        Ok(Shape::RationalBezier(Box::new(hodograph)))
+    }
+}
+
+impl RequestHandler<GetNearestParameter> for RationalBezierCurve {
+    fn handle(&self, event: GetNearestParameter) -> Response<GetNearestParameter> {
+        if self.points.length() < 2 {
+            return Err(Error::Other(anyhow!("curve needs at least two control points")));
+        }
+        Ok(self.nearest_parameter(event.point))
+    }
+}
+
+impl RequestHandlerMut<ElevateDegree> for RationalBezierCurve {
+    fn handle_mut(&mut self, event: ElevateDegree) -> ResponseMut<ElevateDegree> {
+        if self.points.length() < 2 {
+            return Err(Error::Other(anyhow!("curve needs at least two control points")));
+        }
+        if event.0 > MAX_DEGREE.saturating_sub(self.points.length() - 1) {
+            return Err(Error::Other(anyhow!("degree of the curve cannot exceed {MAX_DEGREE}")));
+        }
+        self.elevate_degree(event.0);
+        Ok(())
+    }
+}
+
+impl RequestHandlerMut<ReduceDegree> for RationalBezierCurve {
+    fn handle_mut(&mut self, _event: ReduceDegree) -> ResponseMut<ReduceDegree> {
+        self.reduce_degree()
+            .ok_or_else(|| Error::Other(anyhow!("degree of the curve cannot be reduced")))
+    }
+}
+
+impl RequestHandler<GetBezierAlgorithm> for RationalBezierCurve {
+    fn handle(&self, _event: GetBezierAlgorithm) -> Response<GetBezierAlgorithm> {
+        let algorithm = match self.properties.algorithm {
+            RationalBezierCurveAlgorithm::DeCasteljau => BezierCurveAlgorithm::DeCasteljau,
+            RationalBezierCurveAlgorithm::ChudyWozny => BezierCurveAlgorithm::ChudyWozny,
+        };
+        Ok(algorithm)
+    }
+}
+
+impl RequestHandlerMut<SetBezierAlgorithm> for RationalBezierCurve {
+    fn handle_mut(&mut self, event: SetBezierAlgorithm) -> ResponseMut<SetBezierAlgorithm> {
+        self.properties.algorithm = match event.0 {
+            BezierCurveAlgorithm::DeCasteljau => RationalBezierCurveAlgorithm::DeCasteljau,
+            BezierCurveAlgorithm::ChudyWozny => RationalBezierCurveAlgorithm::ChudyWozny,
+        };
+        Ok(())
+    }
+}
+
+impl RequestHandler<ProjectPoint> for RationalBezierCurve {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.base_line.handle(ProjectPoint::new(event.point))?;
+        if self.points.length() < 2 {
+            return Ok(sample);
+        }
+        Ok(self.project(event.point, sample.t).unwrap_or(sample))
+    }
+}
+
+impl RequestSubHandler<OpenBaseLine> for RationalBezierCurve {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.base_line
+    }
+}
+
+impl RequestSubHandler<VisualControlPoints> for RationalBezierCurve {
+    fn sub_handler(&self) -> &VisualControlPoints {
+        &self.control_points
+    }
+}
+
+impl RequestSubHandlerMut<VisualControlPoints> for RationalBezierCurve {
+    fn sub_handler_mut(&mut self) -> &mut VisualControlPoints {
+        &mut self.control_points
+    }
+}
@@ -0,0 +1,130 @@
+use std::f32::consts::PI;
+
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::ClosedBaseLine;
+use crate::canvas::control_points::point::CurveControlPoints;
+use crate::canvas::control_points::ControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::math::point::Point;
+use crate::canvas::polygon::Polygon;
+use crate::canvas::shape::layers::Layers;
+use crate::canvas::shape::shape_changer::ShapeCommonValues;
+use crate::canvas::shape::{DrawOn, Update};
+use crate::config::CanvasConfig;
+
+pub mod request;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct RegularPolygon {
+    polygon: Polygon,
+    properties: RegularPolygonProperties,
+}
+
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RegularPolygonProperties {
+    pub center: Point<f32>,
+    pub radius: f32,
+    pub vertices: u32,
+    /// Measured in radians from the vertical axis.
+    pub rotation: f32,
+}
+
+impl RegularPolygon {
+    #[must_use]
+    pub fn new(
+        control_points: VisualControlPoints,
+        base_line: ClosedBaseLine,
+        properties: RegularPolygonProperties,
+    ) -> Self {
+        let points = Self::create_regular_polygon_points(properties);
+        let polygon = Polygon::new(points, control_points, base_line);
+        Self { polygon, properties }
+    }
+
+    fn create_regular_polygon_points(properties: RegularPolygonProperties) -> CurveControlPoints {
+        let RegularPolygonProperties { center, radius, vertices, rotation } = properties;
+        let angle_delta = (2.0 * PI) / vertices as f32;
+        let points = (0..vertices)
+            .map(|index| {
+                let angle = rotation + index as f32 * angle_delta;
+                let x = radius * f32::sin(angle);
+                let y = radius * f32::cos(angle);
+                Point::new(x, y) + center.into_vector(Point::zero())
+            })
+            .collect();
+        ControlPoints::new(points)
+    }
+}
+
+impl Update for RegularPolygon {
+    fn update_layers(&mut self, layers: Layers) {
+        self.polygon.points = Self::create_regular_polygon_points(self.properties);
+        self.polygon.update_layers(layers);
+    }
+}
+
+impl DrawOn for RegularPolygon {
+    fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        self.polygon.draw_on(pixmap);
+    }
+}
+
+impl From<RegularPolygon> for ShapeCommonValues {
+    fn from(value: RegularPolygon) -> Self {
+        let Polygon { points, control_points, base_line } = value.polygon;
+        Self {
+            points: Some(points),
+            control_points: Some(control_points),
+            closed_base_line: Some(base_line),
+            regular_polygon_properties: Some(value.properties),
+            ..Default::default()
+        }
+    }
+}
+
+impl RegularPolygonProperties {
+    #[must_use]
+    pub fn new(center: Point<f32>, radius: f32, vertices: u32, rotation: f32) -> Self {
+        Self { center, radius, vertices, rotation }
+    }
+
+    /// Circumscribed around the center of mass of the points, with a vertex at the first one.
+    #[must_use]
+    pub fn from_vertices(points: &CurveControlPoints) -> Option<Self> {
+        let vertices = u32::try_from(points.length()).ok().filter(|&vertices| vertices >= 3)?;
+        let center = points.center_of_mass()?;
+        let first_point = *points.get(0)?;
+        let radius = center.distance_squared(first_point).sqrt();
+        let first = first_point - center;
+        let rotation = f32::atan2(first.horizontal(), first.vertical());
+        Some(Self { center, radius, vertices, rotation })
+    }
+}
+
+impl From<&CanvasConfig> for RegularPolygonProperties {
+    fn from(value: &CanvasConfig) -> Self {
+        Self {
+            center: value.default_regular_polygon_center,
+            radius: value.default_regular_polygon_radius,
+            vertices: value.default_regular_polygon_vertices,
+            rotation: 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_vertices_recovers_properties() {
+        let properties = RegularPolygonProperties::new(Point::new(10.0, -5.0), 3.0, 5, 0.5);
+        let points = RegularPolygon::create_regular_polygon_points(properties);
+        let recovered = RegularPolygonProperties::from_vertices(&points).unwrap();
+        assert_eq!(recovered.vertices, 5);
+        assert!((recovered.radius - 3.0).abs() < 1e-4);
+        assert!((recovered.rotation - 0.5).abs() < 1e-4);
+        assert!(recovered.center.distance_squared(properties.center) < 1e-8);
+    }
+}
@@ -0,0 +1,205 @@
+use anyhow::anyhow;
+
+use crate::canvas::base_line::ClosedBaseLine;
+use crate::canvas::control_points::point::CurveControlPoints;
+use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::shape::regular_polygon::RegularPolygon;
+use crate::canvas::shape::request::declare::{
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSampling, GetSplineBoundary, GetSplineDegree,
+    GetSplineParametrisation, GetTangent, GetTcbParameters, GetWeight, MoveCurve, MovePoint,
+    ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily, SetBezierAlgorithm,
+    SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes, SetJointContinuity,
+    SetKnotVector, SetOverlayParameter, SetOverlayVisibility, SetParametricConstant,
+    SetParametricExpressions, SetPlacement, SetPolygonRadius, SetPolygonVertices, SetSampling,
+    SetSplineBoundary, SetSplineDegree, SetSplineParametrisation, SetTangent, SetTcbParameters,
+    SetTrochoidProperties, SplitCurve,
+};
+use crate::canvas::shape::request::declare::{GetSamples, SetSamples};
+use crate::request::macros::delegate_requests;
+use crate::request::{
+    Error, RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
+
+delegate_requests! {
+    RegularPolygon {
+        // ControlPoints requests
+        { mut AddControlPoint => ! },
+        { mut MovePoint => ! },
+        { mut DeletePoint => ! },
+        { mut ChangeWeight => ! },
+        { mut AddWeightedControlPoint => ! },
+        { GetControlPointsLength => CurveControlPoints },
+        { SelectPoint => CurveControlPoints },
+        { GetPoint => CurveControlPoints },
+        { GetWeight => ! },
+
+        // Samples requests
+        { mut SetSamples => ! },
+        { mut SetSampling => ! },
+        { GetSamples => ! },
+        { GetSampling => ! },
+
+        // InterpolationCurve requests
+        { mut SetInterpolationNodes => ! },
+        { GetInterpolationNodes => ! },
+
+        // TrochoidCurve requests
+        { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // Placement requests
+        { GetPlacement => ! },
+        { mut SetPlacement => ! },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetArcLength => ClosedBaseLine },
+        { GetEquidistantPoints => ClosedBaseLine },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => ClosedBaseLine },
+        { ProjectPoint => ClosedBaseLine },
+        { GetCurveFeatures => ClosedBaseLine },
+
+        // Overlay requests
+        { GetOverlayVisibility => VisualControlPoints },
+        { mut SetOverlayVisibility => VisualControlPoints },
+        { GetOverlayParameter => VisualControlPoints },
+        { mut SetOverlayParameter => VisualControlPoints },
+        { GetCombScale => VisualControlPoints },
+        { mut SetCombScale => VisualControlPoints },
+    }
+}
+
+impl RequestHandlerMut<MoveCurve> for RegularPolygon {
+    fn handle_mut(&mut self, event: MoveCurve) -> ResponseMut<MoveCurve> {
+        self.properties.center = self.properties.center + event.shift;
+        Ok(())
+    }
+}
+
+impl RequestHandlerMut<RotateCurve> for RegularPolygon {
+    fn handle_mut(&mut self, event: RotateCurve) -> ResponseMut<RotateCurve> {
+        // Vertices are laid out clockwise from the vertical axis, so the rotation angle has to be
+        // subtracted to match rotation of control points of other curves.
+        self.properties.rotation -= event.angle;
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetCurveCenter> for RegularPolygon {
+    fn handle(&self, _event: GetCurveCenter) -> Response<GetCurveCenter> {
+        Ok(Some(self.properties.center))
+    }
+}
+
+impl RequestHandler<GetPolygonVertices> for RegularPolygon {
+    fn handle(&self, _event: GetPolygonVertices) -> Response<GetPolygonVertices> {
+        Ok(self.properties.vertices)
+    }
+}
+
+impl RequestHandlerMut<SetPolygonVertices> for RegularPolygon {
+    fn handle_mut(&mut self, event: SetPolygonVertices) -> ResponseMut<SetPolygonVertices> {
+        if event.0 < 3 {
+            return Err(Error::Other(anyhow!("polygon should have at least 3 vertices")));
+        }
+        self.properties.vertices = event.0;
+        Ok(())
+    }
+}
+
+impl RequestHandler<GetPolygonRadius> for RegularPolygon {
+    fn handle(&self, _event: GetPolygonRadius) -> Response<GetPolygonRadius> {
+        Ok(self.properties.radius)
+    }
+}
+
+impl RequestHandlerMut<SetPolygonRadius> for RegularPolygon {
+    fn handle_mut(&mut self, event: SetPolygonRadius) -> ResponseMut<SetPolygonRadius> {
+        if event.0 <= 0.0 {
+            return Err(Error::Other(anyhow!("polygon radius should be positive")));
+        }
+        self.properties.radius = event.0;
+        Ok(())
+    }
+}
+
+impl RequestSubHandler<CurveControlPoints> for RegularPolygon {
+    fn sub_handler(&self) -> &CurveControlPoints {
+        &self.polygon.points
+    }
+}
+
+impl RequestSubHandler<ClosedBaseLine> for RegularPolygon {
+    fn sub_handler(&self) -> &ClosedBaseLine {
+        &self.polygon.base_line
+    }
+}
+
+impl RequestSubHandler<VisualControlPoints> for RegularPolygon {
+    fn sub_handler(&self) -> &VisualControlPoints {
+        &self.polygon.control_points
+    }
+}
+
+impl RequestSubHandlerMut<VisualControlPoints> for RegularPolygon {
+    fn sub_handler_mut(&mut self) -> &mut VisualControlPoints {
+        &mut self.polygon.control_points
+    }
+}
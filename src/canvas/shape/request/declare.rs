@@ -1,8 +1,25 @@
+use std::collections::BTreeMap;
+
+use crate::canvas::control_points::tcb::TcbParameters;
 use crate::canvas::control_points::weighted::WeightedPoint;
+use crate::canvas::control_points_curve::Overlay;
+use crate::canvas::math::curve_features::CurveFeatures;
 use crate::canvas::math::point::Point;
+use crate::canvas::math::projection::Projection;
 use crate::canvas::math::vector::Vector;
+use crate::canvas::placement::Placement;
+use crate::canvas::samples::Sampling;
+use crate::canvas::shape::analytic::family::AnalyticFamily;
+use crate::canvas::shape::b_spline::KnotVector;
+use crate::canvas::shape::bezier::BezierCurveAlgorithm;
+use crate::canvas::shape::bezier_spline::JointContinuity;
+use crate::canvas::shape::cubic_spline::{SplineBoundary, SplineParametrisation};
+use crate::canvas::shape::fitting::FittingBasis;
 use crate::canvas::shape::interpolation::InterpolationNodes;
+use crate::canvas::shape::layers::{invalidates, Layers};
+use crate::canvas::shape::parametric::ParametricExpressions;
 use crate::canvas::shape::trochoid::TrochoidCurveProperties;
+use crate::canvas::shape::Shape;
 use crate::request::macros::declare_requests;
 use crate::request::PointId;
 
@@ -24,6 +41,8 @@ declare_requests! {
     // Samples requests
     { mut SetSamples (u32) -> () },
     { GetSamples () -> u32 },
+    { mut SetSampling (Sampling) -> () },
+    { GetSampling () -> Sampling },
 
     // InterpolationCurve requests
     { GetInterpolationNodes () -> InterpolationNodes },
@@ -31,4 +50,119 @@ declare_requests! {
 
     // TrochoidCurve requests
     { mut SetTrochoidProperties (TrochoidCurveProperties) -> () },
+
+    // BSplineCurve requests
+    { GetSplineDegree () -> usize },
+    { mut SetSplineDegree (usize) -> () },
+    { GetKnotVector () -> KnotVector },
+    { mut SetKnotVector (KnotVector) -> () },
+
+    // BezierSpline requests
+    { GetJointContinuity { joint: usize } -> JointContinuity },
+    { mut SetJointContinuity { joint: Option<usize>, continuity: JointContinuity } -> () },
+
+    // CubicSplineCurve requests
+    { GetSplineBoundary () -> SplineBoundary },
+    { mut SetSplineBoundary (SplineBoundary) -> () },
+    { GetSplineParametrisation () -> SplineParametrisation },
+    { mut SetSplineParametrisation (SplineParametrisation) -> () },
+
+    // KochanekBartelsCurve requests
+    { GetTcbParameters { id: PointId } -> TcbParameters },
+    { mut SetTcbParameters { id: Option<PointId>, parameters: TcbParameters } -> () },
+
+    // HermiteCurve requests
+    { GetTangent { id: PointId } -> Vector<f32> },
+    { mut SetTangent { id: PointId, tangent: Vector<f32> } -> () },
+
+    // RegularPolygon requests
+    { GetPolygonVertices () -> u32 },
+    { mut SetPolygonVertices (u32) -> () },
+    { GetPolygonRadius () -> f32 },
+    { mut SetPolygonRadius (f32) -> () },
+
+    // Placement requests
+    { GetPlacement () -> Placement },
+    { mut SetPlacement (Placement) -> () },
+
+    // ParametricCurve requests
+    { GetParametricExpressions () -> ParametricExpressions },
+    { mut SetParametricExpressions (ParametricExpressions) -> () },
+    { GetParametricConstants () -> BTreeMap<String, f32> },
+    { mut SetParametricConstant { name: String, value: f32 } -> () },
+
+    // AnalyticCurve requests
+    { GetAnalyticFamily () -> AnalyticFamily },
+    { mut SetAnalyticFamily (AnalyticFamily) -> () },
+
+    // FittingCurve requests
+    { GetFittingBasis () -> FittingBasis },
+    { mut SetFittingBasis (FittingBasis) -> () },
+    { GetFittingDegree () -> usize },
+    { mut SetFittingDegree (usize) -> () },
+
+    // BezierCurve requests
+    { SplitCurve { t: f32 } -> (Shape, Shape) },
+    { GetHodograph () -> Shape },
+    { GetNearestParameter { point: Point<f32> } -> f32 },
+    { mut ElevateDegree (usize) -> () },
+    { mut ReduceDegree () -> f32 },
+    { GetBezierAlgorithm () -> BezierCurveAlgorithm },
+    { mut SetBezierAlgorithm (BezierCurveAlgorithm) -> () },
+
+    // Length requests
+    { GetArcLength () -> f32 },
+    { GetEquidistantPoints { count: usize } -> Vec<Point<f32>> },
+
+    // Geometry requests
+    { GetBezierSegments () -> Vec<Vec<Point<f32>>> },
+    { GetSampledPath () -> Vec<Point<f32>> },
+    { GetCurveFeatures () -> CurveFeatures },
+    { ProjectPoint { point: Point<f32> } -> Projection },
+
+    // Overlay requests
+    { GetOverlayVisibility (Overlay) -> bool },
+    { mut SetOverlayVisibility (Overlay, bool) -> () },
+    { GetOverlayParameter () -> f32 },
+    { mut SetOverlayParameter (f32) -> () },
+    { GetCombScale () -> f32 },
+    { mut SetCombScale (f32) -> () },
+}
+
+invalidates! {
+    Layers::ALL => [
+        AddControlPoint,
+        MovePoint,
+        DeletePoint,
+        RotateCurve,
+        MoveCurve,
+        AddWeightedControlPoint,
+        SetJointContinuity,
+        SetPolygonVertices,
+        SetPolygonRadius,
+        ElevateDegree,
+        ReduceDegree,
+    ],
+    // Weights, tangents and properties of curves do not move control points.
+    Layers::CURVE => [
+        ChangeWeight,
+        SetSamples,
+        SetSampling,
+        SetInterpolationNodes,
+        SetTrochoidProperties,
+        SetSplineDegree,
+        SetKnotVector,
+        SetSplineBoundary,
+        SetSplineParametrisation,
+        SetTcbParameters,
+        SetPlacement,
+        SetParametricExpressions,
+        SetParametricConstant,
+        SetAnalyticFamily,
+        SetFittingBasis,
+        SetFittingDegree,
+        SetBezierAlgorithm,
+    ],
+    Layers::CURVE.union(Layers::CONTROL_POINTS) => [SetTangent],
+    Layers::OVERLAYS => [SetOverlayVisibility, SetOverlayParameter, SetCombScale],
 }
@@ -1,10 +1,21 @@
+use crate::canvas::shape::analytic::AnalyticCurve;
+use crate::canvas::shape::b_spline::BSplineCurve;
 use crate::canvas::shape::bezier::BezierCurve;
+use crate::canvas::shape::bezier_spline::BezierSpline;
+use crate::canvas::shape::catmull_rom::CatmullRomCurve;
+use crate::canvas::shape::cubic_spline::CubicSplineCurve;
+use crate::canvas::shape::fitting::FittingCurve;
+use crate::canvas::shape::hermite::HermiteCurve;
 use crate::canvas::shape::interpolation::InterpolationCurve;
+use crate::canvas::shape::kochanek_bartels::KochanekBartelsCurve;
+use crate::canvas::shape::layers::Invalidates;
+use crate::canvas::shape::nurbs::NurbsCurve;
+use crate::canvas::shape::parametric::ParametricCurve;
 use crate::canvas::shape::polyline::PolylineCurve;
 use crate::canvas::shape::rational_bezier::RationalBezierCurve;
 use crate::canvas::shape::regular_polygon::RegularPolygon;
 use crate::canvas::shape::request::sieve::{
-    ExcludeAllRequests, ExcludeControlPointsRequests, ExcludeInterpolationRequests,
+    ExcludeControlPointsRequests, ExcludeInterpolationRequests,
 };
 use crate::canvas::shape::trochoid::TrochoidCurve;
 use crate::canvas::shape::{Shape, Update};
@@ -24,7 +35,17 @@ where
     RationalBezierCurve: RequestHandler<T>,
     for<'a> ExcludeInterpolationRequests<ExcludeControlPointsRequests<&'a TrochoidCurve>>:
         RequestHandler<T>,
-    for<'a> ExcludeAllRequests<&'a RegularPolygon>: RequestHandler<T>,
+    RegularPolygon: RequestHandler<T>,
+    BSplineCurve: RequestHandler<T>,
+    NurbsCurve: RequestHandler<T>,
+    BezierSpline: RequestHandler<T>,
+    CubicSplineCurve: RequestHandler<T>,
+    CatmullRomCurve: RequestHandler<T>,
+    KochanekBartelsCurve: RequestHandler<T>,
+    HermiteCurve: RequestHandler<T>,
+    ParametricCurve: RequestHandler<T>,
+    AnalyticCurve: RequestHandler<T>,
+    FittingCurve: RequestHandler<T>,
 {
     fn handle(&self, request: T) -> Response<T> {
         match self {
@@ -36,21 +57,41 @@ where
                 ExcludeInterpolationRequests::new(ExcludeControlPointsRequests::new(curve.as_ref()))
                     .handle(request)
             }
-            Shape::RegularPolygon(shape) => ExcludeAllRequests::new(shape.as_ref()).handle(request),
+            Shape::RegularPolygon(shape) => shape.handle(request),
+            Shape::BSpline(curve) => curve.handle(request),
+            Shape::Nurbs(curve) => curve.handle(request),
+            Shape::BezierSpline(curve) => curve.handle(request),
+            Shape::CubicSpline(curve) => curve.handle(request),
+            Shape::CatmullRom(curve) => curve.handle(request),
+            Shape::KochanekBartels(curve) => curve.handle(request),
+            Shape::Hermite(curve) => curve.handle(request),
+            Shape::Parametric(curve) => curve.handle(request),
+            Shape::Analytic(curve) => curve.handle(request),
+            Shape::Fitting(curve) => curve.handle(request),
         }
     }
 }
 
 impl<T> RequestHandlerMut<T> for Shape
 where
-    T: RequestMut,
+    T: RequestMut + Invalidates,
     PolylineCurve: RequestHandlerMut<T>,
     InterpolationCurve: RequestHandlerMut<T>,
     BezierCurve: RequestHandlerMut<T>,
     RationalBezierCurve: RequestHandlerMut<T>,
     for<'a> ExcludeInterpolationRequests<ExcludeControlPointsRequests<&'a mut TrochoidCurve>>:
         RequestHandlerMut<T>,
-    for<'a> ExcludeAllRequests<&'a mut RegularPolygon>: RequestHandlerMut<T>,
+    RegularPolygon: RequestHandlerMut<T>,
+    BSplineCurve: RequestHandlerMut<T>,
+    NurbsCurve: RequestHandlerMut<T>,
+    BezierSpline: RequestHandlerMut<T>,
+    CubicSplineCurve: RequestHandlerMut<T>,
+    CatmullRomCurve: RequestHandlerMut<T>,
+    KochanekBartelsCurve: RequestHandlerMut<T>,
+    HermiteCurve: RequestHandlerMut<T>,
+    ParametricCurve: RequestHandlerMut<T>,
+    AnalyticCurve: RequestHandlerMut<T>,
+    FittingCurve: RequestHandlerMut<T>,
 {
     fn handle_mut(&mut self, request: T) -> ResponseMut<T> {
         let result = match self {
@@ -62,13 +103,21 @@ where
                 ExcludeInterpolationRequests::new(ExcludeControlPointsRequests::new(curve.as_mut()))
                     .handle_mut(request)
             }
-            Shape::RegularPolygon(shape) => {
-                ExcludeAllRequests::new(shape.as_mut()).handle_mut(request)
-            }
+            Shape::RegularPolygon(shape) => shape.handle_mut(request),
+            Shape::BSpline(curve) => curve.handle_mut(request),
+            Shape::Nurbs(curve) => curve.handle_mut(request),
+            Shape::BezierSpline(curve) => curve.handle_mut(request),
+            Shape::CubicSpline(curve) => curve.handle_mut(request),
+            Shape::CatmullRom(curve) => curve.handle_mut(request),
+            Shape::KochanekBartels(curve) => curve.handle_mut(request),
+            Shape::Hermite(curve) => curve.handle_mut(request),
+            Shape::Parametric(curve) => curve.handle_mut(request),
+            Shape::Analytic(curve) => curve.handle_mut(request),
+            Shape::Fitting(curve) => curve.handle_mut(request),
         };
 
         if result.is_ok() {
-            self.update();
+            self.update_layers(T::LAYERS);
         }
 
         result
@@ -1,7 +1,18 @@
 use crate::canvas::shape::request::declare::{
-    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, GetControlPointsLength,
-    GetCurveCenter, GetInterpolationNodes, GetPoint, GetSamples, GetWeight, MoveCurve, MovePoint,
-    RotateCurve, SelectPoint, SetInterpolationNodes, SetSamples, SetTrochoidProperties,
+    AddControlPoint, AddWeightedControlPoint, ChangeWeight, DeletePoint, ElevateDegree,
+    GetAnalyticFamily, GetArcLength, GetBezierAlgorithm, GetBezierSegments, GetCombScale,
+    GetControlPointsLength, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetFittingBasis, GetFittingDegree, GetHodograph, GetInterpolationNodes, GetJointContinuity,
+    GetKnotVector, GetNearestParameter, GetOverlayParameter, GetOverlayVisibility,
+    GetParametricConstants, GetParametricExpressions, GetPlacement, GetPoint, GetPolygonRadius,
+    GetPolygonVertices, GetSampledPath, GetSamples, GetSampling, GetSplineBoundary,
+    GetSplineDegree, GetSplineParametrisation, GetTangent, GetTcbParameters, GetWeight, MoveCurve,
+    MovePoint, ProjectPoint, ReduceDegree, RotateCurve, SelectPoint, SetAnalyticFamily,
+    SetBezierAlgorithm, SetCombScale, SetFittingBasis, SetFittingDegree, SetInterpolationNodes,
+    SetJointContinuity, SetKnotVector, SetOverlayParameter, SetOverlayVisibility,
+    SetParametricConstant, SetParametricExpressions, SetPlacement, SetPolygonRadius,
+    SetPolygonVertices, SetSamples, SetSampling, SetSplineBoundary, SetSplineDegree,
+    SetSplineParametrisation, SetTangent, SetTcbParameters, SetTrochoidProperties, SplitCurve,
 };
 use crate::request::macros::delegate_requests;
 use crate::request::sieve::RequestSieve;
@@ -15,19 +26,21 @@ delegate_requests! {
         { mut AddControlPoint => ! },
         { mut MovePoint => ! },
         { mut DeletePoint => ! },
-        { mut RotateCurve => ! },
-        { mut MoveCurve => ! },
+        { mut RotateCurve => T },
+        { mut MoveCurve => T },
         { mut ChangeWeight => ! },
         { mut AddWeightedControlPoint => ! },
         { GetControlPointsLength => ! },
-        { GetCurveCenter => ! },
+        { GetCurveCenter => T },
         { SelectPoint => ! },
         { GetPoint => ! },
         { GetWeight => ! },
 
         // Samples requests
         { mut SetSamples => T },
+        { mut SetSampling => T },
         { GetSamples => T },
+        { GetSampling => T },
 
         // InterpolationCurve requests
         { mut SetInterpolationNodes => T },
@@ -35,6 +48,83 @@ delegate_requests! {
 
         // TrochoidCurve requests
         { mut SetTrochoidProperties => T },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => T },
+        { mut SetPlacement => T },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetArcLength => T },
+        { GetEquidistantPoints => T },
+
+        // Geometry requests
+        { GetBezierSegments => T },
+        { GetSampledPath => T },
+        { ProjectPoint => T },
+        { GetCurveFeatures => T },
+
+        // Overlay requests
+        { GetOverlayVisibility => ! },
+        { mut SetOverlayVisibility => ! },
+        { GetOverlayParameter => ! },
+        { mut SetOverlayParameter => ! },
+        { GetCombScale => ! },
+        { mut SetCombScale => ! },
     }
 }
 
@@ -59,7 +149,9 @@ delegate_requests! {
 
         // Samples requests
         { mut SetSamples => T },
+        { mut SetSampling => T },
         { GetSamples => T },
+        { GetSampling => T },
 
         // InterpolationCurve requests
         { mut SetInterpolationNodes => ! },
@@ -67,6 +159,83 @@ delegate_requests! {
 
         // TrochoidCurve requests
         { mut SetTrochoidProperties => T },
+
+        // BSplineCurve requests
+        { GetSplineDegree => T },
+        { mut SetSplineDegree => T },
+        { GetKnotVector => T },
+        { mut SetKnotVector => T },
+
+        // BezierSpline requests
+        { GetJointContinuity => T },
+        { mut SetJointContinuity => T },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => T },
+        { mut SetSplineBoundary => T },
+        { GetSplineParametrisation => T },
+        { mut SetSplineParametrisation => T },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => T },
+        { mut SetTcbParameters => T },
+
+        // HermiteCurve requests
+        { GetTangent => T },
+        { mut SetTangent => T },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => T },
+        { mut SetPolygonVertices => T },
+        { GetPolygonRadius => T },
+        { mut SetPolygonRadius => T },
+
+        // Placement requests
+        { GetPlacement => T },
+        { mut SetPlacement => T },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => T },
+        { mut SetParametricExpressions => T },
+        { GetParametricConstants => T },
+        { mut SetParametricConstant => T },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => T },
+        { mut SetAnalyticFamily => T },
+
+        // FittingCurve requests
+        { GetFittingBasis => T },
+        { mut SetFittingBasis => T },
+        { GetFittingDegree => T },
+        { mut SetFittingDegree => T },
+
+        // BezierCurve requests
+        { SplitCurve => T },
+        { GetHodograph => T },
+        { GetNearestParameter => T },
+        { mut ElevateDegree => T },
+        { mut ReduceDegree => T },
+        { GetBezierAlgorithm => T },
+        { mut SetBezierAlgorithm => T },
+
+        // Length requests
+        { GetArcLength => T },
+        { GetEquidistantPoints => T },
+
+        // Geometry requests
+        { GetBezierSegments => T },
+        { GetSampledPath => T },
+        { ProjectPoint => T },
+        { GetCurveFeatures => T },
+
+        // Overlay requests
+        { GetOverlayVisibility => T },
+        { mut SetOverlayVisibility => T },
+        { GetOverlayParameter => T },
+        { mut SetOverlayParameter => T },
+        { GetCombScale => T },
+        { mut SetCombScale => T },
     }
 }
 
@@ -91,7 +260,9 @@ delegate_requests! {
 
         // Samples requests
         { mut SetSamples => ! },
+        { mut SetSampling => ! },
         { GetSamples => ! },
+        { GetSampling => ! },
 
         // InterpolationCurve requests
         { mut SetInterpolationNodes => ! },
@@ -99,5 +270,82 @@ delegate_requests! {
 
         // TrochoidCurve requests
         { mut SetTrochoidProperties => ! },
+
+        // BSplineCurve requests
+        { GetSplineDegree => ! },
+        { mut SetSplineDegree => ! },
+        { GetKnotVector => ! },
+        { mut SetKnotVector => ! },
+
+        // BezierSpline requests
+        { GetJointContinuity => ! },
+        { mut SetJointContinuity => ! },
+
+        // CubicSplineCurve requests
+        { GetSplineBoundary => ! },
+        { mut SetSplineBoundary => ! },
+        { GetSplineParametrisation => ! },
+        { mut SetSplineParametrisation => ! },
+
+        // KochanekBartelsCurve requests
+        { GetTcbParameters => ! },
+        { mut SetTcbParameters => ! },
+
+        // HermiteCurve requests
+        { GetTangent => ! },
+        { mut SetTangent => ! },
+
+        // RegularPolygon requests
+        { GetPolygonVertices => ! },
+        { mut SetPolygonVertices => ! },
+        { GetPolygonRadius => ! },
+        { mut SetPolygonRadius => ! },
+
+        // Placement requests
+        { GetPlacement => ! },
+        { mut SetPlacement => ! },
+
+        // ParametricCurve requests
+        { GetParametricExpressions => ! },
+        { mut SetParametricExpressions => ! },
+        { GetParametricConstants => ! },
+        { mut SetParametricConstant => ! },
+
+        // AnalyticCurve requests
+        { GetAnalyticFamily => ! },
+        { mut SetAnalyticFamily => ! },
+
+        // FittingCurve requests
+        { GetFittingBasis => ! },
+        { mut SetFittingBasis => ! },
+        { GetFittingDegree => ! },
+        { mut SetFittingDegree => ! },
+
+        // BezierCurve requests
+        { SplitCurve => ! },
+        { GetHodograph => ! },
+        { GetNearestParameter => ! },
+        { mut ElevateDegree => ! },
+        { mut ReduceDegree => ! },
+        { GetBezierAlgorithm => ! },
+        { mut SetBezierAlgorithm => ! },
+
+        // Length requests
+        { GetArcLength => ! },
+        { GetEquidistantPoints => ! },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => ! },
+        { ProjectPoint => ! },
+        { GetCurveFeatures => ! },
+
+        // Overlay requests
+        { GetOverlayVisibility => ! },
+        { mut SetOverlayVisibility => ! },
+        { GetOverlayParameter => ! },
+        { mut SetOverlayParameter => ! },
+        { GetCombScale => ! },
+        { mut SetCombScale => ! },
     }
 }
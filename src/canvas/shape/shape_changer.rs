@@ -1,13 +1,34 @@
 use crate::canvas::base_line::{ClosedBaseLine, OpenBaseLine};
 use crate::canvas::control_points::point::CurveControlPoints;
+use crate::canvas::control_points::tangent::TangentPoint;
+use crate::canvas::control_points::tcb::{TcbParameters, TcbPoint};
+use crate::canvas::control_points::weighted::WeightedPoint;
+use crate::canvas::control_points::ControlPoints;
 use crate::canvas::control_points_curve::VisualControlPoints;
+use crate::canvas::placement::Placement;
 use crate::canvas::samples::Samples;
+use crate::canvas::shape::analytic::family::AnalyticFamily;
+use crate::canvas::shape::analytic::AnalyticCurve;
+use crate::canvas::shape::b_spline::{BSplineCurve, BSplineCurveProperties};
 use crate::canvas::shape::bezier::{BezierCurve, BezierCurveProperties};
+use crate::canvas::shape::bezier_spline::{BezierSpline, BezierSplineProperties};
+use crate::canvas::shape::catmull_rom::{CatmullRomCurve, CatmullRomCurveProperties};
+use crate::canvas::shape::cubic_spline::{CubicSplineCurve, CubicSplineCurveProperties};
+use crate::canvas::shape::fitting::{FittingCurve, FittingCurveProperties};
+use crate::canvas::shape::hermite::{
+    HermiteControlPoints, HermiteCurve, HermiteCurveProperties, TangentHandles,
+};
 use crate::canvas::shape::interpolation::{InterpolationCurve, InterpolationCurveProperties};
+use crate::canvas::shape::kochanek_bartels::{
+    KochanekBartelsCurve, KochanekBartelsCurveProperties, TcbControlPoints,
+};
+use crate::canvas::shape::nurbs::NurbsCurve;
+use crate::canvas::shape::parametric::{ParametricCurve, ParametricCurveProperties};
 use crate::canvas::shape::polyline::PolylineCurve;
 use crate::canvas::shape::rational_bezier::{
     RationalBezierCurve, RationalBezierCurveProperties, WeightedControlPoints,
 };
+use crate::canvas::shape::regular_polygon::{RegularPolygon, RegularPolygonProperties};
 use crate::canvas::shape::trochoid::{TrochoidCurve, TrochoidCurveProperties};
 use crate::canvas::shape::{Shape, Update};
 use crate::config::{CanvasConfig, ShapeType};
@@ -22,14 +43,28 @@ pub struct ShapeChanger<'a> {
 pub struct ShapeCommonValues {
     pub points: Option<CurveControlPoints>,
     pub weighted_points: Option<WeightedControlPoints>,
+    pub tcb_points: Option<TcbControlPoints>,
+    pub tangent_points: Option<HermiteControlPoints>,
     pub control_points: Option<VisualControlPoints>,
+    pub tangent_handles: Option<TangentHandles>,
     pub open_base_line: Option<OpenBaseLine>,
     pub closed_base_line: Option<ClosedBaseLine>,
     pub samples: Option<Samples>,
+    pub placement: Option<Placement>,
     pub interpolation_properties: Option<InterpolationCurveProperties>,
     pub bezier_properties: Option<BezierCurveProperties>,
     pub rational_bezier_properties: Option<RationalBezierCurveProperties>,
     pub trochoid_properties: Option<TrochoidCurveProperties>,
+    pub regular_polygon_properties: Option<RegularPolygonProperties>,
+    pub b_spline_properties: Option<BSplineCurveProperties>,
+    pub bezier_spline_properties: Option<BezierSplineProperties>,
+    pub cubic_spline_properties: Option<CubicSplineCurveProperties>,
+    pub catmull_rom_properties: Option<CatmullRomCurveProperties>,
+    pub kochanek_bartels_properties: Option<KochanekBartelsCurveProperties>,
+    pub hermite_properties: Option<HermiteCurveProperties>,
+    pub parametric_properties: Option<ParametricCurveProperties>,
+    pub analytic_family: Option<AnalyticFamily>,
+    pub fitting_properties: Option<FittingCurveProperties>,
 }
 
 impl<'a> ShapeChanger<'a> {
@@ -39,6 +74,11 @@ impl<'a> ShapeChanger<'a> {
         Self { values, default_values }
     }
 
+    #[must_use]
+    pub fn from_values(values: ShapeCommonValues, default_values: &'a CanvasConfig) -> Self {
+        Self { values, default_values }
+    }
+
     #[must_use]
     pub fn from_shape(shape: Shape, default_values: &'a CanvasConfig) -> Self {
         let values = shape.into();
@@ -46,6 +86,7 @@ impl<'a> ShapeChanger<'a> {
     }
 
     #[must_use]
+    #[allow(clippy::too_many_lines)]
     pub fn into_shape(mut self, shape_type: ShapeType) -> Shape {
         let mut shape = match shape_type {
             ShapeType::Polyline => Shape::Polyline(Box::new(PolylineCurve::new(
@@ -77,28 +118,152 @@ impl<'a> ShapeChanger<'a> {
             ShapeType::Trochoid => Shape::Trochoid(Box::new(TrochoidCurve::new(
                 self.open_base_line(),
                 self.trochoid_properties(),
+                self.placement(),
+                self.samples(),
+            ))),
+            ShapeType::RegularPolygon => Shape::RegularPolygon(Box::new(RegularPolygon::new(
+                self.control_points(),
+                self.closed_base_line(),
+                self.regular_polygon_properties(),
+            ))),
+            ShapeType::BSpline => Shape::BSpline(Box::new(BSplineCurve::new(
+                self.curve_control_points(),
+                self.control_points(),
+                self.open_base_line(),
+                self.b_spline_properties(),
+                self.samples(),
+            ))),
+            ShapeType::Nurbs => Shape::Nurbs(Box::new(NurbsCurve::new(
+                self.weighted_control_points(),
+                self.control_points(),
+                self.open_base_line(),
+                self.b_spline_properties(),
+                self.samples(),
+            ))),
+            ShapeType::BezierSpline => Shape::BezierSpline(Box::new(BezierSpline::new(
+                self.curve_control_points(),
+                self.control_points(),
+                self.open_base_line(),
+                self.bezier_spline_properties(),
+                self.samples(),
+            ))),
+            ShapeType::CubicSpline => Shape::CubicSpline(Box::new(CubicSplineCurve::new(
+                self.curve_control_points(),
+                self.control_points(),
+                self.open_base_line(),
+                self.cubic_spline_properties(),
+                self.samples(),
+            ))),
+            ShapeType::CatmullRom => Shape::CatmullRom(Box::new(CatmullRomCurve::new(
+                self.curve_control_points(),
+                self.control_points(),
+                self.open_base_line(),
+                self.catmull_rom_properties(),
+                self.samples(),
+            ))),
+            ShapeType::KochanekBartels => {
+                let properties = self.kochanek_bartels_properties();
+                Shape::KochanekBartels(Box::new(KochanekBartelsCurve::new(
+                    self.tcb_control_points(properties.default_parameters),
+                    self.control_points(),
+                    self.open_base_line(),
+                    properties,
+                    self.samples(),
+                )))
+            }
+            ShapeType::Hermite => Shape::Hermite(Box::new(HermiteCurve::new(
+                self.tangent_control_points(),
+                self.control_points(),
+                self.tangent_handles(),
+                self.open_base_line(),
+                self.hermite_properties(),
+                self.samples(),
+            ))),
+            ShapeType::Parametric => Shape::Parametric(Box::new(ParametricCurve::new(
+                self.open_base_line(),
+                self.parametric_properties(),
+                self.placement(),
+                self.samples(),
+            ))),
+            ShapeType::Analytic => Shape::Analytic(Box::new(AnalyticCurve::new(
+                self.open_base_line(),
+                self.analytic_family(),
+                self.placement(),
+                self.samples(),
+            ))),
+            ShapeType::Fitting => Shape::Fitting(Box::new(FittingCurve::new(
+                self.curve_control_points(),
+                self.control_points(),
+                self.open_base_line(),
+                self.fitting_properties(),
                 self.samples(),
             ))),
-            ShapeType::RegularPolygon => todo!(),
         };
         shape.update();
         shape
     }
 
     fn curve_control_points(&mut self) -> CurveControlPoints {
-        // TODO: reuse weighted points if they are present
-        self.values.points.take().unwrap_or_default()
+        if let Some(points) = self.values.points.take() {
+            return points;
+        }
+        if let Some(points) = self.values.weighted_points.take() {
+            return ControlPoints::new(points.points_iterator().collect());
+        }
+        if let Some(points) = self.values.tcb_points.take() {
+            return ControlPoints::new(points.points_iterator().collect());
+        }
+        self.values
+            .tangent_points
+            .take()
+            .map(|points| ControlPoints::new(points.points_iterator().collect()))
+            .unwrap_or_default()
     }
 
     fn weighted_control_points(&mut self) -> WeightedControlPoints {
-        // TODO: reuse curve points if they are present
-        self.values.weighted_points.take().unwrap_or_default()
+        if let Some(points) = self.values.weighted_points.take() {
+            return points;
+        }
+        let weight = self.default_values.default_rational_bezier_weight;
+        let points = self.curve_control_points();
+        let points = points.points_iterator().map(|point| WeightedPoint::new(point, weight));
+        ControlPoints::new(points.collect())
+    }
+
+    fn tcb_control_points(&mut self, parameters: TcbParameters) -> TcbControlPoints {
+        if let Some(points) = self.values.tcb_points.take() {
+            return points;
+        }
+        let points = self.curve_control_points();
+        let points = points.points_iterator().map(|point| TcbPoint::new(point, parameters));
+        ControlPoints::new(points.collect())
+    }
+
+    fn tangent_control_points(&mut self) -> HermiteControlPoints {
+        if let Some(points) = self.values.tangent_points.take() {
+            return points;
+        }
+        let points = self.curve_control_points().into_inner();
+        // Estimate tangents with finite differences of the neighbouring points.
+        let tangents = (0..points.len()).map(|index| {
+            let before = index.saturating_sub(1);
+            let after = (index + 1).min(points.len() - 1);
+            let distance = (after - before).max(1) as f32;
+            (points[after] - points[before]) / distance
+        });
+        let points =
+            points.iter().zip(tangents).map(|(&point, tangent)| TangentPoint::new(point, tangent));
+        ControlPoints::new(points.collect())
     }
 
     fn control_points(&mut self) -> VisualControlPoints {
         take_or_from(&mut self.values.control_points, self.default_values)
     }
 
+    fn tangent_handles(&mut self) -> TangentHandles {
+        take_or_from(&mut self.values.tangent_handles, self.default_values)
+    }
+
     fn open_base_line(&mut self) -> OpenBaseLine {
         take_or_from(&mut self.values.open_base_line, self.default_values)
     }
@@ -111,6 +276,10 @@ impl<'a> ShapeChanger<'a> {
         take_or_from(&mut self.values.samples, self.default_values)
     }
 
+    fn placement(&mut self) -> Placement {
+        take_or_from(&mut self.values.placement, self.default_values)
+    }
+
     fn interpolation_properties(&mut self) -> InterpolationCurveProperties {
         take_or_from(&mut self.values.interpolation_properties, self.default_values)
     }
@@ -126,6 +295,51 @@ impl<'a> ShapeChanger<'a> {
     fn trochoid_properties(&mut self) -> TrochoidCurveProperties {
         take_or_from(&mut self.values.trochoid_properties, self.default_values)
     }
+
+    fn regular_polygon_properties(&mut self) -> RegularPolygonProperties {
+        if let Some(properties) = self.values.regular_polygon_properties.take() {
+            return properties;
+        }
+        let points = self.curve_control_points();
+        RegularPolygonProperties::from_vertices(&points)
+            .unwrap_or_else(|| self.default_values.into())
+    }
+
+    fn b_spline_properties(&mut self) -> BSplineCurveProperties {
+        take_or_from(&mut self.values.b_spline_properties, self.default_values)
+    }
+
+    fn bezier_spline_properties(&mut self) -> BezierSplineProperties {
+        take_or_from(&mut self.values.bezier_spline_properties, self.default_values)
+    }
+
+    fn cubic_spline_properties(&mut self) -> CubicSplineCurveProperties {
+        take_or_from(&mut self.values.cubic_spline_properties, self.default_values)
+    }
+
+    fn catmull_rom_properties(&mut self) -> CatmullRomCurveProperties {
+        take_or_from(&mut self.values.catmull_rom_properties, self.default_values)
+    }
+
+    fn kochanek_bartels_properties(&mut self) -> KochanekBartelsCurveProperties {
+        take_or_from(&mut self.values.kochanek_bartels_properties, self.default_values)
+    }
+
+    fn hermite_properties(&mut self) -> HermiteCurveProperties {
+        take_or_from(&mut self.values.hermite_properties, self.default_values)
+    }
+
+    fn parametric_properties(&mut self) -> ParametricCurveProperties {
+        take_or_from(&mut self.values.parametric_properties, self.default_values)
+    }
+
+    fn analytic_family(&mut self) -> AnalyticFamily {
+        take_or_from(&mut self.values.analytic_family, self.default_values)
+    }
+
+    fn fitting_properties(&mut self) -> FittingCurveProperties {
+        take_or_from(&mut self.values.fitting_properties, self.default_values)
+    }
 }
 
 fn take_or_from<T, U>(option: &mut Option<T>, default_value: U) -> T
@@ -1,8 +1,12 @@
+use std::ops::RangeInclusive;
+
 use tiny_skia::PixmapMut;
 
 use crate::canvas::base_line::OpenBaseLine;
 use crate::canvas::math::point::Point;
+use crate::canvas::placement::Placement;
 use crate::canvas::samples::Samples;
+use crate::canvas::shape::layers::Layers;
 use crate::canvas::shape::shape_changer::ShapeCommonValues;
 use crate::canvas::shape::{DrawOn, Update};
 use crate::config::CanvasConfig;
@@ -13,6 +17,8 @@ pub mod request;
 pub struct TrochoidCurve {
     base_line: OpenBaseLine,
     properties: TrochoidCurveProperties,
+    #[serde(default)]
+    placement: Placement,
     samples: Samples,
 }
 
@@ -21,26 +27,34 @@ impl TrochoidCurve {
     pub fn new(
         base_line: OpenBaseLine,
         properties: TrochoidCurveProperties,
+        placement: Placement,
         samples: Samples,
     ) -> Self {
-        Self { base_line, properties, samples }
+        Self { base_line, properties, placement, samples }
     }
 }
 
 impl Update for TrochoidCurve {
-    fn update(&mut self) {
-        let TrochoidCurveProperties { range_start, range_end, r_1, r_2, w_1, w_2 } =
-            self.properties;
-        let x = move |t| r_1 * f32::cos(w_1 * t) + r_2 * f32::cos(w_2 * t);
-        let y = move |t| r_1 * f32::sin(w_1 * t) + r_2 * f32::sin(w_2 * t);
-        let path = self
-            .samples
-            .equally_spaced(range_start..=range_end)
-            .map(move |t| Point::new(x(t) * 200.0 + 250.0, y(t) * 200.0 + 250.0));
-        self.base_line.rebuild_paths(path);
+    fn update_layers(&mut self, layers: Layers) {
+        if !layers.contains(Layers::CURVE) {
+            return;
+        }
+        let (range, curve) = curve(self.properties, self.placement);
+        let path = self.samples.sample(range, curve);
+        self.base_line.rebuild_sampled_paths(path);
     }
 }
 
+fn curve(
+    properties: TrochoidCurveProperties,
+    placement: Placement,
+) -> (RangeInclusive<f32>, impl Fn(f32) -> Point<f32>) {
+    let TrochoidCurveProperties { range_start, range_end, r_1, r_2, w_1, w_2 } = properties;
+    let x = move |t| r_1 * f32::cos(w_1 * t) + r_2 * f32::cos(w_2 * t);
+    let y = move |t| r_1 * f32::sin(w_1 * t) + r_2 * f32::sin(w_2 * t);
+    (range_start..=range_end, move |t| placement.apply(Point::new(x(t), y(t))))
+}
+
 impl DrawOn for TrochoidCurve {
     fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
         self.base_line.draw_on(pixmap);
@@ -53,6 +67,7 @@ impl From<TrochoidCurve> for ShapeCommonValues {
             open_base_line: Some(value.base_line),
             samples: Some(value.samples),
             trochoid_properties: Some(value.properties),
+            placement: Some(value.placement),
             ..Default::default()
         }
     }
@@ -99,3 +114,23 @@ impl Default for TrochoidCurveProperties {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn curves_saved_without_placement_are_loaded() {
+        let config = CanvasConfig::default();
+        let curve = TrochoidCurve::new(
+            (&config).into(),
+            (&config).into(),
+            Placement::new(Point::new(0.0, 0.0), 1.0, 0.0),
+            Samples::new(50),
+        );
+        let mut value = serde_json::to_value(curve).unwrap();
+        value.as_object_mut().unwrap().remove("placement");
+        let curve = serde_json::from_value::<TrochoidCurve>(value).unwrap();
+        assert_eq!(curve.placement, Placement::default());
+    }
+}
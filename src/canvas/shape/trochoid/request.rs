@@ -1,13 +1,39 @@
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::math;
+use crate::canvas::placement::Placement;
 use crate::canvas::samples::Samples;
-use crate::canvas::shape::request::declare::{GetSamples, SetSamples, SetTrochoidProperties};
-use crate::canvas::shape::trochoid::TrochoidCurve;
+use crate::canvas::shape::request::declare::{
+    GetArcLength, GetBezierSegments, GetCurveCenter, GetCurveFeatures, GetEquidistantPoints,
+    GetPlacement, GetSampledPath, GetSamples, GetSampling, MoveCurve, ProjectPoint, RotateCurve,
+    SetPlacement, SetSamples, SetSampling, SetTrochoidProperties,
+};
+use crate::canvas::shape::trochoid::{curve, TrochoidCurve};
 use crate::request::macros::delegate_requests;
-use crate::request::{RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, ResponseMut};
+use crate::request::{
+    RequestHandler, RequestHandlerMut, RequestSubHandler, RequestSubHandlerMut, Response,
+    ResponseMut,
+};
 
 delegate_requests! {
     TrochoidCurve {
         { GetSamples => Samples },
+        { GetSampling => Samples },
         { mut SetSamples => Samples },
+        { mut SetSampling => Samples },
+        { mut MoveCurve => Placement },
+        { mut RotateCurve => Placement },
+        { GetCurveCenter => Placement },
+        { GetPlacement => Placement },
+        { mut SetPlacement => Placement },
+
+        // Length requests
+        { GetArcLength => OpenBaseLine },
+        { GetEquidistantPoints => OpenBaseLine },
+
+        // Geometry requests
+        { GetBezierSegments => ! },
+        { GetSampledPath => OpenBaseLine },
+        { GetCurveFeatures => OpenBaseLine },
     }
 }
 
@@ -18,6 +44,26 @@ impl RequestHandlerMut<SetTrochoidProperties> for TrochoidCurve {
     }
 }
 
+impl RequestHandler<ProjectPoint> for TrochoidCurve {
+    fn handle(&self, event: ProjectPoint) -> Response<ProjectPoint> {
+        let sample = self.base_line.handle(ProjectPoint::new(event.point))?;
+        let (range, curve) = curve(self.properties, self.placement);
+        Ok(math::projection::refine_projection(curve, range, event.point, sample.t))
+    }
+}
+
+impl RequestSubHandler<Placement> for TrochoidCurve {
+    fn sub_handler(&self) -> &Placement {
+        &self.placement
+    }
+}
+
+impl RequestSubHandlerMut<Placement> for TrochoidCurve {
+    fn sub_handler_mut(&mut self) -> &mut Placement {
+        &mut self.placement
+    }
+}
+
 impl RequestSubHandler<Samples> for TrochoidCurve {
     fn sub_handler(&self) -> &Samples {
         &self.samples
@@ -29,3 +75,9 @@ impl RequestSubHandlerMut<Samples> for TrochoidCurve {
         &mut self.samples
     }
 }
+
+impl RequestSubHandler<OpenBaseLine> for TrochoidCurve {
+    fn sub_handler(&self) -> &OpenBaseLine {
+        &self.base_line
+    }
+}
@@ -0,0 +1,49 @@
+use tiny_skia::{Path, PathBuilder, PixmapMut, Point};
+
+use crate::canvas::visual_path::line::{VisualLineDetails, VisualLineProperties};
+use crate::canvas::visual_path::private::VisualPathDetails;
+use crate::canvas::visual_path::VisualPath;
+
+/// Arrows, each built from two consecutive points: its start and its head.
+pub type VisualArrows = VisualPath<VisualArrowsDetails>;
+
+/// Length of arrow head relative to width of the line.
+const HEAD_LENGTH: f32 = 4.0;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct VisualArrowsDetails;
+
+impl VisualPathDetails for VisualArrowsDetails {
+    type Properties = VisualLineProperties;
+
+    fn draw_on(pixmap: &mut PixmapMut<'_>, path: &Path, properties: &Self::Properties) {
+        VisualLineDetails::<false>::draw_on(pixmap, path, properties);
+    }
+
+    fn build_path(
+        mut builder: PathBuilder,
+        mut points: impl ExactSizeIterator<Item = Point>,
+        properties: &Self::Properties,
+    ) -> Option<Path> {
+        let head_length = HEAD_LENGTH * properties.width();
+        while let (Some(start), Some(end)) = (points.next(), points.next()) {
+            builder.move_to(start.x, start.y);
+            builder.line_to(end.x, end.y);
+
+            let mut direction = start - end;
+            if direction.set_length(head_length) {
+                let (sin, cos) = std::f32::consts::FRAC_PI_6.sin_cos();
+                for sin in [sin, -sin] {
+                    let side = Point::from_xy(
+                        direction.x * cos - direction.y * sin,
+                        direction.x * sin + direction.y * cos,
+                    );
+                    builder.move_to(end.x, end.y);
+                    builder.line_to(end.x + side.x, end.y + side.y);
+                }
+            }
+        }
+
+        builder.finish()
+    }
+}
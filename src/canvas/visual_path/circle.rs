@@ -0,0 +1,31 @@
+use tiny_skia::{Path, PathBuilder, PixmapMut, Point};
+
+use crate::canvas::visual_path::line::{VisualLineDetails, VisualLineProperties};
+use crate::canvas::visual_path::private::VisualPathDetails;
+use crate::canvas::visual_path::VisualPath;
+
+/// Circles, each built from two consecutive points: its center and a point lying on it.
+pub type VisualCircles = VisualPath<VisualCirclesDetails>;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct VisualCirclesDetails;
+
+impl VisualPathDetails for VisualCirclesDetails {
+    type Properties = VisualLineProperties;
+
+    fn draw_on(pixmap: &mut PixmapMut<'_>, path: &Path, properties: &Self::Properties) {
+        VisualLineDetails::<false>::draw_on(pixmap, path, properties);
+    }
+
+    fn build_path(
+        mut builder: PathBuilder,
+        mut points: impl ExactSizeIterator<Item = Point>,
+        _properties: &Self::Properties,
+    ) -> Option<Path> {
+        while let (Some(center), Some(point)) = (points.next(), points.next()) {
+            builder.push_circle(center.x, center.y, center.distance(point));
+        }
+
+        builder.finish()
+    }
+}
@@ -0,0 +1,40 @@
+use tiny_skia::{Path, PathBuilder, PixmapMut, Point};
+
+use crate::canvas::visual_path::line::{VisualLineDetails, VisualLineProperties};
+use crate::canvas::visual_path::private::VisualPathDetails;
+use crate::canvas::visual_path::VisualPath;
+
+/// Teeth of a comb, each built from its base and its tip, whose tips are joined by a line.
+pub type VisualComb = VisualPath<VisualCombDetails>;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct VisualCombDetails;
+
+impl VisualPathDetails for VisualCombDetails {
+    type Properties = VisualLineProperties;
+
+    fn draw_on(pixmap: &mut PixmapMut<'_>, path: &Path, properties: &Self::Properties) {
+        VisualLineDetails::<false>::draw_on(pixmap, path, properties);
+    }
+
+    fn build_path(
+        mut builder: PathBuilder,
+        mut points: impl ExactSizeIterator<Item = Point>,
+        _properties: &Self::Properties,
+    ) -> Option<Path> {
+        let mut tips = Vec::with_capacity(points.len() / 2);
+        while let (Some(base), Some(tip)) = (points.next(), points.next()) {
+            builder.move_to(base.x, base.y);
+            builder.line_to(tip.x, tip.y);
+            tips.push(tip);
+        }
+
+        let (first, rest) = tips.split_first()?;
+        builder.move_to(first.x, first.y);
+        for tip in rest {
+            builder.line_to(tip.x, tip.y);
+        }
+
+        builder.finish()
+    }
+}
@@ -8,6 +8,9 @@ use crate::config::rgb::{Alpha, Rgb};
 
 pub type VisualLine<const CLOSED: bool> = VisualPath<VisualLineDetails<CLOSED>>;
 
+/// Disconnected line segments, each built from two consecutive points.
+pub type VisualSegments = VisualPath<VisualSegmentsDetails>;
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct VisualLineProperties {
     width: SizeProperty<f32>,
@@ -52,6 +55,30 @@ impl<const CLOSED: bool> VisualPathDetails for VisualLineDetails<CLOSED> {
     }
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct VisualSegmentsDetails;
+
+impl VisualPathDetails for VisualSegmentsDetails {
+    type Properties = VisualLineProperties;
+
+    fn draw_on(pixmap: &mut PixmapMut<'_>, path: &Path, properties: &Self::Properties) {
+        VisualLineDetails::<false>::draw_on(pixmap, path, properties);
+    }
+
+    fn build_path(
+        mut builder: PathBuilder,
+        mut points: impl ExactSizeIterator<Item = Point>,
+        _properties: &Self::Properties,
+    ) -> Option<Path> {
+        while let (Some(start), Some(end)) = (points.next(), points.next()) {
+            builder.move_to(start.x, start.y);
+            builder.line_to(end.x, end.y);
+        }
+
+        builder.finish()
+    }
+}
+
 impl VisualLineProperties {
     #[must_use]
     pub fn new(width: f32, color: Rgb, alpha: Alpha) -> Self {
@@ -60,4 +87,9 @@ impl VisualLineProperties {
         let alpha = AlphaProperty::new(alpha);
         Self { width, color, alpha }
     }
+
+    #[must_use]
+    pub fn width(&self) -> f32 {
+        self.width.value()
+    }
 }
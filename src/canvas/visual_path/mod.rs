@@ -3,6 +3,9 @@ use tiny_skia::{Path, PathBuilder, PixmapMut, Point};
 use crate::canvas::shape::DrawOn;
 use crate::canvas::visual_path::private::VisualPathDetails;
 
+pub mod arrow;
+pub mod circle;
+pub mod comb;
 pub mod line;
 pub mod point;
 pub mod property;
@@ -27,6 +30,16 @@ where
         Self { visible, path, properties }
     }
 
+    #[must_use]
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Changes visibility of the path, which has to be rebuilt after it becomes visible.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
     pub fn rebuild_path<P>(&mut self, points: impl ExactSizeIterator<Item = P>)
     where
         P: Into<Point>,
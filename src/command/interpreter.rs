@@ -4,15 +4,31 @@ use std::path::PathBuf;
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
 
+use crate::canvas::control_points::tcb::TcbParameters;
+use crate::canvas::control_points_curve::Overlay;
+use crate::canvas::math::curve_features::CurveFeatures;
 use crate::canvas::math::point::Point;
 use crate::canvas::math::vector::Vector;
+use crate::canvas::placement::Placement;
 use crate::canvas::request::declare::{
-    GetConvexHull, GetCurvesLength, GetLength, GetPointOnCurve, MovePointOnCurve, RotateCurveById,
-    SetConvexHull, SetCurveType,
+    AddOffsetCurve, GetConvexHull, GetCurveType, GetCurvesLength, GetEquidistantPointsOnCurve,
+    GetIntersections, GetLength, GetPointOnCurve, GetPointsLength, InsertHodograph,
+    MovePointOnCurve, ProjectPointOnCurve, RotateCurveById, SetConvexHull, SetCurveType,
+    SetMarkers, SplitCurveById,
 };
+use crate::canvas::shape::analytic::family::AnalyticFamily;
+use crate::canvas::shape::parametric::ParametricExpressions;
 use crate::canvas::shape::request::declare::{
-    GetInterpolationNodes, GetSamples, MoveCurve, RotateCurve, SetInterpolationNodes, SetSamples,
-    SetTrochoidProperties,
+    ElevateDegree, GetAnalyticFamily, GetBezierAlgorithm, GetCombScale, GetCurveFeatures,
+    GetFittingBasis, GetFittingDegree, GetInterpolationNodes, GetJointContinuity, GetKnotVector,
+    GetOverlayParameter, GetOverlayVisibility, GetParametricConstants, GetParametricExpressions,
+    GetPlacement, GetPolygonRadius, GetPolygonVertices, GetSamples, GetSampling, GetSplineBoundary,
+    GetSplineDegree, GetSplineParametrisation, GetTangent, GetTcbParameters, MoveCurve,
+    ReduceDegree, RotateCurve, SetAnalyticFamily, SetBezierAlgorithm, SetCombScale,
+    SetFittingBasis, SetFittingDegree, SetInterpolationNodes, SetJointContinuity, SetKnotVector,
+    SetOverlayParameter, SetOverlayVisibility, SetParametricConstant, SetParametricExpressions,
+    SetPlacement, SetPolygonRadius, SetPolygonVertices, SetSamples, SetSampling, SetSplineBoundary,
+    SetSplineDegree, SetSplineParametrisation, SetTangent, SetTcbParameters, SetTrochoidProperties,
 };
 use crate::canvas::shape::trochoid::TrochoidCurveProperties;
 use crate::command::message::Message;
@@ -20,6 +36,7 @@ use crate::command::parser::{Command, Get, Set, Task, Toggle};
 use crate::command::program_view::ProgramView;
 use crate::config::ShapeType;
 use crate::request::{RequestSubHandler, RequestSubHandlerMut};
+use crate::ui::frame::Frame;
 use crate::ui::handler::message::HandlerMessage;
 
 pub struct CommandInterpreter<'a> {
@@ -46,12 +63,34 @@ impl<'a> CommandInterpreter<'a> {
             Command::SaveImage { path } => self.interpret_save_image(path),
             Command::SetCurveType { curve_type } => self.interpret_set_curve_type(curve_type),
             Command::GetLength { curve_id } => self.get_length(curve_id),
+            Command::GetPointsLength { curve_id } => self.get_points_length(curve_id),
+            Command::GetEquidistantPoints { curve_id, count } => {
+                self.get_equidistant_points(curve_id, count)
+            }
             Command::GetPoint { curve_id, point_id } => self.get_point(curve_id, point_id),
             Command::MovePoint { curve_id, point_id, horizontal, vertical } => {
                 self.move_point(curve_id, point_id, horizontal, vertical)
             }
             Command::GetCurvesLength => self.get_curves_length(),
+            Command::SplitCurve { curve_id, t } => self.split_curve(curve_id, t),
+            Command::Intersect { first_curve_id, second_curve_id, show } => {
+                self.intersect(first_curve_id, second_curve_id, show)
+            }
+            Command::Project { curve_id, horizontal, vertical } => {
+                self.project(curve_id, horizontal, vertical)
+            }
+            Command::ClearMarkers => self.clear_markers(),
+            Command::Offset { distance, tolerance } => self.offset(distance, tolerance),
+            Command::InsertHodograph => self.insert_hodograph(),
+            Command::ElevateDegree { times } => self.elevate_degree(times),
+            Command::ReduceDegree => self.reduce_degree(),
             Command::TrochoidProperties(properties) => self.trochoid(properties),
+            Command::Analytic(family) => self.analytic(family),
+            Command::Parametric { horizontal, vertical, range_start, range_end } => {
+                let expressions =
+                    ParametricExpressions::new(horizontal, vertical, range_start, range_end);
+                self.parametric(expressions)
+            }
             Command::Execute { path, argument } => self.execute(path, argument),
             Command::Task(task) => self.task(task),
             Command::Quit => self.quit(),
@@ -59,6 +98,7 @@ impl<'a> CommandInterpreter<'a> {
         result.map_err(Error::OtherError)
     }
 
+    #[allow(clippy::too_many_lines)]
     fn interpret_get(&mut self, get: Get) -> InterpretResult {
         let frame = &mut *self.state.frame;
         let message = match get {
@@ -74,6 +114,98 @@ impl<'a> CommandInterpreter<'a> {
                 let samples = frame.sub_handle(GetSamples)?;
                 format!("{samples}")
             }
+            Get::Sampling => {
+                let sampling = frame.sub_handle(GetSampling)?;
+                format!("{sampling}")
+            }
+            Get::SplineDegree => {
+                let degree = frame.sub_handle(GetSplineDegree)?;
+                format!("{degree}")
+            }
+            Get::KnotVector => {
+                let knots = frame.sub_handle(GetKnotVector)?;
+                format!("{knots}")
+            }
+            Get::JointContinuity { joint } => {
+                let continuity = frame.sub_handle(GetJointContinuity::new(joint))?;
+                format!("{continuity}")
+            }
+            Get::SplineBoundary => {
+                let boundary = frame.sub_handle(GetSplineBoundary)?;
+                format!("{boundary}")
+            }
+            Get::SplineParametrisation => {
+                let parametrisation = frame.sub_handle(GetSplineParametrisation)?;
+                format!("{parametrisation}")
+            }
+            Get::TcbParameters { point } => {
+                let parameters = frame.sub_handle(GetTcbParameters::new(point))?;
+                format!("{parameters}")
+            }
+            Get::Tangent { point } => {
+                let tangent = frame.sub_handle(GetTangent::new(point))?;
+                format!("{} {}", tangent.horizontal(), tangent.vertical())
+            }
+            Get::PolygonVertices => {
+                let vertices = frame.sub_handle(GetPolygonVertices)?;
+                format!("{vertices}")
+            }
+            Get::PolygonRadius => {
+                let radius = frame.sub_handle(GetPolygonRadius)?;
+                format!("{radius}")
+            }
+            Get::Placement => {
+                let placement = frame.sub_handle(GetPlacement)?;
+                let position = placement.position;
+                format!(
+                    "{} {} {} {}",
+                    position.horizontal(),
+                    position.vertical(),
+                    placement.scale,
+                    placement.rotation.to_degrees()
+                )
+            }
+            Get::Parametric => {
+                let expressions = frame.sub_handle(GetParametricExpressions)?;
+                let constants = frame.sub_handle(GetParametricConstants)?;
+                let constants =
+                    constants.iter().map(|(name, value)| format!("{name}={value}")).join(" ");
+                format!("{expressions} {constants}")
+            }
+            Get::Analytic => {
+                let family = frame.sub_handle(GetAnalyticFamily)?;
+                format!("{family}")
+            }
+            Get::FittingBasis => {
+                let basis = frame.sub_handle(GetFittingBasis)?;
+                format!("{basis}")
+            }
+            Get::FittingDegree => {
+                let degree = frame.sub_handle(GetFittingDegree)?;
+                format!("{degree}")
+            }
+            Get::BezierAlgorithm => {
+                let algorithm = frame.sub_handle(GetBezierAlgorithm)?;
+                format!("{algorithm}")
+            }
+            Get::OverlayParameter => {
+                let parameter = frame.sub_handle(GetOverlayParameter)?;
+                format!("{parameter}")
+            }
+            Get::CombScale => {
+                let scale = frame.sub_handle(GetCombScale)?;
+                format!("{scale}")
+            }
+            Get::SelfIntersections { show } => {
+                mark_feature(frame, |features| features.self_intersections, show)?
+            }
+            Get::Inflections { show } => {
+                mark_feature(frame, |features| features.inflections, show)?
+            }
+            Get::Cusps { show } => mark_feature(frame, |features| features.cusps, show)?,
+            Get::CurvatureExtrema { show } => {
+                mark_feature(frame, |features| features.curvature_extrema, show)?
+            }
         };
         Ok(Some(Message::info(message)))
     }
@@ -87,6 +219,39 @@ impl<'a> CommandInterpreter<'a> {
                 frame.sub_handle_mut(SetInterpolationNodes::new(value))?;
             }
             Set::Samples { value } => frame.sub_handle_mut(SetSamples(value))?,
+            Set::Sampling(sampling) => frame.sub_handle_mut(SetSampling(sampling))?,
+            Set::SplineDegree { value } => frame.sub_handle_mut(SetSplineDegree(value))?,
+            Set::KnotVector { value } => frame.sub_handle_mut(SetKnotVector(value))?,
+            Set::JointContinuity { value, joint } => {
+                frame.sub_handle_mut(SetJointContinuity::new(joint, value))?;
+            }
+            Set::SplineBoundary { value } => frame.sub_handle_mut(SetSplineBoundary(value))?,
+            Set::SplineParametrisation { value } => {
+                frame.sub_handle_mut(SetSplineParametrisation(value))?;
+            }
+            Set::TcbParameters { tension, continuity, bias, point } => {
+                let parameters = TcbParameters::new(tension, continuity, bias);
+                frame.sub_handle_mut(SetTcbParameters::new(point, parameters))?;
+            }
+            Set::Tangent { horizontal, vertical, point } => {
+                let tangent = Vector::new(horizontal, vertical);
+                frame.sub_handle_mut(SetTangent::new(point, tangent))?;
+            }
+            Set::PolygonVertices { value } => frame.sub_handle_mut(SetPolygonVertices(value))?,
+            Set::PolygonRadius { value } => frame.sub_handle_mut(SetPolygonRadius(value))?,
+            Set::Placement { horizontal, vertical, scale, rotation } => {
+                let position = Point::new(horizontal, vertical);
+                let placement = Placement::new(position, scale, rotation.to_radians());
+                frame.sub_handle_mut(SetPlacement(placement))?;
+            }
+            Set::ParametricConstant { name, value } => {
+                frame.sub_handle_mut(SetParametricConstant::new(name, value))?;
+            }
+            Set::FittingBasis { value } => frame.sub_handle_mut(SetFittingBasis(value))?,
+            Set::FittingDegree { value } => frame.sub_handle_mut(SetFittingDegree(value))?,
+            Set::BezierAlgorithm { value } => frame.sub_handle_mut(SetBezierAlgorithm(value))?,
+            Set::OverlayParameter { value } => frame.sub_handle_mut(SetOverlayParameter(value))?,
+            Set::CombScale { value } => frame.sub_handle_mut(SetCombScale(value))?,
         }
         Ok(None)
     }
@@ -102,6 +267,10 @@ impl<'a> CommandInterpreter<'a> {
             Toggle::ControlLine => {
                 // TODO: handle for current curve
             }
+            Toggle::CurvatureComb => toggle_overlay(frame, Overlay::CurvatureComb)?,
+            Toggle::TangentNormal => toggle_overlay(frame, Overlay::TangentNormal)?,
+            Toggle::OsculatingCircle => toggle_overlay(frame, Overlay::OsculatingCircle)?,
+            Toggle::Hodograph => toggle_overlay(frame, Overlay::Hodograph)?,
         }
         Ok(None)
     }
@@ -158,6 +327,20 @@ impl<'a> CommandInterpreter<'a> {
         Ok(Some(Message::info(format!("{result}"))))
     }
 
+    fn get_points_length(&mut self, curve_id: usize) -> InterpretResult {
+        let result = self.state.frame.sub_handle(GetPointsLength(curve_id))?;
+        Ok(Some(Message::info(format!("{result}"))))
+    }
+
+    fn get_equidistant_points(&mut self, curve_id: usize, count: usize) -> InterpretResult {
+        let result = self.state.frame.sub_handle(GetEquidistantPointsOnCurve(curve_id, count))?;
+        let points = result
+            .into_iter()
+            .map(|point| format!("{},{}", point.horizontal(), point.vertical()))
+            .join(" ");
+        Ok(Some(Message::info(points)))
+    }
+
     fn get_point(&mut self, curve_id: usize, point_id: usize) -> InterpretResult {
         let result = self.state.frame.sub_handle(GetPointOnCurve(curve_id, point_id))?;
         Ok(Some(Message::info(format!("{},{}", result.horizontal(), result.vertical()))))
@@ -168,11 +351,85 @@ impl<'a> CommandInterpreter<'a> {
         Ok(None)
     }
 
+    fn split_curve(&mut self, curve_id: usize, t: f32) -> InterpretResult {
+        self.state.frame.sub_handle_mut(SplitCurveById::new(curve_id, t))?;
+        Ok(None)
+    }
+
+    fn intersect(&mut self, first: usize, second: usize, show: bool) -> InterpretResult {
+        let frame = &mut *self.state.frame;
+        let intersections = frame.sub_handle(GetIntersections(first, second))?;
+        if show {
+            let markers = intersections.iter().map(|intersection| intersection.point).collect();
+            frame.sub_handle_mut(SetMarkers(markers))?;
+        }
+        let intersections = intersections
+            .into_iter()
+            .map(|intersection| {
+                let point = intersection.point;
+                let (first, second) = (intersection.first, intersection.second);
+                format!("{},{}@{first},{second}", point.horizontal(), point.vertical())
+            })
+            .join(" ");
+        Ok(Some(Message::info(intersections)))
+    }
+
+    fn project(&mut self, curve_id: usize, horizontal: f32, vertical: f32) -> InterpretResult {
+        let point = Point::new(horizontal, vertical);
+        let projection = self.state.frame.sub_handle(ProjectPointOnCurve(curve_id, point))?;
+        let (point, t, distance) = (projection.point, projection.t, projection.distance);
+        let result = format!("{},{}@{t} {distance}", point.horizontal(), point.vertical());
+        Ok(Some(Message::info(result)))
+    }
+
+    fn clear_markers(&mut self) -> InterpretResult {
+        self.state.frame.sub_handle_mut(SetMarkers(Vec::new()))?;
+        Ok(None)
+    }
+
+    fn offset(&mut self, distance: f32, tolerance: Option<f32>) -> InterpretResult {
+        self.state.frame.sub_handle_mut(AddOffsetCurve::new(distance, tolerance))?;
+        Ok(None)
+    }
+
+    fn insert_hodograph(&mut self) -> InterpretResult {
+        self.state.frame.sub_handle_mut(InsertHodograph)?;
+        Ok(None)
+    }
+
+    fn elevate_degree(&mut self, times: usize) -> InterpretResult {
+        self.state.frame.sub_handle_mut(ElevateDegree(times))?;
+        Ok(None)
+    }
+
+    fn reduce_degree(&mut self) -> InterpretResult {
+        let error = self.state.frame.sub_handle_mut(ReduceDegree)?;
+        Ok(Some(Message::info(format!("Reduced degree with error {error}"))))
+    }
+
     fn trochoid(&mut self, prop: TrochoidCurveProperties) -> InterpretResult {
         self.state.frame.sub_handler_mut().sub_handle_mut(SetTrochoidProperties(prop))?;
         Ok(None)
     }
 
+    fn parametric(&mut self, expressions: ParametricExpressions) -> InterpretResult {
+        let frame = &mut *self.state.frame;
+        if !matches!(frame.sub_handle(GetCurveType)?, ShapeType::Parametric) {
+            frame.sub_handle_mut(SetCurveType(ShapeType::Parametric))?;
+        }
+        frame.sub_handle_mut(SetParametricExpressions(expressions))?;
+        Ok(None)
+    }
+
+    fn analytic(&mut self, family: AnalyticFamily) -> InterpretResult {
+        let frame = &mut *self.state.frame;
+        if !matches!(frame.sub_handle(GetCurveType)?, ShapeType::Analytic) {
+            frame.sub_handle_mut(SetCurveType(ShapeType::Analytic))?;
+        }
+        frame.sub_handle_mut(SetAnalyticFamily(family))?;
+        Ok(None)
+    }
+
     fn execute(&mut self, path: PathBuf, argument: Option<String>) -> InterpretResult {
         if !path.exists() {
             return Err(anyhow!("File '{}' does not exists", path.display()));
@@ -206,6 +463,27 @@ impl<'a> CommandInterpreter<'a> {
     }
 }
 
+fn toggle_overlay(frame: &mut Frame, overlay: Overlay) -> Result<()> {
+    let visible = frame.sub_handle(GetOverlayVisibility(overlay))?;
+    frame.sub_handle_mut(SetOverlayVisibility(overlay, !visible))?;
+    Ok(())
+}
+
+/// Formats the selected points of the current curve, optionally marking them on the canvas.
+fn mark_feature(
+    frame: &mut Frame,
+    select: impl FnOnce(CurveFeatures) -> Vec<Point<f32>>,
+    show: bool,
+) -> Result<String> {
+    let points = select(frame.sub_handle(GetCurveFeatures)?);
+    let message =
+        points.iter().map(|point| format!("{},{}", point.horizontal(), point.vertical())).join(" ");
+    if show {
+        frame.sub_handle_mut(SetMarkers(points))?;
+    }
+    Ok(message)
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("unknown command")]
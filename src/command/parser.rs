@@ -1,6 +1,14 @@
 use std::path::PathBuf;
 use std::str;
 
+use crate::canvas::math::expression::Expression;
+use crate::canvas::samples::Sampling;
+use crate::canvas::shape::analytic::family::AnalyticFamily;
+use crate::canvas::shape::b_spline::KnotVector;
+use crate::canvas::shape::bezier::BezierCurveAlgorithm;
+use crate::canvas::shape::bezier_spline::JointContinuity;
+use crate::canvas::shape::cubic_spline::{SplineBoundary, SplineParametrisation};
+use crate::canvas::shape::fitting::FittingBasis;
 use crate::canvas::shape::interpolation::InterpolationNodes;
 use crate::canvas::shape::trochoid::TrochoidCurveProperties;
 use crate::config::ShapeType;
@@ -95,6 +103,20 @@ pub enum Command {
         curve_id: usize,
     },
 
+    #[command()]
+    GetPointsLength {
+        #[arg()]
+        curve_id: usize,
+    },
+
+    #[command()]
+    GetEquidistantPoints {
+        #[arg()]
+        curve_id: usize,
+        #[arg()]
+        count: usize,
+    },
+
     #[command()]
     GetPoint {
         #[arg()]
@@ -115,9 +137,76 @@ pub enum Command {
         vertical: f32,
     },
 
+    #[command()]
+    SplitCurve {
+        #[arg()]
+        curve_id: usize,
+        #[arg()]
+        t: f32,
+    },
+
+    #[command()]
+    Intersect {
+        #[arg()]
+        first_curve_id: usize,
+        #[arg()]
+        second_curve_id: usize,
+        #[arg(long)]
+        show: bool,
+    },
+
+    #[command(allow_negative_numbers(true))]
+    Project {
+        #[arg()]
+        curve_id: usize,
+        #[arg()]
+        horizontal: f32,
+        #[arg()]
+        vertical: f32,
+    },
+
+    #[command()]
+    ClearMarkers,
+
+    /// Fits cubic Bezier spline within the tolerance if one is given
+    #[command(allow_negative_numbers(true))]
+    Offset {
+        #[arg()]
+        distance: f32,
+        #[arg(long)]
+        tolerance: Option<f32>,
+    },
+
+    #[command()]
+    InsertHodograph,
+
+    #[command()]
+    ElevateDegree {
+        #[arg(default_value_t = 1)]
+        times: usize,
+    },
+
+    #[command()]
+    ReduceDegree,
+
     #[command()]
     TrochoidProperties(TrochoidCurveProperties),
 
+    #[command(subcommand)]
+    Analytic(AnalyticFamily),
+
+    #[command(allow_negative_numbers(true))]
+    Parametric {
+        #[arg()]
+        horizontal: Expression,
+        #[arg()]
+        vertical: Expression,
+        #[arg()]
+        range_start: f32,
+        #[arg()]
+        range_end: f32,
+    },
+
     /// Creates new task
     #[command()]
     Execute {
@@ -144,6 +233,93 @@ pub enum Get {
 
     #[command()]
     Samples,
+
+    #[command()]
+    Sampling,
+
+    #[command()]
+    SplineDegree,
+
+    #[command()]
+    KnotVector,
+
+    #[command()]
+    JointContinuity {
+        #[arg()]
+        joint: usize,
+    },
+
+    #[command()]
+    SplineBoundary,
+
+    #[command()]
+    SplineParametrisation,
+
+    #[command()]
+    TcbParameters {
+        #[arg()]
+        point: usize,
+    },
+
+    #[command()]
+    Tangent {
+        #[arg()]
+        point: usize,
+    },
+
+    #[command()]
+    PolygonVertices,
+
+    #[command()]
+    PolygonRadius,
+
+    #[command()]
+    Placement,
+
+    #[command()]
+    Parametric,
+
+    #[command()]
+    Analytic,
+
+    #[command()]
+    FittingBasis,
+
+    #[command()]
+    FittingDegree,
+
+    #[command()]
+    BezierAlgorithm,
+
+    #[command()]
+    OverlayParameter,
+
+    #[command()]
+    CombScale,
+
+    #[command()]
+    SelfIntersections {
+        #[arg(long)]
+        show: bool,
+    },
+
+    #[command()]
+    Inflections {
+        #[arg(long)]
+        show: bool,
+    },
+
+    #[command()]
+    Cusps {
+        #[arg(long)]
+        show: bool,
+    },
+
+    #[command()]
+    CurvatureExtrema {
+        #[arg(long)]
+        show: bool,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -165,6 +341,132 @@ pub enum Set {
         #[arg()]
         value: u32,
     },
+
+    #[command(subcommand)]
+    Sampling(Sampling),
+
+    #[command()]
+    SplineDegree {
+        #[arg()]
+        value: usize,
+    },
+
+    /// One of `uniform`, `open-uniform` or comma separated values
+    #[command()]
+    KnotVector {
+        #[arg()]
+        value: KnotVector,
+    },
+
+    /// Sets all joints if none is given
+    #[command()]
+    JointContinuity {
+        #[arg()]
+        value: JointContinuity,
+        #[arg()]
+        joint: Option<usize>,
+    },
+
+    /// One of `natural`, `periodic`, `clamped` or `clamped:<start x>,<start y>,<end x>,<end y>`
+    #[command()]
+    SplineBoundary {
+        #[arg()]
+        value: SplineBoundary,
+    },
+
+    #[command()]
+    SplineParametrisation {
+        #[arg()]
+        value: SplineParametrisation,
+    },
+
+    /// Sets all points if none is given
+    #[command(allow_negative_numbers(true))]
+    TcbParameters {
+        #[arg()]
+        tension: f32,
+        #[arg()]
+        continuity: f32,
+        #[arg()]
+        bias: f32,
+        #[arg()]
+        point: Option<usize>,
+    },
+
+    #[command(allow_negative_numbers(true))]
+    Tangent {
+        #[arg()]
+        horizontal: f32,
+        #[arg()]
+        vertical: f32,
+        #[arg()]
+        point: usize,
+    },
+
+    #[command()]
+    PolygonVertices {
+        #[arg()]
+        value: u32,
+    },
+
+    #[command()]
+    PolygonRadius {
+        #[arg()]
+        value: f32,
+    },
+
+    /// Rotation is given in degrees
+    #[command(allow_negative_numbers(true))]
+    Placement {
+        #[arg()]
+        horizontal: f32,
+        #[arg()]
+        vertical: f32,
+        #[arg()]
+        scale: f32,
+        #[arg()]
+        rotation: f32,
+    },
+
+    #[command(allow_negative_numbers(true))]
+    ParametricConstant {
+        #[arg()]
+        name: String,
+        #[arg()]
+        value: f32,
+    },
+
+    #[command()]
+    FittingBasis {
+        #[arg()]
+        value: FittingBasis,
+    },
+
+    #[command()]
+    FittingDegree {
+        #[arg()]
+        value: usize,
+    },
+
+    #[command()]
+    BezierAlgorithm {
+        #[arg()]
+        value: BezierCurveAlgorithm,
+    },
+
+    /// Fraction of the parameter range of the curve
+    #[command()]
+    OverlayParameter {
+        #[arg()]
+        value: f32,
+    },
+
+    /// Length of teeth per unit of curvature
+    #[command()]
+    CombScale {
+        #[arg()]
+        value: f32,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -174,6 +476,18 @@ pub enum Toggle {
 
     #[command()]
     ControlLine,
+
+    #[command()]
+    CurvatureComb,
+
+    #[command()]
+    TangentNormal,
+
+    #[command()]
+    OsculatingCircle,
+
+    #[command()]
+    Hodograph,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -2,7 +2,16 @@ use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 
+use crate::canvas::control_points::tcb::TcbParameters;
+use crate::canvas::math::point::Point;
+use crate::canvas::placement::Placement;
+use crate::canvas::shape::analytic::family::AnalyticFamily;
+use crate::canvas::shape::b_spline::KnotVector;
 use crate::canvas::shape::bezier::BezierCurveAlgorithm;
+use crate::canvas::shape::bezier_spline::JointContinuity;
+use crate::canvas::shape::cubic_spline::{SplineBoundary, SplineParametrisation};
+use crate::canvas::shape::fitting::FittingBasis;
+use crate::canvas::shape::hermite;
 use crate::canvas::shape::interpolation::InterpolationNodes;
 use crate::canvas::shape::rational_bezier::RationalBezierCurveAlgorithm;
 use crate::canvas::shape::trochoid::TrochoidCurveProperties;
@@ -108,6 +117,7 @@ impl Default for FrameConfig {
     }
 }
 
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
 pub struct CanvasConfig {
@@ -117,8 +127,20 @@ pub struct CanvasConfig {
 
     pub show_center_of_mass: bool,
 
+    pub show_curvature_comb: bool,
+
+    pub show_tangent_normal: bool,
+
+    pub show_osculating_circle: bool,
+
+    pub show_hodograph: bool,
+
     pub curve_samples: u32,
 
+    pub freehand_tolerance: f32,
+
+    pub curve_select_radius: f32,
+
     pub default_curve_type: ShapeType,
 
     pub default_bezier_algorithm: BezierCurveAlgorithm,
@@ -129,12 +151,42 @@ pub struct CanvasConfig {
 
     pub default_trochoid_properties: TrochoidCurveProperties,
 
+    pub default_spline_degree: usize,
+
+    pub default_knot_vector: KnotVector,
+
+    pub default_joint_continuity: JointContinuity,
+
+    pub default_spline_boundary: SplineBoundary,
+
+    pub default_spline_parametrisation: SplineParametrisation,
+
+    pub default_tcb_parameters: TcbParameters,
+
+    pub default_tangent_handle_scale: f32,
+
+    pub default_regular_polygon_center: Point<f32>,
+
+    pub default_regular_polygon_radius: f32,
+
+    pub default_regular_polygon_vertices: u32,
+
+    pub default_placement: Placement,
+
+    pub default_analytic_family: AnalyticFamily,
+
+    pub default_fitting_basis: FittingBasis,
+
+    pub default_fitting_degree: usize,
+
     pub default_rational_bezier_weight: f32,
 
     pub default_line_width: f32,
 
     pub default_point_radius: f32,
 
+    pub default_comb_scale: f32,
+
     #[serde(with = "rgb::serde_pretty")]
     pub line_color: Rgb,
 
@@ -146,6 +198,12 @@ pub struct CanvasConfig {
 
     #[serde(with = "rgb::serde_pretty")]
     pub current_control_point_color: Rgb,
+
+    #[serde(with = "rgb::serde_pretty")]
+    pub marker_color: Rgb,
+
+    #[serde(with = "rgb::serde_pretty")]
+    pub overlay_color: Rgb,
 }
 
 impl Default for CanvasConfig {
@@ -154,19 +212,42 @@ impl Default for CanvasConfig {
             show_convex_hull: false,
             show_control_line: false,
             show_center_of_mass: true,
+            show_curvature_comb: false,
+            show_tangent_normal: false,
+            show_osculating_circle: false,
+            show_hodograph: false,
             curve_samples: 1000,
+            freehand_tolerance: 4.0,
+            curve_select_radius: 8.0,
             default_curve_type: ShapeType::Polyline,
             default_bezier_algorithm: BezierCurveAlgorithm::ChudyWozny,
             default_rational_bezier_algorithm: RationalBezierCurveAlgorithm::ChudyWozny,
             default_interpolation_nodes: InterpolationNodes::Chebyshev,
             default_trochoid_properties: TrochoidCurveProperties::default(),
+            default_spline_degree: 3,
+            default_knot_vector: KnotVector::OpenUniform,
+            default_joint_continuity: JointContinuity::C1,
+            default_spline_boundary: SplineBoundary::Natural,
+            default_spline_parametrisation: SplineParametrisation::Centripetal,
+            default_tcb_parameters: TcbParameters::default(),
+            default_tangent_handle_scale: hermite::DEFAULT_HANDLE_SCALE,
+            default_regular_polygon_center: Point::new(250.0, 250.0),
+            default_regular_polygon_radius: 100.0,
+            default_regular_polygon_vertices: 6,
+            default_placement: Placement::default(),
+            default_analytic_family: AnalyticFamily::default(),
+            default_fitting_basis: FittingBasis::Bezier,
+            default_fitting_degree: 3,
             default_rational_bezier_weight: 1.0,
             default_line_width: 2.0,
             default_point_radius: 4.0,
+            default_comb_scale: 1000.0,
             line_color: Rgb::new(255, 255, 0),
             convex_hull_color: Rgb::new(0, 255, 255),
             control_points_color: Rgb::new(255, 0, 255),
             current_control_point_color: Rgb::new(255, 255, 255),
+            marker_color: Rgb::new(255, 0, 0),
+            overlay_color: Rgb::new(0, 255, 127),
         }
     }
 }
@@ -226,4 +307,14 @@ pub enum ShapeType {
     RationalBezier,
     Trochoid,
     RegularPolygon,
+    BSpline,
+    Nurbs,
+    BezierSpline,
+    CubicSpline,
+    CatmullRom,
+    KochanekBartels,
+    Hermite,
+    Parametric,
+    Analytic,
+    Fitting,
 }
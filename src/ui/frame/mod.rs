@@ -129,6 +129,11 @@ impl Frame {
         self.mode.as_mode()
     }
 
+    #[must_use]
+    pub fn mode(&self) -> &ModeState {
+        &self.mode
+    }
+
     pub fn mode_mut(&mut self) -> &mut ModeState {
         &mut self.mode
     }
@@ -8,6 +8,8 @@ declare_requests! {
     { mut ChangeWeight (Change) -> () },
     { mut MouseClick (PhysicalPosition<f64>) -> () },
     { mut MousePress (PhysicalPosition<f64>) -> () },
+    { mut MouseRelease (PhysicalPosition<f64>) -> () },
+    { mut SplitCurve (PhysicalPosition<f64>) -> () },
     { mut MovePoint (Direction) -> () },
     { mut Delete () -> () },
     { mut Add () -> () },
@@ -3,9 +3,9 @@ use winit::dpi::PhysicalPosition;
 use crate::canvas::math::point::Point;
 use crate::canvas::math::vector::Vector;
 use crate::canvas::request::declare::{
-    AddCurve, AddPoint, ChangeCurrentCurveIndex, ChangeCurrentPointIndex, ChangeCurrentPointWeight,
-    DeleteCurrentPoint, DeleteCurve, GetConvexHull, GetCurrentPoint, MoveCurrentPoint,
-    SetConvexHull,
+    AddCurve, AddFreehandCurve, AddPoint, ChangeCurrentCurveIndex, ChangeCurrentPointIndex,
+    ChangeCurrentPointWeight, DeleteCurrentPoint, DeleteCurve, GetConvexHull, GetCurrentPoint,
+    MoveCurrentPoint, SelectCurve, SetConvexHull, SplitCurrentCurve,
 };
 use crate::canvas::shape::request::declare::{GetCurveCenter, MoveCurve, RotateCurve, SelectPoint};
 use crate::canvas::Canvas;
@@ -14,7 +14,8 @@ use crate::request::{
     ResponseMut,
 };
 use crate::ui::frame::request::declare::{
-    Add, ChangeIndex, ChangeWeight, Delete, MouseClick, MousePress, MovePoint, ToggleConvexHull,
+    Add, ChangeIndex, ChangeWeight, Delete, MouseClick, MousePress, MouseRelease, MovePoint,
+    SplitCurve, ToggleConvexHull,
 };
 use crate::ui::frame::Frame;
 use crate::ui::mode::Mode;
@@ -55,7 +56,7 @@ impl RequestHandlerMut<ChangeWeight> for Frame {
             Mode::Point => {
                 self.sub_handler_mut().handle_mut(ChangeCurrentPointWeight::new(factor))?;
             }
-            Mode::PointSelect | Mode::PointAdd => {}
+            Mode::PointSelect | Mode::PointAdd | Mode::Freehand => {}
         }
 
         Ok(())
@@ -67,6 +68,14 @@ impl RequestHandlerMut<MouseClick> for Frame {
         let click_point = scale_position(event.0);
         match self.current_mode() {
             Mode::Curve => {
+                let curve = self.sub_handler().handle(SelectCurve::new(
+                    click_point,
+                    self.canvas.config().curve_select_radius,
+                ))?;
+                if let Some(curve) = curve {
+                    self.canvas.state_mut().current_curve = curve;
+                    return Ok(());
+                }
                 let Some(center) = self.sub_handler().handle(GetCurveCenter)? else {
                     return Ok(());
                 };
@@ -90,6 +99,11 @@ impl RequestHandlerMut<MouseClick> for Frame {
                     self.canvas.state_mut().current_point_index = point;
                 }
             }
+            Mode::Freehand => {
+                if let Some(mode) = self.mode_mut().freehand_mut() {
+                    mode.begin_stroke(click_point);
+                }
+            }
         }
         Ok(())
     }
@@ -111,12 +125,42 @@ impl RequestHandlerMut<MousePress> for Frame {
                 let shift = click_point - point;
                 self.sub_handler_mut().handle_mut(MoveCurrentPoint::new(shift))?;
             }
+            Mode::Freehand => {
+                if let Some(mode) = self.mode_mut().freehand_mut() {
+                    mode.extend_stroke(click_point);
+                }
+            }
             Mode::PointAdd | Mode::PointSelect => {}
         }
         Ok(())
     }
 }
 
+impl RequestHandlerMut<MouseRelease> for Frame {
+    fn handle_mut(&mut self, _event: MouseRelease) -> ResponseMut<MouseRelease> {
+        if let Some(mode) = self.mode_mut().freehand_mut() {
+            let stroke = mode.finish_stroke();
+            if stroke.len() > 1 {
+                self.sub_handler_mut().handle_mut(AddFreehandCurve::new(stroke))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RequestHandlerMut<SplitCurve> for Frame {
+    fn handle_mut(&mut self, event: SplitCurve) -> ResponseMut<SplitCurve> {
+        let click_point = scale_position(event.0);
+        match self.current_mode() {
+            Mode::Curve => {
+                self.sub_handler_mut().handle_mut(SplitCurrentCurve::new(click_point))?;
+            }
+            Mode::Point | Mode::PointAdd | Mode::PointSelect | Mode::Freehand => {}
+        }
+        Ok(())
+    }
+}
+
 impl RequestHandlerMut<MovePoint> for Frame {
     fn handle_mut(&mut self, event: MovePoint) -> ResponseMut<MovePoint> {
         let direction = match event.0 {
@@ -128,7 +172,7 @@ impl RequestHandlerMut<MovePoint> for Frame {
         match self.current_mode() {
             Mode::Curve => self.sub_handler_mut().handle_mut(MoveCurve::new(direction))?,
             Mode::Point => self.sub_handler_mut().handle_mut(MoveCurrentPoint::new(direction))?,
-            Mode::PointAdd | Mode::PointSelect => {}
+            Mode::PointAdd | Mode::PointSelect | Mode::Freehand => {}
         }
         Ok(())
     }
@@ -139,7 +183,7 @@ impl RequestHandlerMut<Delete> for Frame {
         match self.current_mode() {
             Mode::Curve => self.sub_handler_mut().handle_mut(DeleteCurve)?,
             Mode::Point => self.sub_handler_mut().handle_mut(DeleteCurrentPoint)?,
-            Mode::PointAdd | Mode::PointSelect => {}
+            Mode::PointAdd | Mode::PointSelect | Mode::Freehand => {}
         }
         Ok(())
     }
@@ -158,7 +202,7 @@ impl RequestHandlerMut<ChangeIndex> for Frame {
             Mode::Point => {
                 self.sub_handler_mut().handle_mut(ChangeCurrentPointIndex::new(change))?;
             }
-            Mode::PointAdd | Mode::PointSelect => {}
+            Mode::PointAdd | Mode::PointSelect | Mode::Freehand => {}
         }
         Ok(())
     }
@@ -169,7 +213,7 @@ impl RequestHandlerMut<Add> for Frame {
         match self.current_mode() {
             Mode::Curve => self.sub_handler_mut().handle_mut(AddCurve)?,
             Mode::Point => self.mode_mut().enter_add(),
-            Mode::PointAdd | Mode::PointSelect => {}
+            Mode::PointAdd | Mode::PointSelect | Mode::Freehand => {}
         }
         Ok(())
     }
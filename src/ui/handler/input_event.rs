@@ -5,7 +5,8 @@ use winit::keyboard::{Key, NamedKey};
 
 use crate::request::{Change, Direction};
 use crate::ui::frame::request::declare::{
-    Add, ChangeIndex, ChangeWeight, Delete, MouseClick, MousePress, MovePoint, ToggleConvexHull,
+    Add, ChangeIndex, ChangeWeight, Delete, MouseClick, MousePress, MouseRelease, MovePoint,
+    SplitCurve, ToggleConvexHull,
 };
 use crate::ui::input_handler::{Input, InputEvent};
 use crate::ui::mode::Mode;
@@ -69,8 +70,20 @@ impl InputEventHandler {
     ) -> Option<InputEvent> {
         self.mouse_left_state = state;
 
-        if state == ElementState::Pressed && button == MouseButton::Left {
-            return Some(InputEvent::MouseClick(MouseClick(self.cursor_position)));
+        if button == MouseButton::Left
+            && state == ElementState::Pressed
+            && self.modifiers.state().control_key()
+        {
+            return Some(InputEvent::SplitCurve(SplitCurve(self.cursor_position)));
+        }
+
+        if button == MouseButton::Left {
+            return Some(match state {
+                ElementState::Pressed => InputEvent::MouseClick(MouseClick(self.cursor_position)),
+                ElementState::Released => {
+                    InputEvent::MouseRelease(MouseRelease(self.cursor_position))
+                }
+            });
         }
         None
     }
@@ -90,6 +103,7 @@ impl InputEventHandler {
 
                 Key::Character("p") => InputEvent::ChangeMode(Mode::Point),
                 Key::Character("s") => InputEvent::ChangeMode(Mode::PointSelect),
+                Key::Character("f") => InputEvent::ChangeMode(Mode::Freehand),
                 Key::Character("a") => InputEvent::AddCurve(Add),
                 Key::Character("d") => InputEvent::Delete(Delete),
 
@@ -1,11 +1,13 @@
 use anyhow::Result;
 use winit::keyboard::SmolStr;
 
+use crate::canvas::base_line::OpenBaseLine;
 use crate::command::program_view::ProgramView;
 use crate::request::RequestHandlerMut;
 use crate::ui::command_state::CommandState;
 use crate::ui::frame::request::declare::{
-    Add, ChangeIndex, ChangeWeight, Delete, MouseClick, MousePress, MovePoint, ToggleConvexHull,
+    Add, ChangeIndex, ChangeWeight, Delete, MouseClick, MousePress, MouseRelease, MovePoint,
+    SplitCurve, ToggleConvexHull,
 };
 use crate::ui::mode::Mode;
 
@@ -35,6 +37,8 @@ impl InputHandler {
                         InputEvent::MovePoint(event) => frame.handle_mut(event)?,
                         InputEvent::MouseClick(event) => frame.handle_mut(event)?,
                         InputEvent::MousePress(event) => frame.handle_mut(event)?,
+                        InputEvent::MouseRelease(event) => frame.handle_mut(event)?,
+                        InputEvent::SplitCurve(event) => frame.handle_mut(event)?,
                         InputEvent::AddCurve(event) => frame.handle_mut(event)?,
                         InputEvent::Delete(event) => frame.handle_mut(event)?,
                         InputEvent::ChangeIndex(event) => frame.handle_mut(event)?,
@@ -80,12 +84,14 @@ impl InputHandler {
     }
 
     fn change_mode(&mut self, mode: Mode, state: ProgramView<'_>) {
+        let line = OpenBaseLine::from(state.frame.canvas().config());
         let mode_state = state.frame.mode_mut();
         match mode {
             Mode::Curve => mode_state.exit(),
             Mode::Point => mode_state.enter_point(),
             Mode::PointAdd => mode_state.enter_add(),
             Mode::PointSelect => mode_state.select(),
+            Mode::Freehand => mode_state.enter_freehand(line),
         }
     }
 
@@ -115,6 +121,8 @@ pub enum InputEvent {
     MovePoint(MovePoint),
     MouseClick(MouseClick),
     MousePress(MousePress),
+    MouseRelease(MouseRelease),
+    SplitCurve(SplitCurve),
     AddCurve(Add),
     Delete(Delete),
     ChangeIndex(ChangeIndex),
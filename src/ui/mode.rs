@@ -1,11 +1,18 @@
 use std::fmt::{Display, Formatter};
 
+use tiny_skia::PixmapMut;
+
+use crate::canvas::base_line::OpenBaseLine;
+use crate::canvas::math::point::Point;
+use crate::canvas::shape::DrawOn;
+
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub enum Mode {
     Curve,
     Point,
     PointSelect,
     PointAdd,
+    Freehand,
 }
 
 impl Display for Mode {
@@ -15,6 +22,7 @@ impl Display for Mode {
             Mode::Point => write!(f, "Point"),
             Mode::PointAdd => write!(f, "PointAdd"),
             Mode::PointSelect => write!(f, "PointSelect"),
+            Mode::Freehand => write!(f, "Freehand"),
         }
     }
 }
@@ -25,6 +33,7 @@ pub enum ModeState {
     Point(ModePoint),
     PointAdd(ModePointAdd),
     PointSelect(ModePointSelect),
+    Freehand(Box<ModeFreehand>),
 }
 
 impl ModeState {
@@ -54,11 +63,19 @@ impl ModeState {
         });
     }
 
+    pub fn enter_freehand(&mut self, line: OpenBaseLine) {
+        replace_with::replace_with_or_abort(self, |state| match state {
+            ModeState::Curve(mode) => ModeState::Freehand(Box::new(mode.into_freehand(line))),
+            other => other,
+        });
+    }
+
     pub fn exit(&mut self) {
         replace_with::replace_with_or_abort(self, |state| match state {
             ModeState::Point(mode) => ModeState::Curve(mode.exit()),
             ModeState::PointAdd(mode) => ModeState::Point(mode.exit()),
             ModeState::PointSelect(mode) => ModeState::Point(mode.exit()),
+            ModeState::Freehand(mode) => ModeState::Curve((*mode).exit()),
             other => other,
         });
     }
@@ -70,6 +87,22 @@ impl ModeState {
             ModeState::Point(_) => Mode::Point,
             ModeState::PointAdd(_) => Mode::PointAdd,
             ModeState::PointSelect(_) => Mode::PointSelect,
+            ModeState::Freehand(_) => Mode::Freehand,
+        }
+    }
+
+    pub fn freehand_mut(&mut self) -> Option<&mut ModeFreehand> {
+        match self {
+            ModeState::Freehand(mode) => Some(mode),
+            _ => None,
+        }
+    }
+
+    pub fn draw_on(&self, pixmap: &mut PixmapMut<'_>) {
+        if let ModeState::Freehand(mode) = self {
+            for line in &mode.preview {
+                line.draw_on(pixmap);
+            }
         }
     }
 }
@@ -88,6 +121,11 @@ impl ModeCurve {
     pub fn into_point(self) -> ModePoint {
         ModePoint
     }
+
+    #[must_use]
+    pub fn into_freehand(self, line: OpenBaseLine) -> ModeFreehand {
+        ModeFreehand { stroke: Vec::new(), line, preview: Vec::new() }
+    }
 }
 
 #[derive(Debug)]
@@ -129,3 +167,41 @@ impl ModePointSelect {
         ModePoint
     }
 }
+
+const PREVIEW_SEGMENTS: usize = 64;
+
+#[derive(Debug)]
+pub struct ModeFreehand {
+    stroke: Vec<Point<f32>>,
+    line: OpenBaseLine,
+    /// Split into lines, so that extending the stroke rebuilds only the last one.
+    preview: Vec<OpenBaseLine>,
+}
+
+impl ModeFreehand {
+    pub fn begin_stroke(&mut self, point: Point<f32>) {
+        self.stroke.clear();
+        self.preview.clear();
+        self.extend_stroke(point);
+    }
+
+    pub fn extend_stroke(&mut self, point: Point<f32>) {
+        self.stroke.push(point);
+        let chunk = (self.stroke.len() - 1).saturating_sub(1) / PREVIEW_SEGMENTS;
+        if chunk == self.preview.len() {
+            self.preview.push(self.line.clone());
+        }
+        let start = chunk * PREVIEW_SEGMENTS;
+        self.preview[chunk].rebuild_paths(self.stroke[start..].iter().copied());
+    }
+
+    pub fn finish_stroke(&mut self) -> Vec<Point<f32>> {
+        self.preview.clear();
+        std::mem::take(&mut self.stroke)
+    }
+
+    #[must_use]
+    pub fn exit(self) -> ModeCurve {
+        ModeCurve
+    }
+}
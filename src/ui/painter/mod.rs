@@ -92,6 +92,7 @@ impl Painter {
         );
 
         canvas.draw_on_all(&mut panel.as_pixmap_mut());
+        view.frame.mode().draw_on(&mut panel.as_pixmap_mut());
 
         Ok(())
     }